@@ -27,7 +27,7 @@ pub async fn main() -> Result<(), Error> {
 	let _ = block.events().extrinsic_weight().await?;
 
 	// If you just need the event count you can call .event_count()
-	let _ = block.events().event_count();
+	let _ = block.events().event_count().await?;
 
 	// .all() interface builds on top of existing RPC. If you need raw values from the underlying RCP
 	// you can call .rpc(). The first parameter is the same as for .all() while the second allows you
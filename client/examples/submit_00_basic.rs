@@ -10,7 +10,7 @@ pub async fn main() -> Result<(), Error> {
 	// The typical flow is: build a call, submit it, then wait for a receipt. The simplest path
 	// is .submit() which signs and sends the transaction in one RPC call. It returns a
 	// SubmittedTransaction handle that we can use to track the transaction's progress.
-	let tx = client.tx().data_availability().submit_data(2, "Hello Avail!");
+	let tx = client.tx().data_availability().submit_data(2, "Hello Avail!")?;
 	let submitted = tx.submit(&signer, Default::default()).await?;
 	println!("Ext Hash: {:?}", submitted.ext_hash);
 
@@ -38,7 +38,7 @@ pub async fn main() -> Result<(), Error> {
 	// .estimate_call_fees(at)                    - fee estimate from the unsigned call
 	// .estimate_extrinsic_fees(signer, opts, at) - fee estimate from the fully signed extrinsic
 	// .call_info(at)                             - runtime dispatch info including weight
-	let tx = client.tx().data_availability().submit_data(2, "Fee check");
+	let tx = client.tx().data_availability().submit_data(2, "Fee check")?;
 	let fee = tx.estimate_call_fees(None).await?;
 	println!("Fee: {}", fee.final_fee());
 
@@ -54,7 +54,7 @@ pub async fn main() -> Result<(), Error> {
 	// .submit_and_wait_for_outcome(signer, opts, wait) - returns (TransactionReceipt, BlockEvents)
 	//
 	// The outcome variant is handy when you want to check events (e.g. ExtrinsicSuccess) right away.
-	let tx = client.tx().data_availability().submit_data(2, "Full flow");
+	let tx = client.tx().data_availability().submit_data(2, "Full flow")?;
 	let (receipt, events) = tx
 		.submit_and_wait_for_outcome(&signer, Options::new(), BlockQueryMode::Best)
 		.await?;
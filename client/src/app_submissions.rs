@@ -0,0 +1,80 @@
+//! Resumable, paged listing of the data submitted under one application id.
+
+use crate::{Client, Error};
+use avail_rust_core::{BlockInfo, H256, avail::data_availability::tx::SubmitData, ext::sp_crypto_hashing};
+use std::collections::VecDeque;
+
+/// One `submit_data` extrinsic of an application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppSubmission {
+	pub block: BlockInfo,
+	pub tx_index: u32,
+	/// Keccak-256 hash of the submitted data, as reported by the `DataSubmitted` event.
+	pub data_hash: H256,
+}
+
+/// Walks finalized blocks from a starting height and yields an application's submissions page by page.
+///
+/// The pager remembers where it stopped, so calling [`next_page`](Self::next_page) again after the chain
+/// has progressed picks up the newly finalized blocks.
+pub struct AppSubmissionPager {
+	client: Client,
+	app_id: u32,
+	next_height: u32,
+	page_size: usize,
+	pending: VecDeque<AppSubmission>,
+}
+
+impl AppSubmissionPager {
+	/// Creates a pager over the `submit_data` extrinsics of `app_id`, starting at block `from_height`.
+	///
+	/// A `page_size` of zero is treated as one.
+	pub fn new(client: Client, app_id: u32, from_height: u32, page_size: usize) -> Self {
+		Self {
+			client,
+			app_id,
+			next_height: from_height,
+			page_size: page_size.max(1),
+			pending: VecDeque::new(),
+		}
+	}
+
+	/// Returns up to `page_size` submissions and advances past them.
+	///
+	/// A page shorter than `page_size` means the finalized head was reached; an empty page means there
+	/// is nothing new yet.
+	pub async fn next_page(&mut self) -> Result<Vec<AppSubmission>, Error> {
+		let finalized_height = self.client.finalized().block_height().await?;
+		while self.pending.len() < self.page_size && self.next_height <= finalized_height {
+			self.scan_block(self.next_height).await?;
+			self.next_height += 1;
+		}
+
+		let len = self.pending.len().min(self.page_size);
+		Ok(self.pending.drain(..len).collect())
+	}
+
+	/// Returns the height of the next block that will be scanned.
+	pub fn next_height(&self) -> u32 {
+		self.next_height
+	}
+
+	async fn scan_block(&mut self, height: u32) -> Result<(), Error> {
+		let block = self.client.block(height);
+		let info = block.info().await?;
+		let extrinsics = block.extrinsics().all_as::<SubmitData>(Default::default()).await?;
+		for ext in extrinsics {
+			if ext.call.app_id != self.app_id {
+				continue;
+			}
+
+			self.pending.push_back(AppSubmission {
+				block: info,
+				tx_index: ext.metadata.ext_index,
+				data_hash: H256::from(sp_crypto_hashing::keccak_256(&ext.call.data)),
+			});
+		}
+
+		Ok(())
+	}
+}
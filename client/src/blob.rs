@@ -87,7 +87,7 @@ impl<'a> Blob<'a> {
 		opts: impl Into<WaitOption>,
 	) -> Result<FindBlobExtOutcome, Error> {
 		Self::submit_with_metadata_and_watch_inner(
-			&self,
+			self,
 			app_id,
 			blob,
 			blob_hash,
@@ -166,6 +166,7 @@ pub struct FoundBlobExt {
 	pub summary: BlobTxSummary,
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum FindBlobExtOutcome {
 	Found(FoundBlobExt),
@@ -1,8 +1,13 @@
 use crate::{Client, Error, RetryPolicy, UserError, block::shared::BlockContext, error_ops};
 use avail_rust_core::{
-	HasHeader, TransactionEventDecodable, avail,
+	H256, HasHeader, TransactionEventDecodable, avail,
+	decoded_events::RawEventRecord,
 	rpc::{self, AllowedEvents},
-	types::{HashStringNumber, RuntimePhase, substrate::Weight},
+	types::{
+		HashStringNumber, RuntimePhase,
+		pallets::system::types::{DispatchError, DispatchInfo},
+		substrate::Weight,
+	},
 };
 
 /// Helper for retrieving events scoped to a specific block.
@@ -50,6 +55,31 @@ impl EventsQuery {
 		Ok(BlockEvents::new(result))
 	}
 
+	/// Returns all events for this block read from `System::Events` storage instead of the events RPC.
+	pub async fn from_storage(&self) -> Result<BlockEvents, Error> {
+		let records = self.ctx.chain().storage_events(self.ctx.at.clone()).await?;
+
+		let mut result = Vec::with_capacity(records.len());
+		for (index, record) in records.into_iter().enumerate() {
+			if let Some(event) = BlockEvent::from_record(index as u32, record) {
+				result.push(event);
+			}
+		}
+
+		Ok(BlockEvents::new(result))
+	}
+
+	/// Cross-checks the events reported by the events RPC against the block's `System::Events` storage.
+	///
+	/// Returns `false` when the two views disagree, which points at a bug in the node's events RPC.
+	/// Both views come from the same node and the storage value is not checked against the header's
+	/// state root, so this is a consistency check only: it does not protect against a dishonest node.
+	pub async fn cross_check(&self) -> Result<bool, Error> {
+		let rpc_events = self.all(AllowedEvents::All).await?;
+		let storage_events = self.from_storage().await?;
+		Ok(events_match(&rpc_events, &storage_events))
+	}
+
 	/// Returns raw phase-grouped event data for this block.
 	pub async fn rpc(&self, allow_list: AllowedEvents, fetch_data: bool) -> Result<Vec<rpc::PhaseEvents>, Error> {
 		let at = self.ctx.hash_number()?;
@@ -108,6 +138,17 @@ impl EventsQuery {
 	}
 }
 
+fn events_match(left: &BlockEvents, right: &BlockEvents) -> bool {
+	let normalize = |data: &str| data.trim_start_matches("0x").to_ascii_lowercase();
+	left.len() == right.len()
+		&& left.0.iter().zip(right.0.iter()).all(|(l, r)| {
+			l.phase == r.phase
+				&& l.index == r.index
+				&& (l.pallet_id, l.variant_id) == (r.pallet_id, r.variant_id)
+				&& normalize(&l.data) == normalize(&r.data)
+		})
+}
+
 /// Event emitted during block execution with contextual metadata.
 #[derive(Debug, Clone)]
 pub struct BlockEvent {
@@ -121,23 +162,99 @@ pub struct BlockEvent {
 	pub variant_id: u8,
 	/// SCALE-encoded payload containing event data.
 	pub data: String,
+	topics: Option<Vec<H256>>,
 }
 
 impl BlockEvent {
+	/// Creates an event without topics, as reported by the events RPC.
+	pub fn new(phase: RuntimePhase, index: u32, pallet_id: u8, variant_id: u8, data: String) -> Self {
+		BlockEvent { phase, index, pallet_id, variant_id, data, topics: None }
+	}
+
 	/// Converts a raw phase event into a typed [`BlockEvent`].
 	///
 	/// Returns the converted event or an error when encoded data is missing.
 	pub fn from_parts(event: rpc::RuntimeEvent, phase: RuntimePhase) -> Result<Self, Error> {
-		let e = BlockEvent {
-			index: event.index,
-			pallet_id: event.pallet_id,
-			variant_id: event.variant_id,
-			data: event.data.clone(),
-			phase,
-		};
+		let e = BlockEvent::new(phase, event.index, event.pallet_id, event.variant_id, event.data);
 
 		Ok(e)
 	}
+
+	/// Converts a raw `System::Events` record into a [`BlockEvent`], keeping its topics.
+	///
+	/// Returns `None` when the record is too short to hold a pallet and variant index.
+	pub fn from_record(index: u32, record: RawEventRecord) -> Option<Self> {
+		let [pallet_id, variant_id, ..] = record.event[..] else {
+			return None;
+		};
+
+		Some(BlockEvent {
+			phase: record.phase,
+			index,
+			pallet_id,
+			variant_id,
+			data: const_hex::encode(&record.event),
+			topics: Some(record.topics),
+		})
+	}
+
+	/// Returns the indexed topics attached to the event.
+	///
+	/// Only events read from `System::Events` storage carry topics. Returns `None` for events built from the
+	/// events RPC, which does not report them; use [`EventsQuery::from_storage`] when topics are needed.
+	pub fn topics(&self) -> Option<&[H256]> {
+		self.topics.as_deref()
+	}
+
+	/// Returns whether the event was emitted with `topic` among its topics.
+	///
+	/// Returns `None` when the event carries no topic information.
+	pub fn has_topic(&self, topic: &H256) -> Option<bool> {
+		self.topics().map(|topics| topics.contains(topic))
+	}
+}
+
+/// Dispatch result of an extrinsic derived from its `ExtrinsicSuccess`/`ExtrinsicFailed` event.
+#[derive(Debug, Clone)]
+pub enum ExtrinsicOutcome {
+	/// The extrinsic was dispatched successfully.
+	Success { dispatch_info: DispatchInfo },
+	/// The extrinsic was dispatched but returned an error.
+	Failed {
+		error: DispatchError,
+		dispatch_info: DispatchInfo,
+	},
+}
+
+impl ExtrinsicOutcome {
+	/// Returns true when the extrinsic was dispatched successfully.
+	pub fn is_success(&self) -> bool {
+		matches!(self, Self::Success { .. })
+	}
+
+	/// Returns the dispatch info regardless of the outcome.
+	pub fn dispatch_info(&self) -> &DispatchInfo {
+		match self {
+			Self::Success { dispatch_info } => dispatch_info,
+			Self::Failed { dispatch_info, .. } => dispatch_info,
+		}
+	}
+}
+
+/// Per-call results of a `utility::force_batch` dispatch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForceBatchSummary {
+	/// Number of calls that completed without error.
+	pub completed: u32,
+	/// Positions within the batch of the calls that failed.
+	pub errored_indices: Vec<u32>,
+}
+
+impl ForceBatchSummary {
+	/// Returns true when every call in the batch completed.
+	pub fn is_success(&self) -> bool {
+		self.errored_indices.is_empty()
+	}
 }
 
 /// Collection of block events with helpers for querying by header.
@@ -204,6 +321,26 @@ impl BlockEvents {
 		self.is_present::<avail::system::events::ExtrinsicFailed>()
 	}
 
+	/// Returns the dispatch outcome from the first `ExtrinsicSuccess` or `ExtrinsicFailed` event.
+	///
+	/// Returns `None` when neither event is present or the matching event cannot be decoded.
+	pub fn outcome(&self) -> Option<ExtrinsicOutcome> {
+		use avail::system::events::{ExtrinsicFailed, ExtrinsicSuccess};
+
+		let event = self.0.iter().find(|x| {
+			let header = (x.pallet_id, x.variant_id);
+			header == ExtrinsicSuccess::HEADER_INDEX || header == ExtrinsicFailed::HEADER_INDEX
+		})?;
+
+		if (event.pallet_id, event.variant_id) == ExtrinsicSuccess::HEADER_INDEX {
+			let e = ExtrinsicSuccess::from_event(&event.data).ok()?;
+			Some(ExtrinsicOutcome::Success { dispatch_info: e.dispatch_info })
+		} else {
+			let e = ExtrinsicFailed::from_event(&event.data).ok()?;
+			Some(ExtrinsicOutcome::Failed { error: e.dispatch_error, dispatch_info: e.dispatch_info })
+		}
+	}
+
 	/// Returns whether a proxy call succeeded, when present.
 	///
 	pub fn proxy_executed_successfully(&self) -> Option<bool> {
@@ -218,6 +355,49 @@ impl BlockEvents {
 		Some(executed.result.is_ok())
 	}
 
+	/// Summarizes a `utility::force_batch` from its `ItemCompleted`/`ItemFailed` events.
+	///
+	/// Each call emits one item event in order, so a call's position is its index among them.
+	/// Returns `None` when neither `BatchCompleted` nor `BatchCompletedWithErrors` is present.
+	pub fn force_batch_summary(&self) -> Option<ForceBatchSummary> {
+		use avail::utility::events::{BatchCompleted, BatchCompletedWithErrors, ItemCompleted, ItemFailed};
+
+		if !self.is_present::<BatchCompleted>() && !self.is_present::<BatchCompletedWithErrors>() {
+			return None;
+		}
+
+		let mut summary = ForceBatchSummary::default();
+		let mut index = 0u32;
+		for event in &self.0 {
+			let header = (event.pallet_id, event.variant_id);
+			if header == ItemCompleted::HEADER_INDEX {
+				summary.completed += 1;
+			} else if header == ItemFailed::HEADER_INDEX {
+				summary.errored_indices.push(index);
+			} else {
+				continue;
+			}
+			index += 1;
+		}
+
+		Some(summary)
+	}
+
+	/// Returns the events that were emitted with `topic`.
+	///
+	/// Returns `None` when any event carries no topic information, as is the case for events read
+	/// through the events RPC; use [`EventsQuery::from_storage`] to get events with topics.
+	pub fn with_topic(&self, topic: &H256) -> Option<Vec<&BlockEvent>> {
+		let mut result = Vec::new();
+		for event in &self.0 {
+			if event.has_topic(topic)? {
+				result.push(event);
+			}
+		}
+
+		Some(result)
+	}
+
 	/// Returns true when at least one event of the given type exists.
 	///
 	pub fn is_present<T: HasHeader>(&self) -> bool {
@@ -261,3 +441,140 @@ impl BlockEvents {
 		self.0.is_empty()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use avail_rust_core::types::{
+		pallets::system::types::Pays,
+		substrate::{DispatchClass, Weight},
+	};
+	use codec::Encode;
+
+	fn dispatch_info() -> DispatchInfo {
+		DispatchInfo {
+			weight: Weight { ref_time: 10, proof_size: 20 },
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes,
+		}
+	}
+
+	fn event(variant_id: u8, payload: Vec<u8>) -> BlockEvent {
+		let mut data = vec![0u8, variant_id];
+		data.extend(payload);
+		BlockEvent::new(RuntimePhase::ApplyExtrinsic(1), 0, 0, variant_id, const_hex::encode(data))
+	}
+
+	#[test]
+	fn outcome_success() {
+		let events = BlockEvents::new(vec![event(0, dispatch_info().encode())]);
+		let outcome = events.outcome().expect("outcome present");
+		assert!(outcome.is_success());
+		assert_eq!(outcome.dispatch_info().weight.ref_time, 10);
+	}
+
+	#[test]
+	fn outcome_failed() {
+		let mut payload = DispatchError::BadOrigin.encode();
+		payload.extend(dispatch_info().encode());
+		let events = BlockEvents::new(vec![event(1, payload)]);
+		let outcome = events.outcome().expect("outcome present");
+		match outcome {
+			ExtrinsicOutcome::Failed { error, dispatch_info } => {
+				assert!(matches!(error, DispatchError::BadOrigin));
+				assert_eq!(dispatch_info.weight.proof_size, 20);
+			},
+			ExtrinsicOutcome::Success { .. } => panic!("expected failed outcome"),
+		}
+	}
+
+	#[test]
+	fn from_record_keeps_topics() {
+		let topic = H256::from([7u8; 32]);
+		let record = RawEventRecord {
+			phase: RuntimePhase::ApplyExtrinsic(2),
+			event: vec![0, 0, 1, 2],
+			topics: vec![topic],
+		};
+		let event = BlockEvent::from_record(5, record).expect("event present");
+		assert_eq!((event.index, event.pallet_id, event.variant_id), (5, 0, 0));
+		assert_eq!(event.topics(), Some(&[topic][..]));
+
+		let other = RawEventRecord {
+			phase: RuntimePhase::Finalization,
+			event: vec![0, 1],
+			topics: vec![],
+		};
+		let other = BlockEvent::from_record(6, other).expect("event present");
+		let events = BlockEvents::new(vec![event, other]);
+		assert_eq!(events.with_topic(&topic).map(|x| x.len()), Some(1));
+		assert_eq!(events.with_topic(&H256::zero()).map(|x| x.len()), Some(0));
+
+		let empty = RawEventRecord {
+			phase: RuntimePhase::Initialization,
+			event: vec![0],
+			topics: vec![],
+		};
+		assert!(BlockEvent::from_record(0, empty).is_none());
+	}
+
+	#[test]
+	fn rpc_events_report_missing_topics() {
+		let rpc_event = rpc::RuntimeEvent {
+			index: 3,
+			pallet_id: 0,
+			variant_id: 0,
+			data: String::from("0000"),
+		};
+		let event = BlockEvent::from_parts(rpc_event, RuntimePhase::ApplyExtrinsic(1)).expect("event converts");
+		assert_eq!(event.topics(), None);
+		assert_eq!(event.has_topic(&H256::zero()), None);
+
+		let record = RawEventRecord {
+			phase: RuntimePhase::ApplyExtrinsic(1),
+			event: vec![0, 0],
+			topics: vec![H256::zero()],
+		};
+		let stored = BlockEvent::from_record(4, record).expect("event present");
+		let events = BlockEvents::new(vec![stored, event]);
+		assert!(events.with_topic(&H256::zero()).is_none());
+	}
+
+	#[test]
+	fn force_batch_summary_lists_failed_calls() {
+		use avail::utility::events::{BatchCompletedWithErrors, ItemCompleted, ItemFailed};
+
+		let utility = |(pallet_id, variant_id): (u8, u8), payload: Vec<u8>| {
+			let mut e = event(variant_id, Vec::new());
+			e.pallet_id = pallet_id;
+			e.data = const_hex::encode([vec![pallet_id, variant_id], payload].concat());
+			e
+		};
+		let events = BlockEvents::new(vec![
+			utility(ItemCompleted::HEADER_INDEX, Vec::new()),
+			utility(ItemFailed::HEADER_INDEX, DispatchError::BadOrigin.encode()),
+			utility(ItemCompleted::HEADER_INDEX, Vec::new()),
+			utility(BatchCompletedWithErrors::HEADER_INDEX, Vec::new()),
+		]);
+
+		let summary = events.force_batch_summary().expect("summary present");
+		assert_eq!(summary, ForceBatchSummary { completed: 2, errored_indices: vec![1] });
+		assert!(!summary.is_success());
+		assert!(BlockEvents::new(Vec::new()).force_batch_summary().is_none());
+	}
+
+	#[test]
+	fn events_match_compares_every_event() {
+		let mut prefixed = event(0, vec![1, 2]);
+		prefixed.data = std::format!("0x{}", prefixed.data.to_uppercase());
+		let left = BlockEvents::new(vec![event(0, vec![1, 2])]);
+		assert!(events_match(&left, &BlockEvents::new(vec![prefixed])));
+		assert!(!events_match(&left, &BlockEvents::new(vec![event(0, vec![1, 3])])));
+		assert!(!events_match(&left, &BlockEvents::new(Vec::new())));
+	}
+
+	#[test]
+	fn outcome_missing() {
+		assert!(BlockEvents::new(Vec::new()).outcome().is_none());
+	}
+}
@@ -202,6 +202,27 @@ impl ExtrinsicsQuery {
 		Ok(result)
 	}
 
+	/// Fetches matching extrinsics without decoding them.
+	///
+	/// Each [`LazyExtrinsic`] decodes its call only when asked to.
+	pub async fn lazy(
+		&self,
+		allow_list: Option<Vec<AllowedExtrinsic>>,
+		sig_filter: rpc::SignatureFilter,
+	) -> Result<Vec<LazyExtrinsic>, Error> {
+		let at = self.ctx.hash_number()?;
+		let chain = self.ctx.chain();
+
+		let extrinsics = chain
+			.extrinsics(at, allow_list, sig_filter, DataFormat::Extrinsic)
+			.await?;
+
+		Ok(extrinsics
+			.into_iter()
+			.map(|x| LazyExtrinsic::from_rpc_extrinsic(x, at))
+			.collect())
+	}
+
 	// ── Predefined ──────────────────────────────────────────────────────
 
 	/// Block 0 is the only block that does not have this extrinsic in it.
@@ -354,6 +375,70 @@ impl UntypedExtrinsic {
 	}
 }
 
+// ── LazyExtrinsic ───────────────────────────────────────────────────────
+
+/// Extrinsic kept in its encoded form until it is explicitly decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LazyExtrinsic {
+	/// Hex-encoded extrinsic as returned by the node.
+	pub data: String,
+	pub metadata: ExtrinsicMetadata,
+}
+
+impl LazyExtrinsic {
+	/// Wraps hex-encoded extrinsic `data` without decoding it.
+	pub fn new(data: String, metadata: ExtrinsicMetadata) -> Self {
+		Self { data, metadata }
+	}
+
+	/// Builds a lazy extrinsic from an RPC result of the block at `at`, keeping its data encoded.
+	pub fn from_rpc_extrinsic(ext: rpc::Extrinsic, at: HashNumber) -> Self {
+		let metadata = ExtrinsicMetadata::from_rpc_extrinsic(&ext, at);
+		Self::new(ext.data, metadata)
+	}
+
+	/// Returns the extrinsic's index within its block.
+	pub fn ext_index(&self) -> u32 {
+		self.metadata.ext_index
+	}
+
+	/// Returns the extrinsic's hash.
+	pub fn ext_hash(&self) -> H256 {
+		self.metadata.ext_hash
+	}
+
+	/// Returns the `(pallet_id, variant_id)` of the call, as reported by the node.
+	pub fn header(&self) -> (u8, u8) {
+		(self.metadata.pallet_id, self.metadata.variant_id)
+	}
+
+	/// Checks the call header without decoding anything.
+	pub fn is<T: HasHeader>(&self) -> bool {
+		self.header() == T::HEADER_INDEX
+	}
+
+	/// Decodes the preamble and call into an [`UntypedExtrinsic`].
+	pub fn untyped(&self) -> Result<UntypedExtrinsic, Error> {
+		let extrinsic = Extrinsic::try_from(self.data.as_str())
+			.map_err(|e| Error::decode_with_op(error_ops::ErrorOperation::BlockExtrinsicFromRpc, e))?;
+		Ok(UntypedExtrinsic::new(extrinsic.preamble, extrinsic.call.0, self.metadata.clone()))
+	}
+
+	/// Decodes the extrinsic as `T`.
+	///
+	/// Fails when the call header does not match `T`; use [`LazyExtrinsic::is`] to check first.
+	pub fn decode<T: HasHeader + Decode>(&self) -> Result<TypedExtrinsic<T>, Error> {
+		if !self.is::<T>() {
+			return Err(Error::decode_with_op(
+				error_ops::ErrorOperation::BlockExtrinsicTyped,
+				std::format!("Extrinsic header {:?} does not match {:?}", self.header(), T::HEADER_INDEX),
+			));
+		}
+
+		self.untyped()?.as_typed::<T>()
+	}
+}
+
 // ── BlockExtrinsic<T> ───────────────────────────────────────────────────
 
 #[derive(Debug, Clone)]
@@ -433,3 +518,42 @@ impl<T: HasHeader + Decode> TryFrom<&UntypedExtrinsic> for TypedExtrinsic<T> {
 		Ok(Self::new(value.preamble.clone(), call, value.metadata.clone()))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use avail::{data_availability::tx::SubmitData, timestamp::tx::Set};
+	use avail_rust_core::{ExtrinsicCall, ext::codec::Encode};
+
+	fn lazy<T: HasHeader + codec::Encode>(call: &T, ext_index: u32) -> LazyExtrinsic {
+		let call = ExtrinsicCall::from(call);
+		let ext = Extrinsic::new_bare(4, call);
+		let metadata =
+			ExtrinsicMetadata::new(ext.hash(), ext_index, T::HEADER_INDEX.0, T::HEADER_INDEX.1, HashNumber::Number(1));
+		LazyExtrinsic::new(const_hex::encode(ext.encode()), metadata)
+	}
+
+	#[test]
+	fn lazy_extrinsic_decodes_only_matching() {
+		let exts = [
+			lazy(&Set { now: 1_000 }, 0),
+			lazy(&SubmitData { app_id: 2, data: b"first".to_vec() }, 1),
+			LazyExtrinsic::new("not hex".into(), ExtrinsicMetadata::new(H256::zero(), 2, 6, 3, HashNumber::Number(1))),
+			lazy(&SubmitData { app_id: 3, data: b"second".to_vec() }, 3),
+		];
+
+		let decoded: Vec<TypedExtrinsic<SubmitData>> = exts
+			.iter()
+			.filter(|x| x.is::<SubmitData>())
+			.map(|x| x.decode::<SubmitData>())
+			.collect::<Result<_, _>>()
+			.unwrap();
+
+		assert_eq!(decoded.len(), 2);
+		assert_eq!(decoded[0].call.app_id, 2);
+		assert_eq!(decoded[0].call.data, b"first".to_vec());
+		assert_eq!(decoded[1].ext_index(), 3);
+		assert!(exts[0].decode::<SubmitData>().is_err());
+		assert!(exts[2].untyped().is_err());
+	}
+}
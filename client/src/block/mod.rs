@@ -2,15 +2,15 @@ pub mod events;
 pub mod extrinsic;
 pub mod shared;
 
-pub use events::{BlockEvent, BlockEvents, EventsQuery};
-pub use extrinsic::{ExtrinsicsQuery, TypedExtrinsic, UntypedExtrinsic};
+pub use events::{BlockEvent, BlockEvents, EventsQuery, ExtrinsicOutcome, ForceBatchSummary};
+pub use extrinsic::{ExtrinsicsQuery, LazyExtrinsic, TypedExtrinsic, UntypedExtrinsic};
 pub use shared::ExtrinsicMetadata;
 
 use crate::{Client, Error, RetryPolicy, block::shared::BlockContext};
 use avail_rust_core::{
-	AccountId, AccountIdLike, AvailHeader, BlockInfo, HashNumber,
+	AccountId, AccountIdLike, AvailHeader, BlockInfo, H256, HashNumber,
 	grandpa::GrandpaJustification,
-	subxt_metadata,
+	rpc, subxt_metadata,
 	types::{
 		HashStringNumber,
 		substrate::{PerDispatchClassWeight, Weight},
@@ -29,10 +29,37 @@ impl Block {
 		Block { ctx: BlockContext::new(client, at.into()) }
 	}
 
+	/// Returns the block height when this handle was created from one, without an RPC call.
+	pub fn height(&self) -> Option<u32> {
+		match &self.ctx.at {
+			HashStringNumber::HashNumber(HashNumber::Number(n)) => Some(*n),
+			HashStringNumber::HashNumber(HashNumber::HashAndNumber((_, n))) => Some(*n),
+			_ => None,
+		}
+	}
+
+	/// Returns the block hash when this handle was created from one, without an RPC call.
+	pub fn hash(&self) -> Option<H256> {
+		match &self.ctx.at {
+			HashStringNumber::HashNumber(HashNumber::Hash(h)) => Some(*h),
+			HashStringNumber::HashNumber(HashNumber::HashAndNumber((h, _))) => Some(*h),
+			_ => None,
+		}
+	}
+
 	pub fn extrinsics(&self) -> extrinsic::ExtrinsicsQuery {
 		extrinsic::ExtrinsicsQuery::new(self.ctx.client.clone(), self.ctx.at.clone())
 	}
 
+	/// Fetches all extrinsics of this block without decoding them.
+	///
+	/// Call [`LazyExtrinsic::decode`] on the items you care about.
+	pub async fn extrinsics_lazy(&self) -> Result<impl Iterator<Item = LazyExtrinsic>, Error> {
+		let mut q = self.extrinsics();
+		q.set_retry_policy(self.ctx.retry_policy());
+		Ok(q.lazy(None, Default::default()).await?.into_iter())
+	}
+
 	/// Returns an event helper scoped to this block.
 	pub fn events(&self) -> events::EventsQuery {
 		events::EventsQuery::new(self.ctx.client.clone(), self.ctx.at.clone())
@@ -98,6 +125,20 @@ impl Block {
 		q.count(None, Default::default()).await
 	}
 
+	/// Returns the position of the extrinsic with hash `tx_hash` in this block.
+	///
+	/// Returns `None` when the block does not contain that extrinsic.
+	pub async fn tx_index(&self, tx_hash: H256) -> Result<Option<u32>, Error> {
+		let allow_list = vec![rpc::AllowedExtrinsic::TxHash(tx_hash)];
+		let result = self
+			.ctx
+			.chain()
+			.extrinsics(self.ctx.at.clone(), Some(allow_list), Default::default(), rpc::DataFormat::None)
+			.await?;
+
+		Ok(result.first().map(|x| x.ext_index))
+	}
+
 	/// Returns the number of events in this block.
 	pub async fn event_count(&self) -> Result<usize, Error> {
 		self.ctx.event_count().await
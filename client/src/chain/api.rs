@@ -1,37 +1,140 @@
 use crate::{
-	Client, Error, RetryPolicy, avail, conversions, error_ops, submission::SubmittedTransaction,
-	subxt_signer::sr25519::Keypair, transaction_options::Options,
+	Client, Error, ErrorCode, RetryPolicy, avail, conversions, error_ops,
+	submission::SubmittedTransaction,
+	subxt_signer::sr25519::Keypair,
+	transaction_options::{Options, ResolvedOptions},
 };
 use avail::{
 	balances::types::AccountData,
 	system::{storage as SystemStorage, types::AccountInfo},
 };
 use avail_rust_core::{
-	AccountId, AccountIdLike, AvailHeader, BlockInfo, Extension, ExtensionImplicit, H256, HashNumber, consensus,
-	decoded_events::{EncodedEvent, parse_encoded_events},
+	AccountId, AccountIdLike, AvailHeader, BlockInfo, DecodeLimits, Extension, ExtensionImplicit,
+	ExtensionImplicitWithMetadataHash, ExtensionWithMetadataHash, ExtrinsicCall, H256, HashNumber,
+	decoded_events::{EncodedEvent, RawEventRecord, decode_event_records_with_limits, parse_encoded_events},
+	ext::sp_crypto_hashing,
 	ext::subxt_rpcs::client::RpcParams,
-	grandpa::GrandpaJustification,
-	header::DigestItem,
+	grandpa::{AuthorityList, GrandpaJustification, SetId},
 	rpc::{
 		self, Error as RpcError, LegacyBlock,
 		blob::{Blob, BlobInfo},
-		kate::{BlockLength, Cell, DataProof, GCellBlock, GDataProof, GMultiProof, GRow, ProofResponse},
+		kate::{
+			BlockDimensions, BlockLength, Cell, DataProof, GCellBlock, GDataProof, GMultiProof, GRow, ProofResponse,
+		},
 		runtime_api,
 	},
 	substrate::{SignedPayload, StorageMap, StorageValue},
 	types::{
 		HashString,
 		metadata::{ChainInfo, HashStringNumber},
-		substrate::{FeeDetails, PerDispatchClassWeight, RuntimeDispatchInfo},
+		substrate::{FeeDetails, PerDispatchClassWeight, RuntimeDispatchInfo, Weight},
 	},
 };
 use codec::{Decode, Encode};
+use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, ops::RangeInclusive, time::Duration};
+
+/// Default number of keys sent per `state_queryStorageAt` request by [`Chain::fetch_many`].
+pub const DEFAULT_FETCH_MANY_CHUNK_SIZE: usize = 1000;
+/// Maximum number of `state_queryStorageAt` requests [`Chain::fetch_many`] keeps in flight.
+const FETCH_MANY_CONCURRENCY: usize = 4;
+/// Maximum number of headers [`Chain::headers`] fetches concurrently.
+const HEADERS_CONCURRENCY: usize = 8;
+
+/// Lifecycle state of a block, as seen by the connected node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockState {
+	/// The block is part of the best chain but not finalized yet.
+	Included,
+	/// The block is finalized.
+	Finalized,
+	/// The block is not on the node's best chain: another block is canonical at its height.
+	///
+	/// Below the finalized height this is permanent; above it the fork may still become best.
+	Discarded,
+	/// The block has not been produced yet, or the node has never seen its hash.
+	DoesNotExist,
+	/// The block is canonical but the node no longer serves it, as its header or state was pruned.
+	Pruned,
+}
+
+impl BlockState {
+	/// Classifies a block against the current chain heads.
+	///
+	/// `canonical` tells whether the block is the one the node's best chain has at `height`, and
+	/// `available` whether the node can still serve its header and state.
+	pub fn classify(height: u32, best_height: u32, finalized_height: u32, canonical: bool, available: bool) -> Self {
+		if height > best_height {
+			return Self::DoesNotExist;
+		}
+
+		if !canonical {
+			return Self::Discarded;
+		}
+
+		if !available {
+			return Self::Pruned;
+		}
+
+		if height <= finalized_height {
+			Self::Finalized
+		} else {
+			Self::Included
+		}
+	}
+
+	/// Returns the variant name, as used by the `Display`, `FromStr` and serde implementations.
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Included => "Included",
+			Self::Finalized => "Finalized",
+			Self::Discarded => "Discarded",
+			Self::DoesNotExist => "DoesNotExist",
+			Self::Pruned => "Pruned",
+		}
+	}
+}
+
+impl std::fmt::Display for BlockState {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl std::str::FromStr for BlockState {
+	type Err = ParseBlockStateError;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"Included" => Ok(Self::Included),
+			"Finalized" => Ok(Self::Finalized),
+			"Discarded" => Ok(Self::Discarded),
+			"DoesNotExist" => Ok(Self::DoesNotExist),
+			"Pruned" => Ok(Self::Pruned),
+			_ => Err(ParseBlockStateError(value.to_string())),
+		}
+	}
+}
+
+/// Error returned when a string does not name a [`BlockState`] variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBlockStateError(pub String);
+
+impl std::fmt::Display for ParseBlockStateError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Unknown block state: {}", self.0)
+	}
+}
+
+impl std::error::Error for ParseBlockStateError {}
 
 /// Low-level chain RPC API with explicit retry controls.
 pub struct Chain {
 	pub(crate) client: Client,
 	retry_on_error: RetryPolicy,
 	retry_on_none: RetryPolicy,
+	fetch_many_chunk_size: usize,
 }
 impl Chain {
 	/// Creates a chain helper bound to a client.
@@ -40,9 +143,16 @@ impl Chain {
 			client,
 			retry_on_error: RetryPolicy::Inherit,
 			retry_on_none: RetryPolicy::Inherit,
+			fetch_many_chunk_size: DEFAULT_FETCH_MANY_CHUNK_SIZE,
 		}
 	}
 
+	/// Sets how many keys [`Chain::fetch_many`] sends per request.
+	pub fn fetch_many_chunk_size(mut self, value: usize) -> Self {
+		self.fetch_many_chunk_size = value.max(1);
+		self
+	}
+
 	/// Sets retry behavior for RPC errors and `None` responses.
 	pub fn retry_policy(mut self, error: RetryPolicy, none: RetryPolicy) -> Self {
 		self.retry_on_error = error;
@@ -76,6 +186,27 @@ impl Chain {
 		})?)
 	}
 
+	/// Returns the headers of every block in `range`, in ascending height order.
+	///
+	/// Block hashes and headers are fetched with bounded concurrency. Fails with
+	/// [`Error::NotFound`] when a height in the range has no block yet.
+	pub async fn headers(&self, range: RangeInclusive<u32>) -> Result<Vec<AvailHeader>, Error> {
+		futures::stream::iter(range)
+			.map(|height| async move {
+				let _permit = self.client.concurrency_permit().await;
+				let header = self.block_header(Some(height)).await?;
+				header.ok_or_else(|| {
+					Error::not_found_with_op(
+						error_ops::ErrorOperation::ChainHeaders,
+						std::format!("No block header found for block height: {}", height),
+					)
+				})
+			})
+			.buffered(HEADERS_CONCURRENCY)
+			.try_collect()
+			.await
+	}
+
 	/// Returns a legacy block by hash, or best block when `None`.
 	pub async fn legacy_block(&self, at: Option<H256>) -> Result<Option<LegacyBlock>, RpcError> {
 		let retry = self.should_retry_on_error();
@@ -85,6 +216,9 @@ impl Chain {
 	}
 
 	/// Fetches and decodes legacy block events.
+	///
+	/// Events are decoded with the metadata of block `at`, so blocks from before a runtime upgrade
+	/// decode with the types they were produced with.
 	pub async fn legacy_block_events(&self, at: H256) -> Result<Vec<EncodedEvent>, Error> {
 		let metadata = self.block_metadata(Some(at)).await?;
 		let bytes = retry!(self.should_retry_on_error(), {
@@ -105,6 +239,37 @@ impl Chain {
 		})
 	}
 
+	/// Reads `System::Events` storage at a block and splits it into event records.
+	///
+	/// Unlike [`Chain::events`] this does not depend on the custom events RPC. Records are split using
+	/// the metadata valid at that block rather than the client's current metadata.
+	pub async fn storage_events(&self, at: impl Into<HashStringNumber>) -> Result<Vec<RawEventRecord>, Error> {
+		self.storage_events_with_limits(at, &DecodeLimits::default()).await
+	}
+
+	/// Same as [`Chain::storage_events`] but rejects more than `limits.max_vec_len` records with a
+	/// decode error before reserving space for them.
+	pub async fn storage_events_with_limits(
+		&self,
+		at: impl Into<HashStringNumber>,
+		limits: &DecodeLimits,
+	) -> Result<Vec<RawEventRecord>, Error> {
+		let at = conversions::hash_string_number::to_hash(self, at).await?;
+		let metadata = self.block_metadata(Some(at)).await?;
+		let bytes = retry!(self.should_retry_on_error(), {
+			avail::system::storage::Events::fetch(&self.client.rpc_client, Some(at)).await
+		})?
+		.ok_or_else(|| {
+			Error::not_found_with_op(
+				error_ops::ErrorOperation::ChainStorageEvents,
+				"No events found for requested block",
+			)
+		})?;
+
+		decode_event_records_with_limits(&metadata, &bytes.0, limits)
+			.map_err(|e| Error::decode_with_op(error_ops::ErrorOperation::ChainStorageEvents, e))
+	}
+
 	/// Fetches metadata at a specific block hash.
 	pub async fn block_metadata(
 		&self,
@@ -157,9 +322,10 @@ impl Chain {
 
 	/// Fetches the full account record (nonce, balances, …) at a given block.
 	///
-	/// Returns [`AccountInfo`] containing balances, consumers, and nonce data.
+	/// Returns [`AccountInfo`] with the nonce, the `consumers`/`providers`/`sufficients` reference
+	/// counters, and [`AccountData`] balances including `flags`. Unknown accounts yield the default.
 	///
-	/// missing, or the RPC call fails.
+	/// Errors when the account id is malformed, the block cannot be resolved, or the RPC call fails.
 	pub async fn account_info(
 		&self,
 		account_id: impl Into<AccountIdLike>,
@@ -176,6 +342,27 @@ impl Chain {
 		})?)
 	}
 
+	/// Reports whether `System::Account` holds an entry for the account at a given block.
+	///
+	/// Returns `false` for accounts that were never funded or have been reaped.
+	///
+	/// Errors mirror [`Chain::account_info`].
+	pub async fn account_exists(
+		&self,
+		account_id: impl Into<AccountIdLike>,
+		at: impl Into<HashStringNumber>,
+	) -> Result<bool, Error> {
+		let account_id = conversions::account_id_like::to_account_id(account_id)?;
+		let at = conversions::hash_string_number::to_hash(self, at).await?;
+		let retry_on_error = self.should_retry_on_error();
+
+		Ok(retry!(retry_on_error, {
+			SystemStorage::Account::fetch(&self.client.rpc_client, &account_id, Some(at))
+				.await
+				.map(|x| x.is_some())
+		})?)
+	}
+
 	/// Converts a block hash into its block height when possible.
 	///
 	pub async fn block_height(&self, at: impl Into<HashString>) -> Result<Option<u32>, Error> {
@@ -239,30 +426,16 @@ impl Chain {
 			));
 		};
 
-		for item in &header.digest.logs {
-			let (id, value) = match &item {
-				DigestItem::PreRuntime(id, value) => (id, value),
-				_ => continue,
-			};
-
-			if !id.eq(&consensus::babe::BABE_ENGINE_ID) {
-				continue;
-			}
-
-			let mut v = value.as_slice();
-			let pre_digest = consensus::babe::PreDigest::decode(&mut v).map_err(|e| Error::Decode(e.to_string()))?;
-
-			let validators = avail::session::storage::Validators::fetch(&self.client.rpc_client, Some(hash)).await?;
-			let Some(validators) = validators else {
-				return Err(Error::not_found_with_op(
-					error_ops::ErrorOperation::ChainBlockAuthor,
-					std::format!("No validators found for block hash: {:?}", hash),
-				));
-			};
+		let validators = avail::session::storage::Validators::fetch(&self.client.rpc_client, Some(hash)).await?;
+		let Some(validators) = validators else {
+			return Err(Error::not_found_with_op(
+				error_ops::ErrorOperation::ChainBlockAuthor,
+				std::format!("No validators found for block hash: {:?}", hash),
+			));
+		};
 
-			if let Some(account_id) = validators.get(pre_digest.authority_index() as usize) {
-				return Ok(account_id.clone());
-			}
+		if let Some(account_id) = header.author(&validators) {
+			return Ok(account_id);
 		}
 
 		Err(Error::not_found_with_op(
@@ -291,6 +464,16 @@ impl Chain {
 		Ok(count as usize)
 	}
 
+	/// Counts the extrinsics included in the specified block.
+	///
+	/// Only extrinsic metadata is requested, so the block body is not transferred.
+	pub async fn block_extrinsic_count(&self, at: impl Into<HashStringNumber>) -> Result<usize, Error> {
+		let extrinsics = self
+			.extrinsics(at, None, Default::default(), rpc::DataFormat::None)
+			.await?;
+		Ok(extrinsics.len())
+	}
+
 	/// Retrieves the dispatch-class weight totals for the specified block.
 	///
 	/// Returns the per-dispatch-class weight totals or an error if unavailable.
@@ -311,13 +494,79 @@ impl Chain {
 		Ok(weight)
 	}
 
+	/// Reports whether a block is finalized, included, discarded by a fork, not yet produced, or pruned.
+	///
+	/// A block given by hash is compared with the canonical hash at its height, so blocks from abandoned
+	/// forks are reported as [`BlockState::Discarded`]. A block given by height is always the canonical
+	/// one. Finalized blocks whose runtime version can no longer be read are reported as
+	/// [`BlockState::Pruned`].
+	///
+	/// # Errors
+	/// Returns [`Error::Validation`] when `at` cannot be parsed, and propagates RPC failures.
+	pub async fn block_state(&self, at: impl Into<HashStringNumber>) -> Result<BlockState, Error> {
+		let at = HashNumber::try_from(at.into())
+			.map_err(|e| Error::validation_with_op(error_ops::ErrorOperation::ChainBlockState, e))?;
+		let info = self.info().await?;
+
+		let (hash, height) = match at {
+			HashNumber::Number(height) => {
+				if height > info.best_height {
+					return Ok(BlockState::DoesNotExist);
+				}
+				let Some(hash) = self.block_hash(Some(height)).await? else {
+					return Ok(BlockState::classify(height, info.best_height, info.finalized_height, true, false));
+				};
+				(hash, height)
+			},
+			HashNumber::Hash(hash) | HashNumber::HashAndNumber((hash, _)) => {
+				let Some(header) = self.block_header(Some(hash)).await? else {
+					return Ok(BlockState::DoesNotExist);
+				};
+				(hash, header.number)
+			},
+		};
+
+		let canonical = height > info.best_height || self.block_hash(Some(height)).await? == Some(hash);
+		let available = !canonical || height > info.finalized_height || self.has_state(hash).await?;
+		Ok(BlockState::classify(height, info.best_height, info.finalized_height, canonical, available))
+	}
+
+	/// Returns false when the node rejects a state query at `hash`, which is how pruned state surfaces.
+	async fn has_state(&self, hash: H256) -> Result<bool, Error> {
+		let result = retry!(self.should_retry_on_error(), {
+			rpc::state::get_runtime_version(&self.client.rpc_client, Some(hash)).await
+		});
+		match result.map_err(Error::from) {
+			Ok(_) => Ok(true),
+			Err(e) if e.code() == ErrorCode::Rpc => Ok(false),
+			Err(e) => Err(e),
+		}
+	}
+
 	/// Quick snapshot of both the best and finalized heads.
 	pub async fn info(&self) -> Result<ChainInfo, RpcError> {
 		retry!(self.should_retry_on_error(), { rpc::custom::chain_info(&self.client.rpc_client).await })
 	}
 
+	/// Returns the node implementation name (e.g. `Avail Node`).
+	pub async fn system_name(&self) -> Result<String, RpcError> {
+		retry!(self.should_retry_on_error(), { rpc::system::name(&self.client.rpc_client).await })
+	}
+
+	/// Returns the node implementation version.
+	pub async fn system_version(&self) -> Result<String, RpcError> {
+		retry!(self.should_retry_on_error(), { rpc::system::version(&self.client.rpc_client).await })
+	}
+
+	/// Returns the chain name from the chain spec.
+	pub async fn system_chain(&self) -> Result<String, RpcError> {
+		retry!(self.should_retry_on_error(), { rpc::system::chain(&self.client.rpc_client).await })
+	}
+
 	/// Builds a payload from a call and signs it with sensible defaults.
 	///
+	/// Fails with a validation error when `options` include the `CheckMetadataHash` extension, which the
+	/// returned type cannot carry; submit through [`Self::sign_and_submit_call`] instead.
 	pub async fn build_extrinsic_from_call<'a>(
 		&self,
 		signer: &Keypair,
@@ -327,31 +576,53 @@ impl Chain {
 		let account_id = signer.public_key().to_account_id();
 
 		let resolved = options.resolve(&self.client, &account_id, self.retry_on_error).await?;
+		resolved.ensure_without_metadata_hash()?;
 
 		let extension = avail_rust_core::Extension::from(&resolved);
-		let implicit = avail_rust_core::ExtensionImplicit {
-			spec_version: self.client.online_client().spec_version(),
-			tx_version: self.client.online_client().transaction_version(),
-			genesis_hash: self.client.online_client().genesis_hash(),
-			fork_hash: resolved.mortality.block_hash,
-		};
+		let implicit = self.extension_implicit(&resolved);
 
 		let signature = avail_rust_core::SignedPayload::sign_static(call, &extension, &implicit, signer);
 
 		Ok(avail_rust_core::ExtrinsicBorrowed::new_signed(account_id, signature, extension, call))
 	}
 
+	/// Returns the implicit signed data (runtime versions, genesis and mortality checkpoint) for `resolved`.
+	pub fn extension_implicit(&self, resolved: &ResolvedOptions) -> ExtensionImplicit {
+		ExtensionImplicit {
+			spec_version: self.client.online_client().spec_version(),
+			tx_version: self.client.online_client().transaction_version(),
+			genesis_hash: resolved.genesis_hash,
+			fork_hash: resolved.mortality.block_hash,
+		}
+	}
+
 	pub async fn submit(&self, extrinsic: &[u8]) -> Result<H256, RpcError> {
-		retry!(self.should_retry_on_error(), {
+		let result = retry!(self.should_retry_on_error(), {
 			rpc::author::submit_extrinsic(&self.client.rpc_client, extrinsic).await
-		})
+		});
+		self.client.online_client().record_submission(extrinsic, result.is_ok());
+		result
+	}
+
+	/// Returns the encoded extrinsics currently waiting in the node's transaction pool.
+	pub async fn pending_extrinsics(&self) -> Result<Vec<Vec<u8>>, RpcError> {
+		retry!(self.should_retry_on_error(), { rpc::author::pending_extrinsics(&self.client.rpc_client).await })
+	}
+
+	/// Returns the hashes of the extrinsics currently waiting in the node's transaction pool.
+	pub async fn pending_extrinsic_hashes(&self) -> Result<Vec<H256>, RpcError> {
+		let extrinsics = self.pending_extrinsics().await?;
+		Ok(extrinsics
+			.iter()
+			.map(|x| H256(sp_crypto_hashing::blake2_256(x)))
+			.collect())
 	}
 
 	/// Signs the payload and submits it in one step.
-	pub async fn sign_and_submit_payload(
+	pub async fn sign_and_submit_payload<E: Encode + Clone, I: Encode>(
 		&self,
 		signer: &Keypair,
-		payload: SignedPayload<'_>,
+		payload: SignedPayload<'_, E, I>,
 	) -> Result<H256, RpcError> {
 		let signature = payload.sign(signer);
 
@@ -378,17 +649,29 @@ impl Chain {
 	) -> Result<SubmittedTransaction, Error> {
 		let account_id = signer.public_key().to_account_id();
 		let resolved = options.resolve(&self.client, &account_id, self.retry_on_error).await?;
+		self.sign_and_submit_call_resolved(signer, call, &resolved).await
+	}
 
-		let extension = Extension::from(&resolved);
-		let implicit = ExtensionImplicit {
-			spec_version: self.client.online_client().spec_version(),
-			tx_version: self.client.online_client().transaction_version(),
-			genesis_hash: self.client.online_client().genesis_hash(),
-			fork_hash: resolved.mortality.block_hash,
-		};
-
-		let tx_payload = SignedPayload::new(call, &extension, &implicit);
-		let ext_hash = self.sign_and_submit_payload(signer, tx_payload).await?;
+	/// Signs a call with already resolved options and submits it.
+	///
+	/// The options are used exactly as provided; nothing is fetched from the chain.
+	pub async fn sign_and_submit_call_resolved(
+		&self,
+		signer: &Keypair,
+		call: &[u8],
+		resolved: &ResolvedOptions,
+	) -> Result<SubmittedTransaction, Error> {
+		let future = self.sign_and_submit_resolved(signer, call, resolved);
+		#[cfg(feature = "tracing")]
+		let span = crate::utils::tx_submit_span(&signer.public_key().to_account_id(), resolved.nonce, call);
+		#[cfg(feature = "tracing")]
+		let future = tracing::Instrument::instrument(future, span.clone());
+		let ext_hash = future.await?;
+		#[cfg(feature = "tracing")]
+		{
+			span.record("tx_hash", tracing::field::debug(ext_hash));
+			tracing::info!(target: "lib", parent: &span, "Transaction submitted");
+		}
 
 		let start = resolved.mortality.block_height;
 		let end = resolved.mortality.period as u32 + start;
@@ -396,6 +679,27 @@ impl Chain {
 		Ok(SubmittedTransaction::new(self.client.clone(), ext_hash, start, end))
 	}
 
+	/// Signs with the extensions `resolved` calls for, adding `CheckMetadataHash` when a mode is set.
+	async fn sign_and_submit_resolved(
+		&self,
+		signer: &Keypair,
+		call: &[u8],
+		resolved: &ResolvedOptions,
+	) -> Result<H256, RpcError> {
+		let extension = Extension::from(resolved);
+		let implicit = self.extension_implicit(resolved);
+		let Some(mode) = resolved.metadata_hash_mode else {
+			return self
+				.sign_and_submit_payload(signer, SignedPayload::new(call, &extension, &implicit))
+				.await;
+		};
+
+		let extension = ExtensionWithMetadataHash::new(extension, mode);
+		let implicit = ExtensionImplicitWithMetadataHash::new(implicit, resolved.metadata_hash);
+		self.sign_and_submit_payload(signer, SignedPayload::new(call, &extension, &implicit))
+			.await
+	}
+
 	/// Runs a `state_call` and returns the raw response string.
 	pub async fn state_call(&self, method: &str, data: &[u8], at: Option<H256>) -> Result<String, RpcError> {
 		retry!(self.should_retry_on_error(), { rpc::state::call(&self.client.rpc_client, method, data, at).await })
@@ -411,6 +715,52 @@ impl Chain {
 		retry!(self.should_retry_on_error(), { rpc::state::get_storage(&self.client.rpc_client, key, at).await })
 	}
 
+	/// Reads a storage entry by its raw key and returns the undecoded SCALE bytes, if present.
+	///
+	/// Useful for storage items that have no typed definition in this crate.
+	pub async fn storage_raw(&self, key: &[u8], at: Option<H256>) -> Result<Option<Vec<u8>>, Error> {
+		let key = std::format!("0x{}", const_hex::encode(key));
+		Ok(self.state_get_storage(&key, at).await?)
+	}
+
+	/// Reads many raw storage entries at the same block.
+	///
+	/// Keys are split into `state_queryStorageAt` requests of at most
+	/// [`Chain::fetch_many_chunk_size`] keys, a few of which run concurrently.
+	/// Values are returned in the same order as `keys`; `None` marks a missing entry.
+	/// When `at` is `None` the current best block is used for every chunk.
+	pub async fn fetch_many(&self, keys: &[Vec<u8>], at: Option<H256>) -> Result<Vec<Option<Vec<u8>>>, Error> {
+		let at = match at {
+			Some(at) => at,
+			None => self.client.best().block_hash().await?,
+		};
+
+		fetch_chunked(keys, self.fetch_many_chunk_size, FETCH_MANY_CONCURRENCY, |chunk| async move {
+			let _permit = self.client.concurrency_permit().await;
+			let hex_keys: Vec<String> = chunk
+				.iter()
+				.map(|x| std::format!("0x{}", const_hex::encode(x)))
+				.collect();
+			let change_sets = retry!(self.should_retry_on_error(), {
+				rpc::state::query_storage_at(&self.client.rpc_client, &hex_keys, Some(at)).await
+			})?;
+
+			let values: HashMap<String, Option<String>> = change_sets.into_iter().flat_map(|x| x.changes).collect();
+			hex_keys
+				.iter()
+				.map(|key| {
+					let Some(value) = values.get(key).cloned().flatten() else {
+						return Ok(None);
+					};
+					let value = const_hex::decode(value.trim_start_matches("0x"))
+						.map_err(|e| RpcError::MalformedResponse(e.to_string()))?;
+					Ok(Some(value))
+				})
+				.collect()
+		})
+		.await
+	}
+
 	/// Lists storage keys under a prefix, one page at a time.
 	pub async fn state_get_keys_paged(
 		&self,
@@ -487,6 +837,48 @@ impl Chain {
 		inner(self, at).await
 	}
 
+	/// Returns the GRANDPA authority set (ids and weights) active in the state of block `at`.
+	pub async fn grandpa_authorities(&self, at: Option<H256>) -> Result<AuthorityList, RpcError> {
+		retry!(self.should_retry_on_error(), {
+			runtime_api::api_grandpa_authorities(&self.client.rpc_client, at).await
+		})
+	}
+
+	/// Returns the id of the GRANDPA authority set active in the state of block `at`.
+	pub async fn grandpa_current_set_id(&self, at: Option<H256>) -> Result<SetId, RpcError> {
+		retry!(self.should_retry_on_error(), {
+			runtime_api::api_grandpa_current_set_id(&self.client.rpc_client, at).await
+		})
+	}
+
+	/// Fetches the GRANDPA justification for a block and verifies it against the authority set.
+	///
+	/// The authority set and set id are read from the parent block's state, since that set is the one voting
+	/// on the block. Returns `Ok(false)` when the justification targets another block or lacks enough valid
+	/// signatures, and a not-found error when the node holds no justification for the block.
+	pub async fn verify_justification(&self, block_hash: H256) -> Result<bool, Error> {
+		let Some(justification) = self.block_justification(block_hash).await? else {
+			return Err(Error::not_found_with_op(
+				error_ops::ErrorOperation::ChainVerifyJustification,
+				"No justification found for requested block",
+			));
+		};
+		if justification.commit.target_hash != block_hash {
+			return Ok(false);
+		}
+
+		let Some(header) = self.block_header(Some(block_hash)).await? else {
+			return Err(Error::not_found_with_op(
+				error_ops::ErrorOperation::ChainVerifyJustification,
+				"No block header found for requested block",
+			));
+		};
+		let authorities = self.grandpa_authorities(Some(header.parent_hash)).await?;
+		let set_id = self.grandpa_current_set_id(Some(header.parent_hash)).await?;
+
+		Ok(justification.verify(set_id, &authorities))
+	}
+
 	/// Queries the runtime for fee information about an encoded extrinsic.
 	///
 	/// Returns dispatch info describing the estimated fee and weight.
@@ -515,6 +907,14 @@ impl Chain {
 		})
 	}
 
+	/// Returns fee information for an already signed and encoded extrinsic.
+	///
+	/// Unlike [`SubmittableTransaction::estimate_extrinsic_fees`](crate::SubmittableTransaction::estimate_extrinsic_fees)
+	/// this does not need a signer, so it works on bytes produced by offline signing.
+	pub async fn query_fee_info(&self, extrinsic: &[u8], at: Option<H256>) -> Result<RuntimeDispatchInfo, Error> {
+		Ok(self.transaction_payment_query_info(extrinsic.to_vec(), at).await?)
+	}
+
 	/// Queries the runtime for fee information about an encoded call.
 	///
 	/// Returns dispatch info describing the estimated fee and weight.
@@ -529,6 +929,14 @@ impl Chain {
 		})
 	}
 
+	/// Returns the dispatch weight of an arbitrary call.
+	///
+	/// Useful for computing `max_weight` for calls that are not wrapped in a submittable.
+	pub async fn call_weight(&self, call: &ExtrinsicCall, at: Option<H256>) -> Result<Weight, Error> {
+		let info = self.transaction_payment_query_call_info(call.encode(), at).await?;
+		Ok(info.weight)
+	}
+
 	/// Retrieves detailed fee components for an encoded call.
 	///
 	/// Returns the fee breakdown for executing the call.
@@ -549,6 +957,12 @@ impl Chain {
 		retry!(self.should_retry_on_error(), { rpc::kate::block_length(&self.client.rpc_client, at).await })
 	}
 
+	/// Returns the maximum block matrix (rows, cols, chunk size) in effect at `at`, or at the best block.
+	pub async fn block_dimensions(&self, at: Option<H256>) -> Result<BlockDimensions, Error> {
+		let length = self.kate_block_length(at).await?;
+		Ok(BlockDimensions::from(&length))
+	}
+
 	/// Produces the KATE data proof (and optional addressed message) for the given extrinsic index.
 	///
 	pub async fn kate_query_data_proof(
@@ -598,9 +1012,13 @@ impl Chain {
 	///
 	/// Returns `Ok(())` on success or an error if the submission fails.
 	pub async fn blob_submit_blob(&self, metadata_signed_transaction: &[u8], blob: &[u8]) -> Result<(), Error> {
-		Ok(retry!(self.should_retry_on_error(), {
+		let result = retry!(self.should_retry_on_error(), {
 			rpc::blob::submit_blob(&self.client.rpc_client, metadata_signed_transaction, blob).await
-		})?)
+		});
+		self.client
+			.online_client()
+			.record_submission(metadata_signed_transaction, result.is_ok());
+		Ok(result?)
 	}
 
 	pub async fn blob_get_blob(&self, blob_hash: H256, block_hash: Option<H256>) -> Result<Blob, Error> {
@@ -698,9 +1116,240 @@ impl Chain {
 		inner(self, at).await
 	}
 
+	/// Returns the target block time declared by the runtime.
+	///
+	/// Reads `Babe::ExpectedBlockTime` from the cached metadata and falls back to twice
+	/// `Timestamp::MinimumPeriod` when the runtime has no Babe pallet.
+	pub fn block_time(&self) -> Result<Duration, Error> {
+		let metadata = self.client.online_client().metadata();
+		let constant = |pallet: &str, name: &str| {
+			let constant = metadata.pallet_by_name(pallet)?.constant_by_name(name)?;
+			Some(u64::decode(&mut constant.value()))
+		};
+
+		let millis = match (constant("Babe", "ExpectedBlockTime"), constant("Timestamp", "MinimumPeriod")) {
+			(Some(value), _) => value,
+			(None, Some(value)) => value.map(|x| x.saturating_mul(2)),
+			(None, None) => {
+				return Err(Error::not_found_with_op(
+					error_ops::ErrorOperation::ChainBlockTime,
+					"Neither Babe::ExpectedBlockTime nor Timestamp::MinimumPeriod constant found",
+				));
+			},
+		};
+		let millis =
+			millis.map_err(|e| Error::decode_with_op(error_ops::ErrorOperation::ChainBlockTime, e.to_string()))?;
+
+		Ok(Duration::from_millis(millis))
+	}
+
 	/// Reports whether RPC helpers should retry after encountering errors.
 	pub fn should_retry_on_error(&self) -> bool {
 		self.retry_on_error
 			.resolve(self.client.retry_policy() != RetryPolicy::Disabled)
 	}
 }
+
+/// Runs `f` over `keys` in chunks of `chunk_size`, keeping at most `concurrency` chunks in flight.
+///
+/// Results are concatenated in the order of `keys`.
+async fn fetch_chunked<'a, K, V, F, Fut>(
+	keys: &'a [K],
+	chunk_size: usize,
+	concurrency: usize,
+	f: F,
+) -> Result<Vec<V>, Error>
+where
+	F: Fn(&'a [K]) -> Fut,
+	Fut: Future<Output = Result<Vec<V>, Error>>,
+{
+	let chunks: Vec<Vec<V>> = futures::stream::iter(keys.chunks(chunk_size.max(1)))
+		.map(f)
+		.buffered(concurrency.max(1))
+		.try_collect()
+		.await?;
+
+	Ok(chunks.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use avail_rust_core::types::RuntimePhase;
+	use codec::Compact;
+
+	#[tokio::test]
+	async fn storage_events_decode_with_block_metadata() -> Result<(), Error> {
+		use crate::{
+			LOCAL_ENDPOINT,
+			clients::mock_client::{MockClient, metadata_bytes, metadata_bytes_with_event},
+			subxt_rpcs::RpcClient,
+		};
+		use serde_json::value::RawValue;
+
+		let json = |value: String| RawValue::from_string(serde_json::to_string(&value).unwrap()).unwrap();
+		// One record applied by extrinsic 0, holding `event` and no topics.
+		let events = |event: &[u8]| {
+			let mut bytes = Compact(1u32).encode();
+			bytes.extend(RuntimePhase::ApplyExtrinsic(0).encode());
+			bytes.extend(event);
+			bytes.extend(Vec::<H256>::new().encode());
+			const_hex::encode_prefixed(bytes)
+		};
+
+		// The client's own metadata has an empty event type, so using it would split off no event bytes.
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+
+		// Before a runtime upgrade events are two bytes long, after it they are four.
+		commander
+			.add_ok("state_getMetadata", json(const_hex::encode_prefixed(metadata_bytes_with_event::<[u8; 2]>(&[]))));
+		commander.add_ok("state_getStorage", json(events(&[1, 2])));
+		commander
+			.add_ok("state_getMetadata", json(const_hex::encode_prefixed(metadata_bytes_with_event::<[u8; 4]>(&[]))));
+		commander.add_ok("state_getStorage", json(events(&[1, 2, 3, 4])));
+
+		let before = client.chain().storage_events(H256::repeat_byte(1)).await?;
+		assert_eq!(before.len(), 1);
+		assert_eq!(before[0].event, vec![1, 2]);
+
+		let after = client.chain().storage_events(H256::repeat_byte(2)).await?;
+		assert_eq!(after.len(), 1);
+		assert_eq!(after[0].event, vec![1, 2, 3, 4]);
+		assert_eq!(commander.call_count("state_getMetadata"), 3);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn storage_events_reject_records_above_limit() -> Result<(), Error> {
+		use crate::{
+			LOCAL_ENDPOINT,
+			clients::mock_client::{MockClient, metadata_bytes},
+			subxt_rpcs::RpcClient,
+		};
+		use serde_json::value::RawValue;
+
+		let json = |value: String| RawValue::from_string(serde_json::to_string(&value).unwrap()).unwrap();
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+
+		// Two records with an empty event type: phase, no event bytes, no topics.
+		let record = [RuntimePhase::Initialization.encode(), Vec::<H256>::new().encode()].concat();
+		let bytes = [Compact(2u32).encode(), record.clone(), record].concat();
+		commander.add_ok("state_getMetadata", json(const_hex::encode_prefixed(metadata_bytes(&[]))));
+		commander.add_ok("state_getStorage", json(const_hex::encode_prefixed(bytes)));
+
+		let limits = DecodeLimits { max_vec_len: 1, ..Default::default() };
+		let err = client
+			.chain()
+			.storage_events_with_limits(H256::zero(), &limits)
+			.await
+			.unwrap_err();
+		assert_eq!(err.code(), ErrorCode::Decode);
+
+		Ok(())
+	}
+
+	#[test]
+	fn block_state_classify() {
+		assert_eq!(BlockState::classify(101, 100, 90, true, true), BlockState::DoesNotExist);
+		assert_eq!(BlockState::classify(101, 100, 90, false, false), BlockState::DoesNotExist);
+		assert_eq!(BlockState::classify(95, 100, 90, true, true), BlockState::Included);
+		assert_eq!(BlockState::classify(90, 100, 90, true, true), BlockState::Finalized);
+		assert_eq!(BlockState::classify(95, 100, 90, false, true), BlockState::Discarded);
+		assert_eq!(BlockState::classify(50, 100, 90, false, true), BlockState::Discarded);
+		assert_eq!(BlockState::classify(5, 100, 90, true, false), BlockState::Pruned);
+	}
+
+	#[test]
+	fn block_state_string_round_trip() {
+		let states = [
+			BlockState::Included,
+			BlockState::Finalized,
+			BlockState::Discarded,
+			BlockState::DoesNotExist,
+			BlockState::Pruned,
+		];
+		for state in states {
+			assert_eq!(state.to_string().parse::<BlockState>(), Ok(state));
+
+			let json = serde_json::to_string(&state).unwrap();
+			assert_eq!(json, std::format!("\"{}\"", state));
+			assert_eq!(serde_json::from_str::<BlockState>(&json).unwrap(), state);
+		}
+		assert_eq!("Discarded".parse::<BlockState>(), Ok(BlockState::Discarded));
+
+		let err = "Orphaned".parse::<BlockState>().unwrap_err();
+		assert_eq!(err, ParseBlockStateError("Orphaned".into()));
+		assert_eq!(err.to_string(), "Unknown block state: Orphaned");
+	}
+	use std::{
+		sync::atomic::{AtomicUsize, Ordering},
+		time::Duration,
+	};
+
+	#[tokio::test]
+	async fn fetch_chunked_keeps_order() {
+		let keys: Vec<u32> = (0..2500).collect();
+		let calls = AtomicUsize::new(0);
+		let in_flight = AtomicUsize::new(0);
+		let max_in_flight = AtomicUsize::new(0);
+
+		let values = fetch_chunked(&keys, 1000, 2, |chunk| {
+			let (calls, in_flight, max_in_flight) = (&calls, &in_flight, &max_in_flight);
+			async move {
+				calls.fetch_add(1, Ordering::SeqCst);
+				let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+				max_in_flight.fetch_max(current, Ordering::SeqCst);
+				// Later chunks finish first to make sure ordering does not depend on completion order.
+				let delay = 30 - (chunk[0] / 100) as u64;
+				tokio::time::sleep(Duration::from_millis(delay)).await;
+				in_flight.fetch_sub(1, Ordering::SeqCst);
+				Ok(chunk.iter().map(|x| x * 2).collect())
+			}
+		})
+		.await
+		.unwrap();
+
+		assert_eq!(calls.load(Ordering::SeqCst), 3);
+		assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+		assert_eq!(values.len(), keys.len());
+		assert!(values.iter().zip(&keys).all(|(v, k)| *v == k * 2));
+	}
+
+	#[tokio::test]
+	async fn max_concurrency_caps_parallel_requests() -> Result<(), Error> {
+		use crate::{
+			LOCAL_ENDPOINT,
+			clients::mock_client::{MockClient, metadata_bytes},
+			subxt_rpcs::RpcClient,
+		};
+		use serde_json::value::RawValue;
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+		client.set_max_concurrency(2);
+
+		let change_set = std::format!(r#"[{{"block":"{:?}","changes":[]}}]"#, H256::zero());
+		for _ in 0..8 {
+			commander.add_ok("state_queryStorageAt", RawValue::from_string(change_set.clone()).unwrap());
+		}
+		commander.set_delay(Some(Duration::from_millis(50)));
+
+		let keys: Vec<Vec<u8>> = (0..8u8).map(|x| vec![x]).collect();
+		let values = client
+			.chain()
+			.fetch_many_chunk_size(1)
+			.fetch_many(&keys, Some(H256::zero()))
+			.await?;
+		assert_eq!(values, vec![None; 8]);
+		assert_eq!(commander.call_count("state_queryStorageAt"), 8);
+		assert_eq!(commander.max_in_flight(), 2);
+
+		Ok(())
+	}
+}
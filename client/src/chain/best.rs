@@ -77,6 +77,11 @@ impl Best {
 		self.head.account_info(account_id).await
 	}
 
+	/// Returns whether the account exists at the current best block.
+	pub async fn account_exists(&self, account_id: impl Into<AccountIdLike>) -> Result<bool, Error> {
+		self.head.account_exists(account_id).await
+	}
+
 	/// Indicates whether best-head queries retry on failures.
 	pub fn should_retry_on_error(&self) -> bool {
 		self.head.should_retry_on_error()
@@ -82,6 +82,11 @@ impl Finalized {
 		self.head.account_info(account_id).await
 	}
 
+	/// Returns whether the account exists at the current finalized block.
+	pub async fn account_exists(&self, account_id: impl Into<AccountIdLike>) -> Result<bool, Error> {
+		self.head.account_exists(account_id).await
+	}
+
 	/// Indicates whether finalized-head queries retry on failures.
 	pub fn should_retry_on_error(&self) -> bool {
 		self.head.should_retry_on_error()
@@ -108,6 +108,12 @@ impl Head {
 		self.chain.account_info(account_id, at).await
 	}
 
+	/// Returns whether the account exists at the current head.
+	pub async fn account_exists(&self, account_id: impl Into<AccountIdLike>) -> Result<bool, Error> {
+		let at = self.block_hash().await?;
+		self.chain.account_exists(account_id, at).await
+	}
+
 	/// Indicates whether head queries retry on failures.
 	pub fn should_retry_on_error(&self) -> bool {
 		self.chain.should_retry_on_error()
@@ -3,7 +3,7 @@ pub mod best;
 pub mod finalized;
 pub mod head;
 
-pub use api::Chain;
+pub use api::{BlockState, Chain, ParseBlockStateError};
 pub use best::Best;
 pub use finalized::Finalized;
 pub use head::{Head, HeadKind};
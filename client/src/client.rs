@@ -2,14 +2,31 @@
 
 use super::clients::OnlineClient;
 use crate::{
+	app_submissions::AppSubmissionPager,
 	block::Block,
 	chain::{Best, Chain, Finalized, Head, HeadKind},
+	constants::{MAINNET_GENESIS_HASH, TURING_GENESIS_HASH},
+	conversions,
+	error_ops::ErrorOperation,
+	platform,
 	retry_policy::RetryPolicy,
+	submission::{SubmittedTransaction, TransactionSequence},
 	subscription::SubscribeApi,
 	subxt_rpcs::RpcClient,
+	subxt_signer::sr25519::Keypair,
 	transaction_api::TransactionApi,
+	transaction_options::Options,
 };
-use avail_rust_core::{rpc::Error as RpcError, types::metadata::HashStringNumber};
+use avail_rust_core::{
+	AccountId, AccountIdLike, AvailHeader, BlockInfo, ExtrinsicCall, H256, account_id_to_ss58,
+	avail::RuntimeCall,
+	rpc::{self, Error as RpcError},
+	types::metadata::HashStringNumber,
+};
+use codec::{Decode, Encode};
+use futures::{Stream, StreamExt, TryStreamExt, future::Either, stream};
+use std::{str::FromStr, time::Duration};
+use tokio::sync::OwnedSemaphorePermit;
 #[cfg(feature = "tracing")]
 use tracing_subscriber::util::TryInitError;
 
@@ -24,47 +41,164 @@ pub enum TracingFormat {
 	Json,
 }
 
+/// Transport used by a [`Client`] to reach the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportKind {
+	/// Built-in HTTP transport created by [`Client::connect`] or [`Client::new_pooled`].
+	Http,
+	/// User-provided transport passed to [`Client::from_rpc_client`] or [`Client::from_components`].
+	Custom,
+}
+
+/// Avail network a [`Client`] is connected to, as reported by [`Client::network`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+	Mainnet,
+	Turing,
+	/// A development or local chain, such as a node started with `--dev`.
+	Local,
+	Unknown,
+}
+
+impl Network {
+	/// Maps a genesis hash to a public network; anything else is [`Network::Unknown`].
+	pub fn from_genesis_hash(hash: H256) -> Self {
+		let is = |value: &str| H256::from_str(value).is_ok_and(|x| x == hash);
+		if is(MAINNET_GENESIS_HASH) {
+			Self::Mainnet
+		} else if is(TURING_GENESIS_HASH) {
+			Self::Turing
+		} else {
+			Self::Unknown
+		}
+	}
+}
+
+/// Inputs used when signing a transaction, gathered for diagnosing signature mismatches.
+#[derive(Debug, Clone)]
+pub struct SigningContext {
+	/// Genesis hash of the connected chain.
+	pub genesis: H256,
+	/// Runtime spec version the client signs with.
+	pub spec_version: u32,
+	/// Transaction version the client signs with.
+	pub transaction_version: u32,
+	/// Next nonce of the account, as reported by the node.
+	pub nonce: u32,
+	/// Finalized block used as the default mortality checkpoint.
+	pub finalized_info: BlockInfo,
+}
+
 /// Primary entry point used throughout the SDK to interact with the node.
 #[derive(Clone)]
 pub struct Client {
 	online_client: OnlineClient,
 	pub rpc_client: RpcClient,
+	endpoint: Option<String>,
+	transport_kind: TransportKind,
 }
 
 impl std::fmt::Debug for Client {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		f.debug_struct("Client").finish()
+		f.debug_struct("Client")
+			.field("endpoint", &self.endpoint)
+			.field("transport_kind", &self.transport_kind)
+			.finish()
 	}
 }
 
+/// Environment variable read by [`ConnectionOptions::from_env`] for the request timeout, in milliseconds.
+pub const REQUEST_TIMEOUT_ENV: &str = "AVAIL_RPC_REQUEST_TIMEOUT_MS";
+
+/// How often [`Client::subscribe_best_headers`] polls the best header when the transport has no subscriptions.
+pub const BEST_HEADERS_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
 /// Controls how a [`Client`] connects to an RPC endpoint.
+///
+/// Built with [`ConnectionOptions::new`] or from an endpoint string, then adjusted through the builder
+/// methods; new settings may be added without breaking callers.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct ConnectionOptions {
 	pub endpoint: String,
 	pub retry_policy: RetryPolicy,
+	/// Upper bound for every RPC request; requests that take longer fail with
+	/// [`Error::Timeout`](crate::Error::Timeout). `None` waits indefinitely.
+	pub request_timeout: Option<Duration>,
 }
 
-impl From<&str> for ConnectionOptions {
-	fn from(value: &str) -> Self {
+impl ConnectionOptions {
+	/// Creates options for `endpoint` with the default retry policy and no request timeout.
+	pub fn new(endpoint: impl Into<String>) -> Self {
 		Self {
-			endpoint: value.to_owned(),
+			endpoint: endpoint.into(),
 			retry_policy: RetryPolicy::Enabled,
+			request_timeout: None,
+		}
+	}
+
+	/// Creates options for `endpoint`, reading the request timeout from [`REQUEST_TIMEOUT_ENV`] when set.
+	///
+	/// # Errors
+	/// Returns [`Error::Validation`](crate::Error::Validation) when the variable is set but is not a
+	/// whole number of milliseconds.
+	pub fn from_env(endpoint: impl Into<String>) -> Result<Self, crate::Error> {
+		let mut options = Self::new(endpoint);
+		if let Ok(value) = std::env::var(REQUEST_TIMEOUT_ENV) {
+			options.request_timeout = Some(parse_request_timeout(&value)?);
 		}
+		Ok(options)
+	}
+
+	/// Sets the retry policy used while connecting and inherited by the client.
+	pub fn retry_policy(mut self, value: RetryPolicy) -> Self {
+		self.retry_policy = value;
+		self
+	}
+
+	/// Bounds every RPC request made by the client to `value`.
+	pub fn request_timeout(mut self, value: Duration) -> Self {
+		self.request_timeout = Some(value);
+		self
+	}
+}
+
+fn parse_request_timeout(value: &str) -> Result<Duration, crate::Error> {
+	let millis = value.trim().parse::<u64>().map_err(|e| {
+		crate::Error::validation_with_op(
+			ErrorOperation::ClientConnectionOptions,
+			std::format!("Invalid {} value {:?}: {}", REQUEST_TIMEOUT_ENV, value, e),
+		)
+	})?;
+	Ok(Duration::from_millis(millis))
+}
+
+impl From<&str> for ConnectionOptions {
+	fn from(value: &str) -> Self {
+		Self::new(value)
 	}
 }
 
 impl From<&String> for ConnectionOptions {
 	fn from(value: &String) -> Self {
-		Self::from(value.as_str())
+		Self::new(value.as_str())
 	}
 }
 
 impl From<String> for ConnectionOptions {
+	/// Uses the default retry policy and no request timeout; see [`ConnectionOptions::from_env`].
 	fn from(value: String) -> Self {
-		Self { endpoint: value, retry_policy: RetryPolicy::Enabled }
+		Self::new(value)
 	}
 }
 
+/// Error code the transaction pool uses for transactions it already holds.
+const TX_ALREADY_IMPORTED: i32 = 1013;
+
+fn is_already_imported(error: &RpcError) -> bool {
+	matches!(error, RpcError::Rpc(crate::subxt_rpcs::Error::User(e)) if e.code == TX_ALREADY_IMPORTED)
+}
+
 impl Client {
 	/// Connects to an HTTP endpoint.
 	/// Returns an error if transport initialization or handshake fails.
@@ -79,22 +213,60 @@ impl Client {
 	/// # Ok(()) }
 	/// ```
 	pub async fn connect(options: impl Into<ConnectionOptions>) -> Result<Client, crate::Error> {
-		use super::clients::ReqwestClient;
+		use super::clients::{ReqwestClient, TimeoutClient};
 
 		let options = options.into();
 		retry!(options.retry_policy.resolve(false), {
 			let rpc_client = ReqwestClient::new(&options.endpoint);
-			let rpc_client = RpcClient::new(rpc_client);
+			let rpc_client = match options.request_timeout {
+				Some(timeout) => RpcClient::new(TimeoutClient::new(rpc_client, timeout)),
+				None => RpcClient::new(rpc_client),
+			};
 			Self::from_rpc_client(rpc_client).await.map_err(|e| e.into())
 		})
-		.map(|client| {
+		.map(|mut client| {
 			client.set_retry_policy(options.retry_policy);
+			client.endpoint = Some(options.endpoint.clone());
+			client.transport_kind = TransportKind::Http;
 			client
 		})
 	}
 
+	/// Connects to several HTTP endpoints of the same network and spreads requests over them.
+	///
+	/// Read requests are handed out round-robin. Nonce reads and submissions always go to the first
+	/// endpoint, so a transaction is signed with a nonce read from the node it is sent to. See
+	/// [`PooledClient`](crate::clients::PooledClient) for details.
+	///
+	/// Requests are not bounded by a timeout. To bound them, wrap each connection in a
+	/// [`TimeoutClient`](crate::clients::TimeoutClient), pool them with
+	/// [`PooledClient::new`](crate::clients::PooledClient::new) and pass the pool to
+	/// [`Client::from_rpc_client`].
+	///
+	/// # Errors
+	///
+	/// Returns a validation error when `endpoints` is empty, or an error if bootstrap queries fail.
+	pub async fn new_pooled(endpoints: &[&str]) -> Result<Client, crate::Error> {
+		use super::clients::{PooledClient, ReqwestClient};
+
+		let connections = endpoints.iter().map(|x| ReqwestClient::new(x)).collect();
+		let Some(pool) = PooledClient::new(connections) else {
+			return Err(crate::Error::validation_with_op(
+				ErrorOperation::ClientNewPooled,
+				"At least one endpoint is required",
+			));
+		};
+		let mut client = Self::from_rpc_client(RpcClient::new(pool)).await?;
+		client.endpoint = Some(endpoints[0].to_string());
+		client.transport_kind = TransportKind::Http;
+		Ok(client)
+	}
+
 	/// Builds a client from an existing RPC transport.
 	/// Returns an error if metadata/bootstrap queries fail.
+	///
+	/// The transport is used as is; wrap it in a [`TimeoutClient`](crate::clients::TimeoutClient) to
+	/// bound its requests.
 	pub async fn from_rpc_client(rpc_client: RpcClient) -> Result<Client, RpcError> {
 		let online_client = OnlineClient::new(&rpc_client).await?;
 		Self::from_components(rpc_client, online_client).await
@@ -102,7 +274,12 @@ impl Client {
 
 	/// Wraps pre-built transport and online metadata state into a client.
 	pub async fn from_components(rpc_client: RpcClient, online_client: OnlineClient) -> Result<Client, RpcError> {
-		Ok(Self { online_client, rpc_client })
+		Ok(Self {
+			online_client,
+			rpc_client,
+			endpoint: None,
+			transport_kind: TransportKind::Custom,
+		})
 	}
 
 	#[cfg(feature = "tracing")]
@@ -111,7 +288,7 @@ impl Client {
 	///
 	/// # Examples
 	/// ```no_run
-	/// # use avail_rust_client::Client;
+	/// # use avail_rust_client::{Client, TracingFormat};
 	/// Client::init_tracing(TracingFormat::Plain).expect("tracing already initialized");
 	/// ```
 	pub fn init_tracing(format: TracingFormat) -> Result<(), TryInitError> {
@@ -126,21 +303,203 @@ impl Client {
 		}
 	}
 
+	/// Returns the endpoint this client connected to.
+	///
+	/// Returns `None` when the client was built from a user-provided transport.
+	pub fn endpoint(&self) -> Option<&str> {
+		self.endpoint.as_deref()
+	}
+
+	/// Returns the kind of transport used to reach the node.
+	pub fn transport_kind(&self) -> TransportKind {
+		self.transport_kind
+	}
+
 	/// Returns the underlying [`OnlineClient`] clone.
 	pub fn online_client(&self) -> OnlineClient {
 		self.online_client.clone()
 	}
 
+	/// Returns the SS58 address prefix declared by the runtime's `System::SS58Prefix` constant.
+	pub fn ss58_prefix(&self) -> Result<u16, crate::Error> {
+		let metadata = self.online_client.metadata();
+		let constant = metadata
+			.pallet_by_name("System")
+			.and_then(|x| x.constant_by_name("SS58Prefix"))
+			.ok_or_else(|| {
+				crate::Error::not_found_with_op(
+					ErrorOperation::ClientSs58Prefix,
+					"System::SS58Prefix constant not found",
+				)
+			})?;
+
+		u16::decode(&mut constant.value())
+			.map_err(|e| crate::Error::decode_with_op(ErrorOperation::ClientSs58Prefix, e.to_string()))
+	}
+
+	/// Returns the largest `submit_data` payload accepted by the runtime, read from the cached metadata.
+	pub fn max_app_data_length(&self) -> Result<u32, crate::Error> {
+		let metadata = self.online_client.metadata();
+		let constant = metadata
+			.pallet_by_name("DataAvailability")
+			.and_then(|x| x.constant_by_name("MaxAppDataLength"))
+			.ok_or_else(|| {
+				crate::Error::not_found_with_op(
+					ErrorOperation::ClientMaxAppDataLength,
+					"DataAvailability::MaxAppDataLength constant not found",
+				)
+			})?;
+
+		u32::decode(&mut constant.value())
+			.map_err(|e| crate::Error::decode_with_op(ErrorOperation::ClientMaxAppDataLength, e.to_string()))
+	}
+
+	/// Formats an account id as an SS58 address using the chain's prefix.
+	pub fn ss58_address(&self, account_id: impl Into<AccountIdLike>) -> Result<String, crate::Error> {
+		let account_id = conversions::account_id_like::to_account_id(account_id)?;
+		Ok(account_id_to_ss58(&account_id, self.ss58_prefix()?))
+	}
+
+	/// Identifies the connected network from its genesis hash.
+	///
+	/// Chains with an unknown genesis hash are reported as [`Network::Local`] when the node declares a
+	/// `Development` or `Local` chain type, and [`Network::Unknown`] otherwise.
+	pub async fn network(&self) -> Result<Network, crate::Error> {
+		let network = Network::from_genesis_hash(self.online_client.genesis_hash());
+		if network != Network::Unknown {
+			return Ok(network);
+		}
+
+		let chain_type = rpc::system::chain_type(&self.rpc_client).await?;
+		match chain_type.as_str() {
+			"Development" | "Local" => Ok(Network::Local),
+			_ => Ok(Network::Unknown),
+		}
+	}
+
+	/// Collects the genesis hash, runtime versions, nonce and mortality checkpoint used when `account_id` signs.
+	pub async fn signing_context(&self, account_id: &AccountId) -> Result<SigningContext, crate::Error> {
+		let nonce = self.chain().account_nonce(account_id.clone()).await?;
+		let info = self.chain().info().await?;
+
+		Ok(SigningContext {
+			genesis: self.online_client.genesis_hash(),
+			spec_version: self.online_client.spec_version(),
+			transaction_version: self.online_client.transaction_version(),
+			nonce,
+			finalized_info: BlockInfo::from((info.finalized_hash, info.finalized_height)),
+		})
+	}
+
 	/// Returns a transaction API handle.
 	pub fn tx(&self) -> TransactionApi {
 		TransactionApi(self.clone())
 	}
 
+	/// Returns a builder that submits several calls from `signer` with consecutive nonces.
+	pub fn tx_sequence(&self, signer: &Keypair) -> TransactionSequence {
+		TransactionSequence::new(self.clone(), signer)
+	}
+
+	/// Signs and submits a [`RuntimeCall`] as `signer`.
+	///
+	/// The call is re-encoded together with its pallet and call index, so anything decoded from
+	/// or built as a `RuntimeCall` can be submitted without picking the matching builder.
+	pub async fn submit_runtime_call(
+		&self,
+		call: &RuntimeCall,
+		signer: &Keypair,
+		options: Options,
+	) -> Result<SubmittedTransaction, crate::Error> {
+		let call = ExtrinsicCall::from(call);
+		self.chain().sign_and_submit_call(signer, &call.encode(), options).await
+	}
+
+	/// Broadcasts an already signed extrinsic through this client and to every HTTP endpoint in
+	/// `endpoints`, returning its hash as soon as one node accepts it.
+	///
+	/// A node that reports the transaction as already imported counts as accepted. When every node
+	/// rejects the extrinsic, the error of the last one to answer is returned.
+	pub async fn submit_to_many(&self, extrinsic: &[u8], endpoints: &[String]) -> Result<H256, crate::Error> {
+		use super::clients::ReqwestClient;
+		use futures::future::select_ok;
+
+		let mut rpc_clients = vec![self.rpc_client.clone()];
+		rpc_clients.extend(endpoints.iter().map(|x| RpcClient::new(ReqwestClient::new(x))));
+
+		let ext_hash = H256::from(avail_rust_core::ext::sp_crypto_hashing::blake2_256(extrinsic));
+		let submissions = rpc_clients.into_iter().map(|rpc_client| {
+			Box::pin(async move {
+				let _permit = self.concurrency_permit().await;
+				match rpc::author::submit_extrinsic(&rpc_client, extrinsic).await {
+					Ok(hash) => Ok(hash),
+					Err(e) if is_already_imported(&e) => Ok(ext_hash),
+					Err(e) => Err(crate::Error::from(e)),
+				}
+			})
+		});
+
+		let result = select_ok(submissions).await;
+		self.online_client.record_submission(extrinsic, result.is_ok());
+		Ok(result?.0)
+	}
+
+	/// Pages through the data submitted under `app_id` in finalized blocks, starting at `from_height`.
+	pub fn app_submissions(&self, app_id: u32, from_height: u32, page_size: usize) -> AppSubmissionPager {
+		AppSubmissionPager::new(self.clone(), app_id, from_height, page_size)
+	}
+
 	/// Returns a block handle for a specific hash or height.
 	pub fn block(&self, at: impl Into<HashStringNumber>) -> Block {
 		Block::new(self.clone(), at)
 	}
 
+	/// Fetches the current best block and returns a handle bound to its hash and height.
+	pub async fn best_block(&self) -> Result<Block, crate::Error> {
+		let info = self.best().block_info().await?;
+		Ok(Block::new(self.clone(), info))
+	}
+
+	/// Fetches the current finalized block and returns a handle bound to its hash and height.
+	pub async fn finalized_block(&self) -> Result<Block, crate::Error> {
+		let info = self.finalized().block_info().await?;
+		Ok(Block::new(self.clone(), info))
+	}
+
+	/// Waits until the best (`use_best`) or finalized height reaches `target`.
+	///
+	/// Polls the chain head once per second and returns the head block info once `height >= target`.
+	/// Fails with [`Error::Timeout`](crate::Error::Timeout) when `timeout` elapses first.
+	pub async fn wait_for_height(
+		&self,
+		target: u32,
+		use_best: bool,
+		timeout: Duration,
+	) -> Result<BlockInfo, crate::Error> {
+		let future = async {
+			loop {
+				let info = match use_best {
+					true => self.best().block_info().await?,
+					false => self.finalized().block_info().await?,
+				};
+				if info.height >= target {
+					return Ok(info);
+				}
+
+				platform::sleep(Duration::from_secs(1)).await;
+			}
+		};
+
+		match platform::timeout(timeout, future).await {
+			Ok(result) => result,
+			Err(_) => Err(crate::Error::Timeout(std::format!(
+				"[op:{}] Block height {} was not reached in time",
+				ErrorOperation::ClientWaitForHeight,
+				target
+			))),
+		}
+	}
+
 	/// Returns low-level chain RPC helpers.
 	pub fn chain(&self) -> Chain {
 		Chain::new(self.clone())
@@ -171,10 +530,71 @@ impl Client {
 		self.online_client.set_retry_policy(value);
 	}
 
+	/// Caches account nonces for up to `capacity` accounts, shared by every clone of this client.
+	///
+	/// Transactions whose options leave the nonce unset read it from the node once and then reuse
+	/// the cached value until the finalized height advances. Every extrinsic submitted through the
+	/// client updates the cache: an accepted one bumps its signer's nonce, a rejected one drops the
+	/// entry so the next lookup re-reads it. Each lookup still reads the chain info to learn the
+	/// finalized height.
+	///
+	/// The cache cannot see transactions that leave the pool without being included, or that the
+	/// same account submits through another client; call [`Client::invalidate_cached_nonce`] then.
+	pub fn enable_nonce_cache(&self, capacity: usize) {
+		self.online_client.enable_nonce_cache(capacity);
+	}
+
+	/// Drops the cached nonce of `account_id`, so the next transaction reads it from the node.
+	///
+	/// Does nothing when the nonce cache is off or holds no entry for the account.
+	pub fn invalidate_cached_nonce(&self, account_id: &AccountId) {
+		self.online_client.invalidate_nonce(account_id);
+	}
+
+	/// Caps how many requests the SDK's parallel helpers, such as [`Chain::headers`],
+	/// [`Chain::fetch_many`], catching-up subscriptions and fee estimates, keep in flight across the
+	/// whole client. A value of zero is treated as one.
+	///
+	/// The cap is shared by every clone of this client.
+	pub fn set_max_concurrency(&self, value: usize) {
+		self.online_client.set_max_concurrency(Some(value));
+	}
+
+	/// Waits for a slot under the cap set by [`Client::set_max_concurrency`].
+	///
+	/// Returns `None` right away when no cap is set; the slot is released when the permit is dropped.
+	pub(crate) async fn concurrency_permit(&self) -> Option<OwnedSemaphorePermit> {
+		let limiter = self.online_client.concurrency_limiter()?;
+		limiter.acquire_owned().await.ok()
+	}
+
+	/// Runs `future` while holding a slot under the cap set by [`Client::set_max_concurrency`].
+	pub(crate) async fn limited<F: Future>(&self, future: F) -> F::Output {
+		let _permit = self.concurrency_permit().await;
+		future.await
+	}
+
 	pub fn subscribe(&self) -> SubscribeApi {
 		SubscribeApi(self.clone())
 	}
 
+	/// Streams new best block headers as the node announces them via `chain_subscribeNewHeads`.
+	///
+	/// Reorgs are passed through as-is, so heights may repeat or go backwards. When the transport
+	/// cannot open the subscription, as is the case for the built-in HTTP transports, the stream falls
+	/// back to polling `chain_getHeader` every [`BEST_HEADERS_POLL_INTERVAL`] and yields the best
+	/// header whenever its hash changes. Polling can skip headers that were best only briefly.
+	pub fn subscribe_best_headers(&self) -> impl Stream<Item = Result<AvailHeader, crate::Error>> + use<> {
+		let client = self.clone();
+		stream::once(async move {
+			match rpc::chain::subscribe_new_heads(&client.rpc_client).await {
+				Ok(sub) => Either::Left(sub.map_err(|err| crate::Error::from(RpcError::from(err)))),
+				Err(_) => Either::Right(poll_best_headers(client)),
+			}
+		})
+		.flatten()
+	}
+
 	pub fn account<'a>(&'a self) -> crate::account::Account<'a> {
 		crate::account::Account::new(self)
 	}
@@ -182,4 +602,135 @@ impl Client {
 	pub fn blob<'a>(&'a self) -> crate::blob::Blob<'a> {
 		crate::blob::Blob::new(self)
 	}
+
+	/// Returns the event lookups that start from a transaction, holding a clone of this client.
+	pub fn event_client(&self) -> crate::event_client::EventClient {
+		crate::event_client::EventClient::new(self.clone())
+	}
+
+	/// Returns the read API of the `DataAvailability` pallet, holding a clone of this client.
+	///
+	/// Transactions of the pallet are built with [`Client::tx`] instead.
+	pub fn data_availability(&self) -> crate::data_availability::DataAvailabilityApi {
+		crate::data_availability::DataAvailabilityApi::new(self.clone())
+	}
+}
+
+/// Polls the best header, yielding it each time its hash changes.
+fn poll_best_headers(client: Client) -> impl Stream<Item = Result<AvailHeader, crate::Error>> {
+	stream::unfold((client, None::<H256>, true), |(client, last, first)| async move {
+		let mut wait = !first;
+		loop {
+			if wait {
+				platform::sleep(BEST_HEADERS_POLL_INTERVAL).await;
+			}
+			wait = true;
+
+			let header = match client.chain().block_header(None::<H256>).await {
+				Ok(Some(header)) => header,
+				Ok(None) => continue,
+				Err(err) => return Some((Err(err), (client, last, false))),
+			};
+			let hash = header.hash();
+			if last != Some(hash) {
+				return Some((Ok(header), (client, Some(hash), false)));
+			}
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn network_from_genesis_hash() {
+		let mainnet = H256::from_str(MAINNET_GENESIS_HASH).unwrap();
+		let turing = H256::from_str(TURING_GENESIS_HASH).unwrap();
+		assert_eq!(Network::from_genesis_hash(mainnet), Network::Mainnet);
+		assert_eq!(Network::from_genesis_hash(turing), Network::Turing);
+		assert_eq!(Network::from_genesis_hash(H256::zero()), Network::Unknown);
+	}
+
+	#[test]
+	fn connection_options_parse_request_timeout() {
+		assert_eq!(parse_request_timeout(" 1500 ").unwrap(), Duration::from_millis(1500));
+
+		let err = parse_request_timeout("15s").unwrap_err();
+		assert_eq!(err.code(), crate::ErrorCode::Validation);
+
+		let options = ConnectionOptions::from("http://127.0.0.1:9944");
+		assert_eq!(options.request_timeout, None);
+		let options = options.request_timeout(Duration::from_secs(2));
+		assert_eq!(options.request_timeout, Some(Duration::from_secs(2)));
+	}
+
+	#[tokio::test]
+	async fn subscribe_best_headers_passes_reorgs_through() -> Result<(), crate::Error> {
+		use crate::{
+			LOCAL_ENDPOINT,
+			clients::mock_client::{MockClient, metadata_bytes},
+		};
+		use futures::StreamExt;
+		use serde_json::value::RawValue;
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+
+		let heads: Vec<Box<RawValue>> = [5u32, 6, 6, 5]
+			.into_iter()
+			.map(|number| {
+				let header = AvailHeader { number, ..Default::default() };
+				RawValue::from_string(serde_json::to_string(&header).unwrap()).unwrap()
+			})
+			.collect();
+		commander.add_subscription("chain_subscribeNewHeads", heads);
+
+		let numbers: Vec<u32> = client
+			.subscribe_best_headers()
+			.map(|header| header.map(|h| h.number))
+			.collect::<Vec<_>>()
+			.await
+			.into_iter()
+			.collect::<Result<_, _>>()?;
+		assert_eq!(numbers, vec![5, 6, 6, 5]);
+		assert_eq!(commander.call_count("chain_subscribeNewHeads"), 1);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn subscribe_best_headers_polls_without_subscriptions() -> Result<(), crate::Error> {
+		use crate::{
+			LOCAL_ENDPOINT,
+			clients::mock_client::{MockClient, metadata_bytes},
+		};
+		use futures::StreamExt;
+		use serde_json::value::RawValue;
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+
+		// No subscription is queued, so the HTTP transport behind the mock rejects it.
+		for number in [5u32, 5, 6] {
+			let header = AvailHeader { number, ..Default::default() };
+			let value = RawValue::from_string(serde_json::to_string(&header).unwrap()).unwrap();
+			commander.add_ok("chain_getHeader", value);
+		}
+
+		let numbers: Vec<u32> = client
+			.subscribe_best_headers()
+			.take(2)
+			.map(|header| header.map(|h| h.number))
+			.collect::<Vec<_>>()
+			.await
+			.into_iter()
+			.collect::<Result<_, _>>()?;
+		assert_eq!(numbers, vec![5, 6]);
+		assert_eq!(commander.call_count("chain_getHeader"), 3);
+
+		Ok(())
+	}
 }
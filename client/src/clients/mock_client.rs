@@ -2,10 +2,19 @@ use crate::{
 	clients::ReqwestClient,
 	ext::subxt_rpcs::{self, RpcClientT},
 };
-use avail_rust_core::{grandpa::GrandpaJustification, rpc};
+use avail_rust_core::{H256, grandpa::GrandpaJustification, rpc};
 use codec::Encode;
+use frame_metadata::{
+	META_RESERVED, RuntimeMetadata, RuntimeMetadataPrefixed,
+	v15::{CustomMetadata, ExtrinsicMetadata, OuterEnums, PalletConstantMetadata, PalletMetadata, RuntimeMetadataV15},
+};
+use scale_info::{TypeInfo, meta_type};
 use serde_json::value::RawValue;
-use std::sync::{Arc, Mutex};
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::{Arc, Mutex},
+	time::Duration,
+};
 
 /// RPC client wrapper that allows injecting canned responses for testing.
 #[derive(Clone)]
@@ -21,7 +30,7 @@ impl MockClient {
 	pub fn new(endpoint: &str) -> (Self, CommandManagerHelper) {
 		let org = ReqwestClient::new(endpoint);
 		let commander = Arc::new(Mutex::new(CommandManager::default()));
-		let wrapper = CommandManagerHelper { 0: commander.clone() };
+		let wrapper = CommandManagerHelper(commander.clone());
 		(Self { org, commander }, wrapper)
 	}
 }
@@ -34,9 +43,20 @@ impl RpcClientT for MockClient {
 	) -> subxt_rpcs::client::RawRpcFuture<'a, Box<RawValue>> {
 		{
 			let mut commander = self.commander.lock().unwrap();
+			*commander.calls.entry(method.to_string()).or_default() += 1;
+			let delay = commander.delay;
 			if let Some(value) = commander.find(method) {
 				//println!("Found Mock value: Method: {}", method);
-				return Box::pin(async move { value });
+				commander.in_flight += 1;
+				commander.max_in_flight = commander.max_in_flight.max(commander.in_flight);
+				let tracker = self.commander.clone();
+				return Box::pin(async move {
+					if let Some(delay) = delay {
+						crate::platform::sleep(delay).await;
+					}
+					tracker.lock().unwrap().in_flight -= 1;
+					value
+				});
 			}
 		}
 
@@ -49,14 +69,84 @@ impl RpcClientT for MockClient {
 		params: Option<Box<RawValue>>,
 		unsub: &'a str,
 	) -> subxt_rpcs::client::RawRpcFuture<'a, subxt_rpcs::client::RawRpcSubscription> {
+		{
+			let mut commander = self.commander.lock().unwrap();
+			*commander.calls.entry(sub.to_string()).or_default() += 1;
+			if let Some(values) = commander.find_subscription(sub) {
+				let stream = futures::stream::iter(values.into_iter().map(Ok));
+				let value = subxt_rpcs::client::RawRpcSubscription { stream: Box::pin(stream), id: None };
+				return Box::pin(async move { Ok(value) });
+			}
+		}
+
 		self.org.subscribe_raw(sub, params, unsub)
 	}
 }
 
+/// Encodes minimal V15 metadata whose pallets declare only the given `(pallet, constant, value)` entries.
+///
+/// Pallets are indexed in the order they first appear. Together with
+/// [`CommandManagerHelper::bootstrap_ok`] this lets a [`Client`](crate::Client) start without a node.
+pub fn metadata_bytes(constants: &[(&'static str, &'static str, Vec<u8>)]) -> Vec<u8> {
+	metadata_bytes_with_event::<()>(constants)
+}
+
+/// Same as [`metadata_bytes`] but declares `E` as the runtime event type, which decides how many
+/// bytes each `System::Events` record takes.
+pub fn metadata_bytes_with_event<E: TypeInfo + 'static>(
+	constants: &[(&'static str, &'static str, Vec<u8>)],
+) -> Vec<u8> {
+	let mut pallets: Vec<PalletMetadata> = Vec::new();
+	for &(pallet, name, ref value) in constants {
+		let constant = PalletConstantMetadata {
+			name,
+			ty: meta_type::<u32>(),
+			value: value.clone(),
+			docs: Vec::new(),
+		};
+		match pallets.iter_mut().find(|x| x.name == pallet) {
+			Some(x) => x.constants.push(constant),
+			None => pallets.push(PalletMetadata {
+				name: pallet,
+				storage: None,
+				calls: None,
+				event: None,
+				constants: vec![constant],
+				error: None,
+				index: pallets.len() as u8,
+				docs: Vec::new(),
+			}),
+		}
+	}
+
+	let extrinsic = ExtrinsicMetadata {
+		version: 4,
+		address_ty: meta_type::<()>(),
+		call_ty: meta_type::<()>(),
+		signature_ty: meta_type::<()>(),
+		extra_ty: meta_type::<()>(),
+		signed_extensions: Vec::new(),
+	};
+	let outer_enums = OuterEnums {
+		call_enum_ty: meta_type::<()>(),
+		event_enum_ty: meta_type::<E>(),
+		error_enum_ty: meta_type::<()>(),
+	};
+	let custom = CustomMetadata { map: BTreeMap::new() };
+	let metadata = RuntimeMetadataV15::new(pallets, extrinsic, meta_type::<()>(), Vec::new(), outer_enums, custom);
+
+	RuntimeMetadataPrefixed(META_RESERVED, RuntimeMetadata::V15(metadata)).encode()
+}
+
 /// Stores queued responses to satisfy upcoming mock RPC calls.
 #[derive(Debug, Default)]
 pub struct CommandManager {
 	list: Vec<(String, Result<Box<RawValue>, subxt_rpcs::Error>)>,
+	subscriptions: Vec<(String, Vec<Box<RawValue>>)>,
+	calls: HashMap<String, usize>,
+	delay: Option<Duration>,
+	in_flight: usize,
+	max_in_flight: usize,
 }
 
 impl CommandManager {
@@ -64,10 +154,7 @@ impl CommandManager {
 	///
 	/// Returns the stored response result, or `None` if nothing was queued.
 	pub fn find(&mut self, method: &str) -> Option<Result<Box<RawValue>, subxt_rpcs::Error>> {
-		let pos = self.list.iter().position(|x| x.0.as_str() == method);
-		let Some(pos) = pos else {
-			return None;
-		};
+		let pos = self.list.iter().position(|x| x.0.as_str() == method)?;
 		let value = self.list.remove(pos);
 		Some(value.1)
 	}
@@ -85,6 +172,39 @@ impl CommandManager {
 	pub fn add_err(&mut self, method: impl Into<String>, value: subxt_rpcs::Error) {
 		self.list.push((method.into(), Err(value)));
 	}
+
+	/// Retrieves and removes the earliest queued subscription for the given method.
+	///
+	/// Returns the notifications the subscription will yield, or `None` if nothing was queued.
+	pub fn find_subscription(&mut self, method: &str) -> Option<Vec<Box<RawValue>>> {
+		let pos = self.subscriptions.iter().position(|x| x.0.as_str() == method)?;
+		Some(self.subscriptions.remove(pos).1)
+	}
+
+	/// Queues a subscription for the given method that yields `values` and then ends.
+	pub fn add_subscription(&mut self, method: impl Into<String>, values: Vec<Box<RawValue>>) {
+		self.subscriptions.push((method.into(), values));
+	}
+
+	/// Returns how many times `method` was requested, mocked or not.
+	pub fn call_count(&self, method: &str) -> usize {
+		self.calls.get(method).copied().unwrap_or_default()
+	}
+
+	/// Returns how many requests and subscriptions were made in total, across all methods.
+	pub fn total_call_count(&self) -> usize {
+		self.calls.values().sum()
+	}
+
+	/// Delays every mocked response by `value`; `None` answers immediately.
+	pub fn set_delay(&mut self, value: Option<Duration>) {
+		self.delay = value;
+	}
+
+	/// Returns the largest number of mocked requests that were awaiting a response at the same time.
+	pub fn max_in_flight(&self) -> usize {
+		self.max_in_flight
+	}
 }
 
 /// Thread-safe helper that exposes ergonomic methods to queue mock responses.
@@ -106,6 +226,51 @@ impl CommandManagerHelper {
 		lock.add_err(method, value);
 	}
 
+	/// Queues a subscription for the given method that yields `values` and then ends.
+	pub fn add_subscription(&mut self, method: impl Into<String>, values: Vec<Box<RawValue>>) {
+		let mut lock = self.0.lock().unwrap();
+		lock.add_subscription(method, values);
+	}
+
+	/// Returns how many times `method` was requested, mocked or not.
+	pub fn call_count(&self, method: &str) -> usize {
+		let lock = self.0.lock().unwrap();
+		lock.call_count(method)
+	}
+
+	/// Returns how many requests and subscriptions were made in total, across all methods.
+	pub fn total_call_count(&self) -> usize {
+		let lock = self.0.lock().unwrap();
+		lock.total_call_count()
+	}
+
+	/// Delays every mocked response by `value`; `None` answers immediately.
+	pub fn set_delay(&mut self, value: Option<Duration>) {
+		let mut lock = self.0.lock().unwrap();
+		lock.set_delay(value);
+	}
+
+	/// Returns the largest number of mocked requests that were awaiting a response at the same time.
+	pub fn max_in_flight(&self) -> usize {
+		let lock = self.0.lock().unwrap();
+		lock.max_in_flight()
+	}
+
+	/// Queues the responses a client needs to bootstrap from `metadata`, so that
+	/// [`Client::from_rpc_client`](crate::Client::from_rpc_client) succeeds without a node.
+	///
+	/// The finalized head and genesis hash are zero and both runtime versions are one.
+	pub fn bootstrap_ok(&mut self, metadata: &[u8]) {
+		let hash = serde_json::to_string(&H256::zero()).unwrap();
+		let metadata = serde_json::to_string(&const_hex::encode_prefixed(metadata)).unwrap();
+		let version = r#"{"specVersion":1,"transactionVersion":1}"#.to_string();
+
+		self.add_ok("chain_getFinalizedHead", RawValue::from_string(hash.clone()).unwrap());
+		self.add_ok("state_getMetadata", RawValue::from_string(metadata).unwrap());
+		self.add_ok("chainSpec_v1_genesisHash", RawValue::from_string(hash).unwrap());
+		self.add_ok("state_getRuntimeVersion", RawValue::from_string(version).unwrap());
+	}
+
 	/// Queues a `grandpa_blockJustification` response containing encoded justification bytes.
 	///
 	/// Returns `()` once the response is queued.
@@ -5,6 +5,10 @@ pub mod online_client;
 #[cfg(any(test, feature = "mocks"))]
 pub mod mock_client;
 
+pub mod pooled_client;
 pub mod reqwest_client;
+pub mod timeout_client;
 pub use online_client::OnlineClient;
+pub use pooled_client::PooledClient;
 pub use reqwest_client::ReqwestClient;
+pub use timeout_client::{RequestTimedOut, TimeoutClient};
@@ -1,8 +1,14 @@
 //! Thin cached view of chain metadata and runtime versions fetched from an RPC endpoint.
 
-use crate::{RetryPolicy, subxt_core::Metadata, subxt_rpcs::RpcClient};
-use avail_rust_core::{H256, RpcError, ext::codec::Decode, rpc};
+use crate::{
+	RetryPolicy,
+	nonce_cache::{NonceCache, signer_nonce},
+	subxt_core::Metadata,
+	subxt_rpcs::RpcClient,
+};
+use avail_rust_core::{AccountId, H256, RpcError, ext::codec::Decode, rpc};
 use std::sync::{Arc, RwLock};
+use tokio::sync::Semaphore;
 
 /// Shared handle holding runtime metadata and version information.
 #[derive(Clone)]
@@ -16,6 +22,8 @@ pub struct OnlineClientInner {
 	transaction_version: u32,
 	metadata: Metadata,
 	global_retry_policy: RetryPolicy,
+	nonce_cache: Option<NonceCache>,
+	concurrency_limit: Option<(usize, Arc<Semaphore>)>,
 }
 
 impl OnlineClient {
@@ -37,6 +45,8 @@ impl OnlineClient {
 			transaction_version: runtime_version.transaction_version,
 			metadata,
 			global_retry_policy: RetryPolicy::Enabled,
+			nonce_cache: None,
+			concurrency_limit: None,
 		};
 		Ok(Self(Arc::new(RwLock::new(inner))))
 	}
@@ -119,4 +129,76 @@ impl OnlineClient {
 		let mut lock = self.0.write().expect("Should not be poisoned");
 		lock.global_retry_policy = value;
 	}
+
+	/// Caps the number of requests the SDK's parallel helpers keep in flight; `None` removes the cap.
+	pub fn set_max_concurrency(&self, value: Option<usize>) {
+		let mut lock = self.0.write().expect("Should not be poisoned");
+		lock.concurrency_limit = value.map(|x| (x.max(1), Arc::new(Semaphore::new(x.max(1)))));
+	}
+
+	/// Returns the configured concurrency cap, if any.
+	pub fn max_concurrency(&self) -> Option<usize> {
+		let lock = self.0.read().expect("Should not be poisoned");
+		lock.concurrency_limit.as_ref().map(|x| x.0)
+	}
+
+	/// Returns the semaphore shared by all parallel helpers when a cap is set.
+	pub(crate) fn concurrency_limiter(&self) -> Option<Arc<Semaphore>> {
+		let lock = self.0.read().expect("Should not be poisoned");
+		lock.concurrency_limit.as_ref().map(|x| x.1.clone())
+	}
+
+	/// Turns on the shared nonce cache, replacing any previous one.
+	pub fn enable_nonce_cache(&self, capacity: usize) {
+		let mut lock = self.0.write().expect("Should not be poisoned");
+		lock.nonce_cache = Some(NonceCache::new(capacity));
+	}
+
+	/// Reports whether the nonce cache is turned on.
+	pub fn is_nonce_cache_enabled(&self) -> bool {
+		self.0.read().map(|x| x.nonce_cache.is_some()).unwrap_or(false)
+	}
+
+	/// Returns the nonce cached for `account_id`, if any was stored at `finalized_height`.
+	pub(crate) fn cached_nonce(&self, account_id: &AccountId, finalized_height: u32) -> Option<u32> {
+		let mut lock = self.0.write().expect("Should not be poisoned");
+		lock.nonce_cache.as_mut()?.get(account_id, finalized_height)
+	}
+
+	/// Stores a nonce freshly fetched at `finalized_height` when the cache is turned on.
+	pub(crate) fn cache_nonce(&self, account_id: AccountId, nonce: u32, finalized_height: u32) {
+		let mut lock = self.0.write().expect("Should not be poisoned");
+		if let Some(cache) = lock.nonce_cache.as_mut() {
+			cache.insert(account_id, nonce, finalized_height);
+		}
+	}
+
+	/// Updates the nonce cache with the outcome of submitting `extrinsic`.
+	///
+	/// An accepted signed extrinsic bumps its signer's cached nonce past the one it used; a rejected
+	/// one drops the signer's entry so the next lookup reads the nonce from the node.
+	pub(crate) fn record_submission(&self, extrinsic: &[u8], accepted: bool) {
+		if !self.is_nonce_cache_enabled() {
+			return;
+		}
+		let Some((account_id, nonce)) = signer_nonce(extrinsic) else {
+			return;
+		};
+
+		let mut lock = self.0.write().expect("Should not be poisoned");
+		if let Some(cache) = lock.nonce_cache.as_mut() {
+			match accepted {
+				true => cache.mark_used(&account_id, nonce),
+				false => cache.remove(&account_id),
+			}
+		}
+	}
+
+	/// Drops the cached nonce of `account_id` so the next lookup reads it from the node.
+	pub fn invalidate_nonce(&self, account_id: &AccountId) {
+		let mut lock = self.0.write().expect("Should not be poisoned");
+		if let Some(cache) = lock.nonce_cache.as_mut() {
+			cache.remove(account_id);
+		}
+	}
 }
@@ -0,0 +1,140 @@
+use crate::subxt_rpcs::{self, RpcClientT};
+use serde_json::value::RawValue;
+use std::sync::{
+	Arc,
+	atomic::{AtomicUsize, Ordering},
+};
+
+/// RPC methods that always go through the first connection of a [`PooledClient`].
+///
+/// Nonce reads are pinned together with submissions, so the nonce a transaction is signed with
+/// comes from the same node that receives the transaction.
+const PINNED_METHODS: &[&str] = &[
+	"system_accountNextIndex",
+	"author_pendingExtrinsics",
+	"author_submitExtrinsic",
+	"author_submitAndWatchExtrinsic",
+	"blob_submitBlob",
+];
+
+/// RPC transport that spreads requests over several connections, typically to different nodes.
+///
+/// Read requests are handed out round-robin. Nonce reads, submissions and subscriptions always use
+/// the first connection, so a transaction is signed with a nonce read from the node it is sent to,
+/// and transactions from one client reach that node in the order they were sent.
+///
+/// Pointing every connection at the same URL gains nothing over a single HTTP client, which already
+/// keeps its own connection pool; use one connection per node instead.
+#[derive(Clone)]
+pub struct PooledClient {
+	connections: Arc<Vec<Box<dyn RpcClientT>>>,
+	next: Arc<AtomicUsize>,
+}
+
+impl PooledClient {
+	/// Creates a pool from already built connections.
+	///
+	/// Returns `None` when `connections` is empty.
+	pub fn new<T: RpcClientT>(connections: Vec<T>) -> Option<Self> {
+		if connections.is_empty() {
+			return None;
+		}
+
+		let connections: Vec<Box<dyn RpcClientT>> = connections
+			.into_iter()
+			.map(|x| Box::new(x) as Box<dyn RpcClientT>)
+			.collect();
+		Some(Self {
+			connections: Arc::new(connections),
+			next: Arc::new(AtomicUsize::new(0)),
+		})
+	}
+
+	/// Returns the number of connections in the pool.
+	pub fn len(&self) -> usize {
+		self.connections.len()
+	}
+
+	/// Always `false`; a pool holds at least one connection.
+	pub fn is_empty(&self) -> bool {
+		self.connections.is_empty()
+	}
+
+	fn connection_for(&self, method: &str) -> &dyn RpcClientT {
+		if PINNED_METHODS.contains(&method) {
+			return self.connections[0].as_ref();
+		}
+
+		let index = self.next.fetch_add(1, Ordering::Relaxed) % self.connections.len();
+		self.connections[index].as_ref()
+	}
+}
+
+impl RpcClientT for PooledClient {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> subxt_rpcs::client::RawRpcFuture<'a, Box<RawValue>> {
+		self.connection_for(method).request_raw(method, params)
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> subxt_rpcs::client::RawRpcFuture<'a, subxt_rpcs::client::RawRpcSubscription> {
+		self.connections[0].subscribe_raw(sub, params, unsub)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{LOCAL_ENDPOINT, clients::mock_client::MockClient, subxt_rpcs::RpcClient};
+	use avail_rust_core::{H256, rpc};
+
+	#[tokio::test]
+	async fn pooled_client_round_robins_reads_and_pins_nonce_and_submissions() {
+		let mut mocks = Vec::new();
+		let mut commanders = Vec::new();
+		for _ in 0..3 {
+			let (mock, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+			for _ in 0..4 {
+				commander.add_ok("chain_getBlockHash", RawValue::from_string("null".into()).unwrap());
+			}
+			mocks.push(mock);
+			commanders.push(commander);
+		}
+		let hash = serde_json::to_string(&H256::repeat_byte(1)).unwrap();
+		for _ in 0..2 {
+			commanders[0].add_ok("system_accountNextIndex", RawValue::from_string("7".into()).unwrap());
+			commanders[0].add_ok("author_submitExtrinsic", RawValue::from_string(hash.clone()).unwrap());
+		}
+
+		let pool = PooledClient::new(mocks).unwrap();
+		assert_eq!(pool.len(), 3);
+		let rpc_client = RpcClient::new(pool);
+
+		let calls = (0..12).map(|_| rpc::chain::get_block_hash(&rpc_client, Some(1)));
+		let hashes = futures::future::try_join_all(calls).await.unwrap();
+		assert!(hashes.iter().all(|x| x.is_none()));
+		for commander in &commanders {
+			assert_eq!(commander.call_count("chain_getBlockHash"), 4);
+		}
+
+		let address = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+		for _ in 0..2 {
+			assert_eq!(rpc::system::account_next_index(&rpc_client, address).await.unwrap(), 7);
+			rpc::author::submit_extrinsic(&rpc_client, &[1, 2, 3]).await.unwrap();
+		}
+		assert_eq!(commanders[0].call_count("system_accountNextIndex"), 2);
+		assert_eq!(commanders[0].call_count("author_submitExtrinsic"), 2);
+		for commander in &commanders[1..] {
+			assert_eq!(commander.total_call_count(), 4);
+		}
+
+		assert!(PooledClient::new(Vec::<MockClient>::new()).is_none());
+	}
+}
@@ -0,0 +1,104 @@
+use crate::{
+	platform,
+	subxt_rpcs::{self, RpcClientT},
+};
+use serde_json::value::RawValue;
+use std::{sync::Arc, time::Duration};
+
+/// Error returned by a [`TimeoutClient`] when a request does not complete in time.
+///
+/// The client reports it as [`Error::Timeout`](crate::Error::Timeout).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestTimedOut {
+	pub method: String,
+	pub timeout: Duration,
+}
+
+impl std::fmt::Display for RequestTimedOut {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "RPC request {} did not complete within {:?}", self.method, self.timeout)
+	}
+}
+
+impl std::error::Error for RequestTimedOut {}
+
+/// RPC transport that bounds every request made through another transport.
+///
+/// Subscriptions are only bounded while they are being established.
+#[derive(Clone)]
+pub struct TimeoutClient {
+	inner: Arc<dyn RpcClientT>,
+	timeout: Duration,
+}
+
+impl TimeoutClient {
+	/// Wraps `inner` so that each request fails with [`RequestTimedOut`] after `timeout`.
+	pub fn new<T: RpcClientT>(inner: T, timeout: Duration) -> Self {
+		Self { inner: Arc::new(inner), timeout }
+	}
+
+	/// Returns the timeout applied to each request.
+	pub fn timeout(&self) -> Duration {
+		self.timeout
+	}
+
+	fn timed_out(&self, method: &str) -> subxt_rpcs::Error {
+		let err = RequestTimedOut { method: method.to_string(), timeout: self.timeout };
+		subxt_rpcs::Error::Client(Box::new(err))
+	}
+}
+
+impl RpcClientT for TimeoutClient {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> subxt_rpcs::client::RawRpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			match platform::timeout(self.timeout, self.inner.request_raw(method, params)).await {
+				Ok(result) => result,
+				Err(_) => Err(self.timed_out(method)),
+			}
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> subxt_rpcs::client::RawRpcFuture<'a, subxt_rpcs::client::RawRpcSubscription> {
+		Box::pin(async move {
+			match platform::timeout(self.timeout, self.inner.subscribe_raw(sub, params, unsub)).await {
+				Ok(result) => result,
+				Err(_) => Err(self.timed_out(sub)),
+			}
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{LOCAL_ENDPOINT, clients::mock_client::MockClient, error::ErrorCode, subxt_rpcs::RpcClient};
+	use avail_rust_core::rpc;
+
+	#[tokio::test]
+	async fn timeout_client_fails_slow_requests() {
+		let (mock, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.set_delay(Some(Duration::from_millis(500)));
+		for _ in 0..2 {
+			commander.add_ok("system_accountNextIndex", RawValue::from_string("7".into()).unwrap());
+		}
+		let address = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+		let rpc_client = RpcClient::new(TimeoutClient::new(mock.clone(), Duration::from_millis(10)));
+		let err = rpc::system::account_next_index(&rpc_client, address).await.unwrap_err();
+		let err = crate::Error::from(err);
+		assert_eq!(err.code(), ErrorCode::Timeout);
+		assert!(err.to_string().contains("system_accountNextIndex"));
+
+		let rpc_client = RpcClient::new(TimeoutClient::new(mock, Duration::from_secs(5)));
+		assert_eq!(rpc::system::account_next_index(&rpc_client, address).await.unwrap(), 7);
+	}
+}
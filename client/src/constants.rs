@@ -19,6 +19,10 @@ pub const TURING_WS_ENDPOINT: &str = "wss://turing-rpc.avail.so/ws";
 pub const MAINNET_ENDPOINT: &str = "https://mainnet-rpc.avail.so/rpc";
 /// Public WebSocket endpoint for the Avail mainnet.
 pub const MAINNET_WS_ENDPOINT: &str = "wss://mainnet-rpc.avail.so/ws";
+/// Genesis hash of the Avail mainnet.
+pub const MAINNET_GENESIS_HASH: &str = "0xb91746b45e0346cc2f815a520b9c6cb4d5c0902af848db0a80f85932d2e8276a";
+/// Genesis hash of the Turing test network.
+pub const TURING_GENESIS_HASH: &str = "0xd3d2f3a3495dc597434a99d7d449ebad6616db45e4e4f178f31cc6fa14378b70";
 
 /// Development accounts useful for testing and examples.
 pub mod dev_accounts {
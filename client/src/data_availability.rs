@@ -0,0 +1,120 @@
+//! Read helpers for the `DataAvailability` pallet: application keys, app ids and data fees.
+
+use crate::{Client, Error, H256, RpcError, StorageValue, subscription::storage::fetch_entries};
+use avail_rust_core::{
+	AccountId,
+	avail::data_availability::{
+		storage::{AppKeys, NextAppId},
+		tx::SubmitData,
+		types::AppKey,
+	},
+	ext::codec::Encode,
+	substrate::{StorageMap, extrinsic::ExtrinsicCall},
+};
+
+const COST_SAMPLE_SIZE: usize = 64 * 1024;
+
+/// Queries application keys, app ids and data fees of the `DataAvailability` pallet.
+///
+/// Obtained from [`Client::data_availability`]. Every read goes through the retrying [`Chain`](crate::chain::Chain)
+/// calls; transactions of the pallet are built with [`Client::tx`] instead.
+#[derive(Clone)]
+pub struct DataAvailabilityApi {
+	client: Client,
+}
+
+impl DataAvailabilityApi {
+	pub(crate) fn new(client: Client) -> Self {
+		Self { client }
+	}
+
+	/// Returns `at`, or the finalized block hash when `at` is `None`.
+	async fn block_hash_or_finalized(&self, at: Option<H256>) -> Result<H256, Error> {
+		match at {
+			Some(x) => Ok(x),
+			None => Ok(self.client.finalized().block_hash().await?),
+		}
+	}
+
+	/// Reads and decodes every `AppKeys` entry at `block_hash`, in storage key order.
+	async fn app_keys(&self, block_hash: H256) -> Result<Vec<(Vec<u8>, AppKey)>, Error> {
+		let entries = fetch_entries::<AppKeys>(&self.client.chain(), block_hash).await?;
+		let mut result = Vec::with_capacity(entries.len());
+		for (key, value) in entries {
+			let key = AppKeys::decode_storage_key(&mut key.as_slice())
+				.map_err(|e| RpcError::DecodingFailed(e.to_string()))?;
+			let value = AppKeys::decode_storage_value(&mut value.as_slice())
+				.map_err(|e| RpcError::DecodingFailed(e.to_string()))?;
+			result.push((key, value));
+		}
+
+		Ok(result)
+	}
+
+	/// Returns every registered application key with its owner and app id.
+	///
+	/// Reads the whole `AppKeys` map at `at`, or at the finalized block when `at` is `None`. Keys that
+	/// are not valid UTF-8 are converted lossily.
+	pub async fn all_app_keys(&self, at: Option<H256>) -> Result<Vec<(String, AccountId, u32)>, Error> {
+		let block_hash = self.block_hash_or_finalized(at).await?;
+		let keys = self.app_keys(block_hash).await?;
+		let result = keys
+			.into_iter()
+			.map(|(key, value)| (String::from_utf8_lossy(&key).into_owned(), value.owner, value.id))
+			.collect();
+
+		Ok(result)
+	}
+
+	/// Resolves an app id back to its application key name and owner.
+	///
+	/// The runtime has no reverse index, so this reads `AppKeys` at `at` (or the finalized block when
+	/// `at` is `None`) and returns the first match. Returns `None` when no key has that id.
+	pub async fn app_key_by_id(&self, id: u32, at: Option<H256>) -> Result<Option<(String, AccountId)>, Error> {
+		let block_hash = self.block_hash_or_finalized(at).await?;
+		let keys = self.app_keys(block_hash).await?;
+		let found = keys
+			.into_iter()
+			.find(|(_, value)| value.id == id)
+			.map(|(key, value)| (String::from_utf8_lossy(&key).into_owned(), value.owner));
+
+		Ok(found)
+	}
+
+	/// Returns the app id the next created application key will receive.
+	///
+	/// Reads `NextAppId` at `at`, or at the finalized block when `at` is `None`.
+	pub async fn next_app_id(&self, at: Option<H256>) -> Result<u32, Error> {
+		let block_hash = self.block_hash_or_finalized(at).await?;
+		let key = NextAppId::hex_encode_storage_key();
+		let Some(value) = self.client.chain().state_get_storage(&key, Some(block_hash)).await? else {
+			return Ok(0);
+		};
+		let value = NextAppId::decode_storage_value(&mut value.as_slice())
+			.map_err(|e| RpcError::DecodingFailed(e.to_string()))?;
+
+		Ok(value.0)
+	}
+
+	/// Estimates the fee charged per byte of data posted with `submit_data`.
+	///
+	/// Compares the runtime fee quote of an empty payload with a 64 KiB one at `at` (or the best block
+	/// when `at` is `None`), so the DA fee modifier, the length fee and the current fee multiplier are
+	/// all accounted for. The fixed per-transaction part of the fee is excluded.
+	pub async fn cost_per_byte(&self, at: Option<H256>) -> Result<u128, Error> {
+		let empty = ExtrinsicCall::from(&SubmitData { app_id: 0, data: Vec::new() }).encode();
+		let sample = ExtrinsicCall::from(&SubmitData { app_id: 0, data: vec![0u8; COST_SAMPLE_SIZE] }).encode();
+
+		let chain = self.client.chain();
+		let (empty, sample) = futures::future::try_join(
+			self.client
+				.limited(chain.transaction_payment_query_call_fee_details(empty, at)),
+			self.client
+				.limited(chain.transaction_payment_query_call_fee_details(sample, at)),
+		)
+		.await?;
+
+		let delta = sample.final_fee().saturating_sub(empty.final_fee());
+		Ok(delta / COST_SAMPLE_SIZE as u128)
+	}
+}
@@ -23,6 +23,9 @@ pub enum UserError {
 	/// Catch-all for other user-facing errors.
 	#[error("{0}")]
 	Other(String),
+	/// Submitted data exceeds the chain's `MaxAppDataLength`.
+	#[error("Data is {len} bytes long but at most {max} bytes can be submitted")]
+	DataTooLarge { len: usize, max: u32 },
 }
 
 /// Errors raised by the Avail client.
@@ -93,6 +96,7 @@ impl Error {
 			Error::User(UserError::Decoding(msg))
 			| Error::User(UserError::ValidationFailed(msg))
 			| Error::User(UserError::Other(msg)) => parse(msg),
+			Error::User(UserError::DataTooLarge { .. }) => None,
 		}
 	}
 
@@ -108,6 +112,7 @@ impl Error {
 				UserError::Decoding(_) => ErrorCode::Decode,
 				UserError::ValidationFailed(_) => ErrorCode::Validation,
 				UserError::Other(_) => ErrorCode::Other,
+				UserError::DataTooLarge { .. } => ErrorCode::Validation,
 			},
 			Error::Other(_) => ErrorCode::Other,
 		}
@@ -122,11 +127,35 @@ impl From<avail_rust_core::rpc::Error> for Error {
 			avail_rust_core::rpc::Error::MalformedResponse(msg) => Self::Decode(msg),
 			avail_rust_core::rpc::Error::ExpectedData(msg) => Self::NotFound(msg),
 			avail_rust_core::rpc::Error::UnexpectedInput(msg) => Self::Validation(msg),
-			avail_rust_core::rpc::Error::Rpc(inner) => Self::Rpc(inner.to_string()),
+			avail_rust_core::rpc::Error::Rpc(inner) => match &inner {
+				avail_rust_core::ext::subxt_rpcs::Error::Client(e) if e.is::<crate::clients::RequestTimedOut>() => {
+					Self::Timeout(e.to_string())
+				},
+				avail_rust_core::ext::subxt_rpcs::Error::Client(e) if is_connection_failure(e.as_ref()) => {
+					Self::Transport(inner.to_string())
+				},
+				avail_rust_core::ext::subxt_rpcs::Error::DisconnectedWillReconnect(_) => {
+					Self::Transport(inner.to_string())
+				},
+				_ => Self::Rpc(inner.to_string()),
+			},
 		}
 	}
 }
 
+/// Returns true when a transport-level client error means the request never reached the node.
+///
+/// JSON-RPC error responses and malformed payloads are not connection failures and stay `Error::Rpc`.
+fn is_connection_failure(error: &(dyn std::error::Error + Send + Sync + 'static)) -> bool {
+	if error.is::<std::io::Error>() {
+		return true;
+	}
+	match error.downcast_ref::<reqwest::Error>() {
+		Some(e) => e.is_connect() || e.is_timeout() || e.is_request(),
+		None => false,
+	}
+}
+
 impl From<UserError> for Error {
 	/// Wraps a `UserError` into the unified error type.
 	fn from(value: UserError) -> Self {
@@ -147,3 +176,39 @@ impl From<codec::Error> for Error {
 		Self::Decode(value.to_string())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::clients::{RequestTimedOut, reqwest_client::ResponseError};
+	use avail_rust_core::{ext::subxt_rpcs, rpc};
+	use std::time::Duration;
+
+	fn client_error(error: impl std::error::Error + Send + Sync + 'static) -> Error {
+		rpc::Error::Rpc(subxt_rpcs::Error::Client(Box::new(error))).into()
+	}
+
+	#[test]
+	fn rpc_error_conversion_separates_transport_from_rpc() {
+		let io = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "connection reset");
+		assert_eq!(client_error(io).code(), ErrorCode::Transport);
+
+		let disconnected = rpc::Error::Rpc(subxt_rpcs::Error::DisconnectedWillReconnect("closed".into()));
+		assert_eq!(Error::from(disconnected).code(), ErrorCode::Transport);
+
+		let response = ResponseError(r#"{"code":-32601,"message":"Method not found"}"#.into());
+		assert_eq!(client_error(response).code(), ErrorCode::Rpc);
+
+		let serde = serde_json::from_str::<u32>("not a number").unwrap_err();
+		assert_eq!(client_error(serde).code(), ErrorCode::Rpc);
+
+		let timed_out = RequestTimedOut {
+			method: "chain_getHeader".into(),
+			timeout: Duration::from_secs(1),
+		};
+		assert_eq!(client_error(timed_out).code(), ErrorCode::Timeout);
+
+		let missing = rpc::Error::ExpectedData("block".into());
+		assert_eq!(Error::from(missing).code(), ErrorCode::NotFound);
+	}
+}
@@ -20,6 +20,21 @@ pub enum ErrorOperation {
 	BlockJustification,
 	SecretUriParse,
 	KeypairParse,
+	SubmissionFinalizedRef,
+	ClientSs58Prefix,
+	ClientWaitForHeight,
+	ChainVerifyJustification,
+	SubmissionFromParts,
+	TxSubmitDataByKey,
+	ChainStorageEvents,
+	ClientMaxAppDataLength,
+	ChainHeaders,
+	ChainBlockTime,
+	SubmissionCancel,
+	ClientNewPooled,
+	ChainBlockState,
+	ClientConnectionOptions,
+	OptionsMetadataHash,
 }
 
 impl ErrorOperation {
@@ -45,6 +60,21 @@ impl ErrorOperation {
 			Self::BlockJustification => "BLOCK_JUSTIFICATION",
 			Self::SecretUriParse => "SECRET_URI_PARSE",
 			Self::KeypairParse => "KEYPAIR_PARSE",
+			Self::SubmissionFinalizedRef => "SUBMISSION_FINALIZED_REF",
+			Self::ClientSs58Prefix => "CLIENT_SS58_PREFIX",
+			Self::ClientWaitForHeight => "CLIENT_WAIT_FOR_HEIGHT",
+			Self::ChainVerifyJustification => "CHAIN_VERIFY_JUSTIFICATION",
+			Self::SubmissionFromParts => "SUBMISSION_FROM_PARTS",
+			Self::TxSubmitDataByKey => "TX_SUBMIT_DATA_BY_KEY",
+			Self::ChainStorageEvents => "CHAIN_STORAGE_EVENTS",
+			Self::ClientMaxAppDataLength => "CLIENT_MAX_APP_DATA_LENGTH",
+			Self::ChainHeaders => "CHAIN_HEADERS",
+			Self::ChainBlockTime => "CHAIN_BLOCK_TIME",
+			Self::SubmissionCancel => "SUBMISSION_CANCEL",
+			Self::ClientNewPooled => "CLIENT_NEW_POOLED",
+			Self::ChainBlockState => "CHAIN_BLOCK_STATE",
+			Self::ClientConnectionOptions => "CLIENT_CONNECTION_OPTIONS",
+			Self::OptionsMetadataHash => "OPTIONS_METADATA_HASH",
 		}
 	}
 
@@ -70,6 +100,21 @@ impl ErrorOperation {
 			"BLOCK_JUSTIFICATION" => Some(Self::BlockJustification),
 			"SECRET_URI_PARSE" => Some(Self::SecretUriParse),
 			"KEYPAIR_PARSE" => Some(Self::KeypairParse),
+			"SUBMISSION_FINALIZED_REF" => Some(Self::SubmissionFinalizedRef),
+			"CLIENT_SS58_PREFIX" => Some(Self::ClientSs58Prefix),
+			"CLIENT_WAIT_FOR_HEIGHT" => Some(Self::ClientWaitForHeight),
+			"CHAIN_VERIFY_JUSTIFICATION" => Some(Self::ChainVerifyJustification),
+			"SUBMISSION_FROM_PARTS" => Some(Self::SubmissionFromParts),
+			"TX_SUBMIT_DATA_BY_KEY" => Some(Self::TxSubmitDataByKey),
+			"CHAIN_STORAGE_EVENTS" => Some(Self::ChainStorageEvents),
+			"CLIENT_MAX_APP_DATA_LENGTH" => Some(Self::ClientMaxAppDataLength),
+			"CHAIN_HEADERS" => Some(Self::ChainHeaders),
+			"CHAIN_BLOCK_TIME" => Some(Self::ChainBlockTime),
+			"SUBMISSION_CANCEL" => Some(Self::SubmissionCancel),
+			"CLIENT_NEW_POOLED" => Some(Self::ClientNewPooled),
+			"CHAIN_BLOCK_STATE" => Some(Self::ChainBlockState),
+			"CLIENT_CONNECTION_OPTIONS" => Some(Self::ClientConnectionOptions),
+			"OPTIONS_METADATA_HASH" => Some(Self::OptionsMetadataHash),
 			_ => None,
 		}
 	}
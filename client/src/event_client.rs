@@ -0,0 +1,66 @@
+//! Event lookups that start from a transaction rather than from a block.
+
+use crate::{Client, Error, H256, block::BlockEvents};
+use avail_rust_core::rpc::DataFormat;
+
+/// Events emitted by one extrinsic, together with the block that included it.
+#[derive(Debug, Clone)]
+pub struct ExtrinsicEvents {
+	/// Hash of the block that included the extrinsic.
+	pub block_hash: H256,
+	/// Height of the block that included the extrinsic.
+	pub block_height: u32,
+	/// Position of the extrinsic within its block.
+	pub ext_index: u32,
+	/// Events emitted while the extrinsic was applied.
+	pub events: BlockEvents,
+}
+
+/// Looks up events by transaction, holding a clone of the client.
+///
+/// Obtained from [`Client::event_client`]. Events of a known block are read with
+/// [`Block::events`](crate::block::Block::events) instead.
+#[derive(Clone)]
+pub struct EventClient {
+	client: Client,
+}
+
+impl EventClient {
+	pub(crate) fn new(client: Client) -> Self {
+		Self { client }
+	}
+
+	/// Searches the last `search_depth` finalized blocks, newest first, for `tx_hash` and returns
+	/// the events it emitted.
+	///
+	/// Returns `None` when the transaction is not included in the searched range.
+	///
+	/// # Errors
+	/// Returns an error when a block hash, its extrinsics or its events cannot be fetched.
+	pub async fn events_by_tx_hash(&self, tx_hash: H256, search_depth: u32) -> Result<Option<ExtrinsicEvents>, Error> {
+		let chain = self.client.chain();
+		let head_height = self.client.finalized().block_height().await?;
+		let allow_list = Some(vec![tx_hash.into()]);
+
+		for offset in 0..search_depth {
+			let Some(block_height) = head_height.checked_sub(offset) else {
+				break;
+			};
+			let Some(block_hash) = chain.block_hash(Some(block_height)).await? else {
+				continue;
+			};
+
+			let exts = chain
+				.extrinsics(block_hash, allow_list.clone(), Default::default(), DataFormat::None)
+				.await?;
+			let Some(ext) = exts.first() else {
+				continue;
+			};
+
+			let events = self.client.block(block_hash).events().extrinsic(ext.ext_index).await?;
+			return Ok(Some(ExtrinsicEvents { block_hash, block_height, ext_index: ext.ext_index, events }));
+		}
+
+		Ok(None)
+	}
+}
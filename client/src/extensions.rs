@@ -1,4 +1,8 @@
-use avail_rust_core::{AccountId, H256, ext::subxt_core::utils::AccountId32, utils::account_id_from_slice};
+use crate::{Error, account::Account, conversions, error_ops::ErrorOperation};
+use avail_rust_core::{
+	AccountId, H256, ext::subxt_core::utils::AccountId32, subxt_signer::sr25519::Keypair, utils::account_id_from_slice,
+};
+use std::str::FromStr;
 
 /// Extension helpers for working with `H256` values.
 pub trait H256Ext {
@@ -47,6 +51,11 @@ pub trait AccountIdExt {
 	///
 	/// Returns an `AccountId` with all bytes set to zero.
 	fn default() -> AccountId;
+
+	/// Parses each SS58 address into an `AccountId`.
+	///
+	/// Results keep the input order; an invalid address yields an error in its own slot.
+	fn from_ss58_batch(addrs: &[&str]) -> Vec<Result<AccountId, Error>>;
 }
 
 impl AccountIdExt for AccountId {
@@ -57,4 +66,80 @@ impl AccountIdExt for AccountId {
 	fn default() -> AccountId {
 		AccountId32([0u8; 32])
 	}
+
+	fn from_ss58_batch(addrs: &[&str]) -> Vec<Result<AccountId, Error>> {
+		addrs
+			.iter()
+			.map(|addr| {
+				AccountId::from_str(addr).map_err(|e| {
+					Error::validation_with_op(ErrorOperation::ConversionToAccountId, std::format!("{addr}: {e}"))
+				})
+			})
+			.collect()
+	}
+}
+
+/// Extension helpers for reading account data from a `Keypair`.
+pub trait KeypairExt {
+	/// Returns the `AccountId` derived from the public key.
+	fn account_id(&self) -> AccountId;
+
+	/// Returns the SS58 address derived from the public key.
+	fn ss58_address(&self) -> String;
+
+	/// Derives a keypair from `uri` and checks that it controls `address`.
+	///
+	/// Returns an error when either the URI or the SS58 address cannot be parsed.
+	fn verify_uri_matches(uri: &str, address: &str) -> Result<bool, Error>;
+}
+
+impl KeypairExt for Keypair {
+	fn account_id(&self) -> AccountId {
+		self.public_key().to_account_id()
+	}
+
+	fn ss58_address(&self) -> String {
+		self.account_id().to_string()
+	}
+
+	fn verify_uri_matches(uri: &str, address: &str) -> Result<bool, Error> {
+		let keypair = Account::new_from_str(uri)?;
+		let account_id = conversions::account_id_like::to_account_id(address)?;
+		Ok(keypair.account_id() == account_id)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::dev_accounts;
+
+	#[test]
+	fn keypair_ss58_address() {
+		let alice = dev_accounts::alice();
+		assert_eq!(alice.ss58_address(), alice.account_id().to_string());
+		assert_eq!(alice.ss58_address(), "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+	}
+
+	#[test]
+	fn keypair_verify_uri_matches() {
+		let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+		assert!(Keypair::verify_uri_matches("//Alice", alice).unwrap());
+		assert!(!Keypair::verify_uri_matches("//Bob", alice).unwrap());
+		assert!(Keypair::verify_uri_matches("//Alice", "not an address").is_err());
+	}
+
+	#[test]
+	fn account_id_from_ss58_batch() {
+		let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+		let bob = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+		let results = AccountId::from_ss58_batch(&[alice, "not an address", bob, ""]);
+
+		assert_eq!(results.len(), 4);
+		assert_eq!(results[0].as_ref().unwrap(), &dev_accounts::alice().account_id());
+		assert!(results[1].is_err());
+		assert_eq!(results[2].as_ref().unwrap(), &dev_accounts::bob().account_id());
+		assert!(results[3].is_err());
+		assert!(AccountId::from_ss58_batch(&[]).is_empty());
+	}
 }
@@ -19,8 +19,10 @@
 
 #[macro_use]
 mod macros;
+mod nonce_cache;
 
 pub mod account;
+pub mod app_submissions;
 pub mod blob;
 pub mod block;
 pub mod chain;
@@ -29,8 +31,10 @@ pub mod clients;
 pub mod config;
 pub mod constants;
 pub mod conversions;
+pub mod data_availability;
 pub mod error;
 pub mod error_ops;
+pub mod event_client;
 pub mod extensions;
 pub mod platform;
 pub mod retry_policy;
@@ -41,45 +45,51 @@ pub mod transaction_options;
 pub mod utils;
 
 pub use account::Account;
+pub use app_submissions::{AppSubmission, AppSubmissionPager};
 pub use avail_rust_core::{
-	self, AccountId, AvailHeader, BlockInfo, DataFormat, Extension, ExtensionImplicit, Extrinsic, ExtrinsicCall,
-	ExtrinsicDecodable, HasHeader, HashNumber, HeaderExtension, KateCommitment, MultiAddress, RpcError,
+	self, AccountId, AvailHeader, BlockInfo, DataFormat, EventPhase, Extension, ExtensionImplicit, Extrinsic,
+	ExtrinsicCall, ExtrinsicDecodable, HasHeader, HashNumber, HeaderExtension, KateCommitment, MultiAddress, RpcError,
 	TransactionEventDecodable, TransactionEventEncodable, avail,
 	ext::{codec, primitive_types, scale_info, scale_value, subxt_core, subxt_metadata, subxt_rpcs, subxt_signer},
 	grandpa::GrandpaJustification,
-	multi_account_id,
+	multi_account_id, multi_account_id_ss58,
 	rpc::{AllowedEvents, AllowedExtrinsic, LegacyBlock},
 	substrate::{
 		StorageDoubleMap, StorageDoubleMapIterator, StorageHasher, StorageMap, StorageMapIterator, StorageValue,
 	},
 };
 pub use blob::{FindBlobExtOutcome, FoundBlobExt};
-pub use block::{EventsQuery, ExtrinsicsQuery, TypedExtrinsic, UntypedExtrinsic};
-pub use chain::{Head, HeadKind};
+pub use block::{EventsQuery, ExtrinsicsQuery, LazyExtrinsic, TypedExtrinsic, UntypedExtrinsic};
+pub use chain::{BlockState, Head, HeadKind, ParseBlockStateError};
 #[cfg(feature = "tracing")]
 pub use client::TracingFormat;
-pub use client::{Client, ConnectionOptions};
+pub use client::{
+	BEST_HEADERS_POLL_INTERVAL, Client, ConnectionOptions, Network, REQUEST_TIMEOUT_ENV, SigningContext, TransportKind,
+};
 pub use constants::{
-	LOCAL_ENDPOINT, LOCAL_WS_ENDPOINT, MAINNET_ENDPOINT, MAINNET_WS_ENDPOINT, ONE_AVAIL, ONE_HUNDRED_AVAIL,
-	ONE_THOUSAND_AVAIL, TEN_AVAIL, THOUSAND_AVAIL, TURING_ENDPOINT, TURING_WS_ENDPOINT, dev_accounts,
+	LOCAL_ENDPOINT, LOCAL_WS_ENDPOINT, MAINNET_ENDPOINT, MAINNET_GENESIS_HASH, MAINNET_WS_ENDPOINT, ONE_AVAIL,
+	ONE_HUNDRED_AVAIL, ONE_THOUSAND_AVAIL, TEN_AVAIL, THOUSAND_AVAIL, TURING_ENDPOINT, TURING_GENESIS_HASH,
+	TURING_WS_ENDPOINT, dev_accounts,
 };
 pub use error::{Error, ErrorCode, UserError};
 pub use error_ops::*;
-pub use extensions::AccountIdExt;
+pub use extensions::{AccountIdExt, KeypairExt};
 pub use primitive_types::{H256, U256};
 pub use retry_policy::RetryPolicy;
 pub use submission::{
-	SubmissionOutcome, SubmittableTransaction, SubmittedTransaction, TransactionReceipt, submitted::WaitOption,
+	BalanceCheck, CancellationToken, FeeEstimate, SignerPayload, SubmissionOutcome, SubmittableTransaction,
+	SubmittedTransaction, TransactionReceipt, TransactionSequence, submitted::WaitOption,
 };
 pub use subscription::{
-	BlockQueryMode, Fetcher, SubscribeApi, Subscription, SubscriptionBuilder, SubscriptionItem,
+	BlockQueryMode, BlockSource, BlockSourceError, Fetcher, StorageMapChanges, SubscribeApi, Subscription,
+	SubscriptionBuilder, SubscriptionItem,
 	fetcher::{
 		BlockEventsFetcher, BlockFetcher, BlockHeaderFetcher, BlockInfoFetcher, ExtrinsicFetcher,
-		GrandpaJustificationFetcher, LegacyBlockFetcher, UntypedExtrinsicFetcher,
+		GrandpaJustificationFetcher, LegacyBlockFetcher, MatchingExtrinsicFetcher, UntypedExtrinsicFetcher,
 	},
 };
 pub use subxt_signer::{SecretUri, sr25519::Keypair};
-pub use transaction_options::{Mortality, MortalityOption, Options};
+pub use transaction_options::{Mortality, MortalityOption, Options, ResolvedOptions};
 
 // External
 pub mod ext {
@@ -0,0 +1,117 @@
+//! Bounded least-recently-used cache of account nonces, kept in step with submissions.
+
+use avail_rust_core::{AccountId, Extrinsic, MultiAddress, Preamble};
+use codec::Decode;
+use std::collections::VecDeque;
+
+/// Remembers the next nonce of recently used accounts, keyed by the finalized height it was read at.
+///
+/// Entries are advanced when a submission is accepted and dropped when one is rejected. An entry is
+/// only served while the finalized height is unchanged; once it advances, transactions may have been
+/// included or dropped from the pool, so the nonce is read from the node again.
+#[derive(Debug, Clone)]
+pub(crate) struct NonceCache {
+	capacity: usize,
+	/// Least recently used entry first.
+	entries: VecDeque<NonceEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct NonceEntry {
+	account_id: AccountId,
+	nonce: u32,
+	finalized_height: u32,
+}
+
+impl NonceCache {
+	pub(crate) fn new(capacity: usize) -> Self {
+		Self { capacity, entries: VecDeque::with_capacity(capacity) }
+	}
+
+	/// Returns the cached next nonce of `account_id`, marking it as recently used.
+	///
+	/// An entry stored at a different finalized height is dropped and `None` is returned.
+	pub(crate) fn get(&mut self, account_id: &AccountId, finalized_height: u32) -> Option<u32> {
+		let pos = self.entries.iter().position(|x| &x.account_id == account_id)?;
+		let entry = self.entries.remove(pos)?;
+		if entry.finalized_height != finalized_height {
+			return None;
+		}
+		let nonce = entry.nonce;
+		self.entries.push_back(entry);
+		Some(nonce)
+	}
+
+	/// Stores `nonce` for `account_id` as read at `finalized_height`, evicting the least recently used
+	/// entry when full.
+	pub(crate) fn insert(&mut self, account_id: AccountId, nonce: u32, finalized_height: u32) {
+		if self.capacity == 0 {
+			return;
+		}
+		self.remove(&account_id);
+		if self.entries.len() >= self.capacity {
+			self.entries.pop_front();
+		}
+		self.entries
+			.push_back(NonceEntry { account_id, nonce, finalized_height });
+	}
+
+	/// Records that a transaction with `nonce` was accepted, so the next lookup returns `nonce + 1`.
+	pub(crate) fn mark_used(&mut self, account_id: &AccountId, nonce: u32) {
+		if let Some(entry) = self.entries.iter_mut().find(|x| &x.account_id == account_id) {
+			entry.nonce = entry.nonce.max(nonce.saturating_add(1));
+		}
+	}
+
+	/// Drops the entry of `account_id`, forcing the next lookup to read the nonce from the node.
+	pub(crate) fn remove(&mut self, account_id: &AccountId) {
+		if let Some(pos) = self.entries.iter().position(|x| &x.account_id == account_id) {
+			self.entries.remove(pos);
+		}
+	}
+}
+
+/// Returns the signer and nonce of an encoded signed extrinsic, or `None` for anything else.
+pub(crate) fn signer_nonce(extrinsic: &[u8]) -> Option<(AccountId, u32)> {
+	let extrinsic = Extrinsic::decode(&mut &extrinsic[..]).ok()?;
+	match extrinsic.preamble {
+		Preamble::Signed(MultiAddress::Id(account_id), _, extension) => Some((account_id, extension.nonce)),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn account(value: u8) -> AccountId {
+		AccountId::from([value; 32])
+	}
+
+	#[test]
+	fn nonce_cache_evicts_and_invalidates() {
+		let mut cache = NonceCache::new(2);
+		cache.insert(account(1), 5, 10);
+		cache.insert(account(2), 7, 10);
+		assert_eq!(cache.get(&account(1), 10), Some(5));
+
+		// Account 2 is now the least recently used one.
+		cache.insert(account(3), 1, 10);
+		assert_eq!(cache.get(&account(2), 10), None);
+		assert_eq!(cache.get(&account(1), 10), Some(5));
+
+		cache.mark_used(&account(1), 5);
+		assert_eq!(cache.get(&account(1), 10), Some(6));
+		cache.mark_used(&account(1), 2);
+		assert_eq!(cache.get(&account(1), 10), Some(6));
+
+		// A rejected submission drops the entry.
+		cache.remove(&account(3));
+		assert_eq!(cache.get(&account(3), 10), None);
+		assert_eq!(cache.get(&account(1), 10), Some(6));
+
+		// Once the finalized height advances the entry is stale and dropped.
+		assert_eq!(cache.get(&account(1), 11), None);
+		assert_eq!(cache.get(&account(1), 10), None);
+	}
+}
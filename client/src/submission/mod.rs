@@ -1,5 +1,7 @@
+pub mod sequence;
 pub mod submittable;
 pub mod submitted;
 
-pub use submittable::SubmittableTransaction;
-pub use submitted::{SubmissionOutcome, SubmittedTransaction, TransactionReceipt};
+pub use sequence::TransactionSequence;
+pub use submittable::{BalanceCheck, FeeEstimate, SignerPayload, SubmittableTransaction};
+pub use submitted::{CancellationToken, SubmissionOutcome, SubmittedTransaction, TransactionReceipt};
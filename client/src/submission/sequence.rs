@@ -0,0 +1,87 @@
+use super::{SubmittableTransaction, SubmittedTransaction};
+use crate::{
+	Client, Error, RetryPolicy,
+	subxt_signer::sr25519::Keypair,
+	transaction_options::{Options, ResolvedOptions},
+};
+
+/// Builder that submits several calls from one signer in order, assigning consecutive nonces.
+///
+/// Nonce, tip, and mortality are resolved once for the whole sequence; every queued call reuses the
+/// same mortality and tip and receives `nonce + position`. Submission does not wait for inclusion.
+#[derive(Clone)]
+pub struct TransactionSequence {
+	client: Client,
+	signer: Keypair,
+	options: Options,
+	calls: Vec<SubmittableTransaction>,
+	retry_on_error: RetryPolicy,
+}
+
+impl TransactionSequence {
+	/// Creates an empty sequence for `signer`.
+	pub fn new(client: Client, signer: &Keypair) -> Self {
+		Self {
+			client,
+			signer: signer.clone(),
+			options: Options::new(),
+			calls: Vec::new(),
+			retry_on_error: RetryPolicy::Inherit,
+		}
+	}
+
+	/// Queues a call to be submitted after the ones already pushed.
+	pub fn push(mut self, tx: SubmittableTransaction) -> Self {
+		self.calls.push(tx);
+		self
+	}
+
+	/// Sets the options used for the sequence. An explicit nonce is used for the first call.
+	pub fn options(mut self, value: Options) -> Self {
+		self.options = value;
+		self
+	}
+
+	/// Sets retry behavior for nonce resolution and submission.
+	pub fn retry_policy(mut self, value: RetryPolicy) -> Self {
+		self.retry_on_error = value;
+		self
+	}
+
+	/// Returns the number of queued calls.
+	pub fn len(&self) -> usize {
+		self.calls.len()
+	}
+
+	/// Reports whether no calls have been queued.
+	pub fn is_empty(&self) -> bool {
+		self.calls.is_empty()
+	}
+
+	/// Submits every queued call in order and returns their handles in the same order.
+	///
+	/// Stops at the first failed submission; calls submitted before the failure stay in the pool.
+	pub async fn submit_all(&self) -> Result<Vec<SubmittedTransaction>, Error> {
+		if self.calls.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let account_id = self.signer.public_key().to_account_id();
+		let base: ResolvedOptions = self
+			.options
+			.resolve(&self.client, &account_id, self.retry_on_error)
+			.await?;
+
+		let mut result = Vec::with_capacity(self.calls.len());
+		for (i, call) in self.calls.iter().enumerate() {
+			let mut resolved = base.clone();
+			resolved.nonce = base.nonce + i as u32;
+
+			let mut call = call.clone();
+			call.set_retry_policy(self.retry_on_error);
+			result.push(call.submit_with_resolved(&self.signer, &resolved).await?);
+		}
+
+		Ok(result)
+	}
+}
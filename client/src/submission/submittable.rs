@@ -1,15 +1,98 @@
 use super::submitted::SubmissionOutcome;
 use crate::{
-	Client, Error, RetryPolicy, chain::Chain, submission::submitted::WaitOption, subxt_signer::sr25519::Keypair,
-	transaction_options::Options,
+	Client, Error, RetryPolicy,
+	chain::Chain,
+	conversions,
+	error_ops::ErrorOperation,
+	submission::submitted::WaitOption,
+	subxt_signer::sr25519::Keypair,
+	transaction_options::{Options, ResolvedOptions},
 };
 use avail_rust_core::{
-	ExtrinsicBorrowed, H256, HasHeader, RpcError,
+	AccountId, AccountIdLike, Extension, ExtensionImplicit, Extrinsic, ExtrinsicBorrowed, H256, HasHeader,
+	MultiAddress, MultiSignature, Preamble, RpcError, SignedPayload,
 	ext::codec::Encode,
 	substrate::extrinsic::ExtrinsicCall,
-	types::substrate::{FeeDetails, RuntimeDispatchInfo},
+	types::substrate::{DispatchClass, FeeDetails, RuntimeDispatchInfo, Weight},
 };
 
+/// Fee estimate for a call, together with the dispatch class and weight the runtime charged it for.
+#[derive(Debug, Clone)]
+pub struct FeeEstimate {
+	pub fee_details: FeeDetails,
+	pub dispatch_class: DispatchClass,
+	pub weight: Weight,
+}
+
+impl FeeEstimate {
+	/// Returns the final fee, see [`FeeDetails::final_fee`].
+	pub fn final_fee(&self) -> u128 {
+		self.fee_details.final_fee()
+	}
+}
+
+/// Result of [`SubmittableTransaction::check_balance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceCheck {
+	/// Free balance of the signer at the best block.
+	pub free: u128,
+	/// Estimated fee, including any tip.
+	pub fee: u128,
+	/// Amount the call moves out of the signer's account, if it is a transfer.
+	pub value: u128,
+}
+
+impl BalanceCheck {
+	/// Returns the amount the signer needs to cover the fee and the transferred value.
+	pub fn required(&self) -> u128 {
+		self.fee.saturating_add(self.value)
+	}
+
+	/// Returns true when the free balance covers [`Self::required`].
+	pub fn is_sufficient(&self) -> bool {
+		self.free >= self.required()
+	}
+
+	/// Returns how much is missing, or zero when the balance is sufficient.
+	pub fn shortfall(&self) -> u128 {
+		self.required().saturating_sub(self.free)
+	}
+}
+
+/// Signing payload for an external signer, produced by [`SubmittableTransaction::signer_payload`].
+///
+/// Keeps the extension and implicit data the payload was built from, so the extrinsic can be
+/// assembled once the signature comes back.
+#[derive(Debug, Clone)]
+pub struct SignerPayload {
+	pub account_id: AccountId,
+	pub call: ExtrinsicCall,
+	pub extension: Extension,
+	pub implicit: ExtensionImplicit,
+}
+
+impl SignerPayload {
+	/// Returns the exact bytes the wallet has to sign with sr25519.
+	///
+	/// Payloads longer than 256 bytes are replaced by their blake2-256 hash, as the runtime expects.
+	pub fn bytes(&self) -> Vec<u8> {
+		SignedPayload::new(&self.call.0, &self.extension, &self.implicit).message()
+	}
+
+	/// Assembles the signed extrinsic from the wallet's signature over [`Self::bytes`].
+	///
+	/// Fails with a validation error when the signature does not match the payload.
+	pub fn into_extrinsic(self, signature: [u8; 64]) -> Result<Vec<u8>, Error> {
+		SubmittableTransaction::from_parts(
+			&self.call,
+			MultiAddress::Id(self.account_id),
+			MultiSignature::Sr25519(signature),
+			self.extension,
+			&self.implicit,
+		)
+	}
+}
+
 /// Builder that keeps an encoded call together with the client connection and exposes helpers for
 /// signing, submitting, and querying execution costs.
 #[derive(Clone)]
@@ -39,6 +122,50 @@ impl SubmittableTransaction {
 		self.chain().sign_and_submit_call(signer, &self.call.0, options).await
 	}
 
+	/// Resolves `options` (nonce, tip, mortality) for `signer` without signing or submitting.
+	///
+	/// The returned [`ResolvedOptions`] can be adjusted and passed to
+	/// [`submit_with_resolved`](Self::submit_with_resolved).
+	pub async fn resolve_options(&self, signer: &Keypair, options: Options) -> Result<ResolvedOptions, Error> {
+		let account_id = signer.public_key().to_account_id();
+		options.resolve(&self.client, &account_id, self.retry_on_error).await
+	}
+
+	/// Builds the payload an external or air-gapped wallet signs for `account_id`, without a keypair.
+	///
+	/// `options` are resolved as for [`submit`](Self::submit); submit the result of
+	/// [`SignerPayload::into_extrinsic`] before the chosen nonce or mortality goes stale. Options that
+	/// include the `CheckMetadataHash` extension are rejected with a validation error.
+	pub async fn signer_payload(
+		&self,
+		account_id: impl Into<AccountIdLike>,
+		options: Options,
+	) -> Result<SignerPayload, Error> {
+		let account_id = conversions::account_id_like::to_account_id(account_id)?;
+		let resolved = options.resolve(&self.client, &account_id, self.retry_on_error).await?;
+		resolved.ensure_without_metadata_hash()?;
+		let extension = Extension::from(&resolved);
+		let implicit = self.chain().extension_implicit(&resolved);
+
+		Ok(SignerPayload { account_id, call: self.call.clone(), extension, implicit })
+	}
+
+	/// Signs the call with already resolved options and submits it.
+	pub async fn submit_with_resolved(
+		&self,
+		signer: &Keypair,
+		resolved: &ResolvedOptions,
+	) -> Result<super::SubmittedTransaction, Error> {
+		self.chain()
+			.sign_and_submit_call_resolved(signer, &self.call.0, resolved)
+			.await
+	}
+
+	/// Submits the transaction and waits until it is included, returning its receipt.
+	///
+	/// A transaction that never lands within its mortality window fails with
+	/// [`Error::NotFound`] instead of yielding an empty result. Pass
+	/// [`BlockQueryMode::Finalized`](crate::subscription::BlockQueryMode::Finalized) to wait for finality.
 	pub async fn submit_and_wait_for_receipt(
 		&self,
 		signer: &Keypair,
@@ -49,6 +176,11 @@ impl SubmittableTransaction {
 		submitted.receipt(wait_opts).await
 	}
 
+	/// Same as [`Self::submit_and_wait_for_receipt`] but also returns the transaction's events.
+	///
+	/// The events are the ones emitted by this extrinsic only, ready for checks such as
+	/// [`BlockEvents::is_extrinsic_success_present`](crate::block::BlockEvents::is_extrinsic_success_present).
+	#[doc(alias = "submit_and_events")]
 	pub async fn submit_and_wait_for_outcome(
 		&self,
 		signer: &Keypair,
@@ -72,6 +204,17 @@ impl SubmittableTransaction {
 		self.chain().transaction_payment_query_call_fee_details(call, at).await
 	}
 
+	/// Estimates call fees and reports the dispatch class they were computed for.
+	///
+	/// The runtime applies the class-specific weight rules, so operational and normal calls are
+	/// estimated the same way they will be charged.
+	pub async fn estimate_fees(&self, at: Option<H256>) -> Result<FeeEstimate, RpcError> {
+		let info = self.client.limited(self.call_info(at));
+		let fee_details = self.client.limited(self.estimate_call_fees(at));
+		let (info, fee_details) = futures::future::try_join(info, fee_details).await?;
+		Ok(FeeEstimate { fee_details, dispatch_class: info.class, weight: info.weight })
+	}
+
 	/// Signs the call and estimates fees for the exact extrinsic payload.
 	pub async fn estimate_extrinsic_fees(
 		&self,
@@ -87,6 +230,36 @@ impl SubmittableTransaction {
 			.await?)
 	}
 
+	/// Checks, before submitting, whether `signer` can pay the fees and the amount the call transfers.
+	///
+	/// This is an opt-in pre-flight; submission does not run it. Only `transfer_allow_death` and
+	/// `transfer_keep_alive` count a transferred amount, other calls are checked against their fees.
+	pub async fn check_balance(&self, signer: &Keypair, options: Options) -> Result<BalanceCheck, Error> {
+		let account_id = signer.public_key().to_account_id();
+		let best = self.client.best();
+		let fees = self.client.limited(self.estimate_extrinsic_fees(signer, options, None));
+		let balance = self.client.limited(best.account_balance(account_id));
+		let (fees, balance) = futures::future::try_join(fees, balance).await?;
+
+		Ok(BalanceCheck {
+			free: balance.free,
+			fee: fees.final_fee(),
+			value: self.transferred_value(),
+		})
+	}
+
+	fn transferred_value(&self) -> u128 {
+		use avail_rust_core::avail::balances::tx::{TransferAllowDeath, TransferKeepAlive};
+
+		if let Ok(call) = self.call.try_decode::<TransferAllowDeath>() {
+			return call.value;
+		}
+		if let Ok(call) = self.call.try_decode::<TransferKeepAlive>() {
+			return call.value;
+		}
+		0
+	}
+
 	/// Returns runtime dispatch information for the call, including weight, class, and partial fee
 	/// estimation based on the provided block context.
 	///
@@ -123,12 +296,48 @@ impl SubmittableTransaction {
 		SubmittableTransaction::new(client, call)
 	}
 
+	/// Re-encodes a signed extrinsic from decoded parts, checking the signature first.
+	///
+	/// `implicit` must hold the runtime versions, genesis hash and mortality checkpoint the extrinsic was
+	/// signed with. Only sr25519 signatures from an `Id` address are supported; anything else, or a signature
+	/// that does not match the payload, is reported as a validation error.
+	pub fn from_parts(
+		call: &ExtrinsicCall,
+		address: MultiAddress,
+		signature: MultiSignature,
+		extension: Extension,
+		implicit: &ExtensionImplicit,
+	) -> Result<Vec<u8>, Error> {
+		let (MultiAddress::Id(account_id), MultiSignature::Sr25519(raw_signature)) = (&address, &signature) else {
+			return Err(Error::validation_with_op(
+				ErrorOperation::SubmissionFromParts,
+				"Only sr25519 signatures from account id addresses are supported",
+			));
+		};
+
+		let payload = SignedPayload::new(&call.0, &extension, implicit);
+		if !payload.verify(raw_signature, account_id) {
+			return Err(Error::validation_with_op(
+				ErrorOperation::SubmissionFromParts,
+				"Signature does not match the call, extension and implicit data",
+			));
+		}
+
+		let preamble = Preamble::Signed(address, signature, extension);
+		Ok(Extrinsic { preamble, call: call.clone() }.encode())
+	}
+
 	/// Hashes the call payload as it would appear in an extrinsic, returning the blake2 hash used by
 	/// the runtime for call identification.
 	pub fn call_hash(&self) -> H256 {
 		H256::from(self.call.hash())
 	}
 
+	/// Returns the encoded call as `0x`-prefixed hex, handy for logging or reproducing a submission.
+	pub fn call_data_hex(&self) -> String {
+		const_hex::encode_prefixed(&self.call.0)
+	}
+
 	fn chain(&self) -> Chain {
 		self.client
 			.chain()
@@ -147,3 +356,38 @@ impl From<&SubmittableTransaction> for ExtrinsicCall {
 		value.call.clone()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use avail_rust_core::{Era, avail::data_availability::tx::SubmitData};
+
+	#[test]
+	fn from_parts_round_trips_decoded_extrinsic() {
+		let signer = crate::dev_accounts::alice();
+		let call = ExtrinsicCall::from(&SubmitData { app_id: 2, data: b"from parts".to_vec() });
+		let extension = Extension::new(Era::mortal(32, 100), 3, 0);
+		let mut implicit = ExtensionImplicit::new(1, 1, H256::repeat_byte(1), H256::repeat_byte(2));
+		let signature = SignedPayload::sign_static(&call.0, &extension, &implicit, &signer);
+		let account_id = signer.public_key().to_account_id();
+		let encoded = ExtrinsicBorrowed::new_signed(account_id, signature, extension, &call.0).encode();
+
+		let decoded = Extrinsic::try_from(&encoded).unwrap();
+		let Preamble::Signed(address, signature, extension) = decoded.preamble else {
+			panic!("Expected a signed extrinsic");
+		};
+		let rebuilt = SubmittableTransaction::from_parts(
+			&decoded.call,
+			address.clone(),
+			signature.clone(),
+			extension.clone(),
+			&implicit,
+		)
+		.unwrap();
+		assert_eq!(rebuilt, encoded);
+
+		implicit.spec_version = 2;
+		let result = SubmittableTransaction::from_parts(&decoded.call, address, signature, extension, &implicit);
+		assert!(matches!(result, Err(Error::Validation(_))));
+	}
+}
@@ -2,13 +2,24 @@ use crate::{
 	Client, Error, UserError,
 	block::{self, Block, events::BlockEvents},
 	conversions,
+	error::ErrorCode,
 	error_ops::ErrorOperation,
+	ext::sp_crypto_hashing,
 	platform,
 	subscription::sub::{BlockQueryMode, Sub, SubConfig},
+	subxt_signer::sr25519::Keypair,
+	transaction_options::Options,
+};
+use avail_rust_core::{
+	BlockInfo, DataFormat, DecodedEra, Era, Extrinsic, H256, HasHeader, MultiAddress, Preamble, RpcError,
+	types::metadata::HashString,
 };
-use avail_rust_core::{DataFormat, H256, HasHeader, RpcError, types::metadata::HashString};
 use codec::Decode;
-use std::time::Duration;
+use std::{future::Future, sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+/// Delay before a receipt search resumes after a transport failure.
+const RECEIPT_RESUME_DELAY: Duration = Duration::from_secs(1);
 
 #[derive(Debug, Clone, Copy)]
 pub struct WaitOption {
@@ -61,6 +72,49 @@ impl Default for WaitOption {
 
 pub type SubmissionOutcome = (TransactionReceipt, BlockEvents);
 
+/// Cloneable signal used to abort long-running waits from another task.
+#[derive(Debug, Clone)]
+pub struct CancellationToken(Arc<watch::Sender<bool>>);
+
+impl CancellationToken {
+	/// Creates a token that has not been cancelled yet.
+	pub fn new() -> Self {
+		Self(Arc::new(watch::Sender::new(false)))
+	}
+
+	/// Signals cancellation to every clone of this token.
+	pub fn cancel(&self) {
+		self.0.send_replace(true);
+	}
+
+	/// Returns whether [`CancellationToken::cancel`] was called on this token or any of its clones.
+	pub fn is_cancelled(&self) -> bool {
+		*self.0.borrow()
+	}
+
+	/// Resolves once the token has been cancelled.
+	pub async fn cancelled(&self) {
+		let mut rx = self.0.subscribe();
+		_ = rx.wait_for(|x| *x).await;
+	}
+}
+
+impl Default for CancellationToken {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Runs `future` until it completes or `cancel` fires, returning `None` on cancellation.
+async fn with_cancel<F: Future>(future: F, cancel: &CancellationToken) -> Option<F::Output> {
+	let future = std::pin::pin!(future);
+	let cancelled = std::pin::pin!(cancel.cancelled());
+	match futures::future::select(future, cancelled).await {
+		futures::future::Either::Left((value, _)) => Some(value),
+		futures::future::Either::Right(_) => None,
+	}
+}
+
 /// Handle for a transaction that has already been submitted.
 #[derive(Clone)]
 pub struct SubmittedTransaction {
@@ -78,10 +132,17 @@ impl SubmittedTransaction {
 
 	pub async fn find_receipt(&self, opts: impl Into<WaitOption>) -> Result<FindReceiptOutcome, Error> {
 		let mut opts = opts.into();
-		opts.max_block_height = opts.max_block_height.or_else(|| Some(self.block_end));
-		find_receipt(self.client.clone(), self.ext_hash, self.block_start, opts).await
+		opts.max_block_height = opts.max_block_height.or(Some(self.block_end));
+		let future = find_receipt(self.client.clone(), self.ext_hash, self.block_start, opts);
+		#[cfg(feature = "tracing")]
+		let future = tracing::Instrument::instrument(future, crate::utils::tx_receipt_span(self.ext_hash));
+		future.await
 	}
 
+	/// Waits until the transaction is included and returns where it landed.
+	///
+	/// Transport failures while waiting do not end the search; it resumes from the first block that
+	/// was not checked yet until the wait timeout elapses.
 	pub async fn receipt(&self, opts: impl Into<WaitOption>) -> Result<TransactionReceipt, Error> {
 		match self.find_receipt(opts).await? {
 			FindReceiptOutcome::Found(receipt) => Ok(receipt),
@@ -96,11 +157,100 @@ impl SubmittedTransaction {
 		}
 	}
 
+	/// Same as [`Self::receipt`] but returns `Ok(None)` as soon as `cancel` is triggered.
+	pub async fn receipt_with_cancel(
+		&self,
+		opts: impl Into<WaitOption>,
+		cancel: CancellationToken,
+	) -> Result<Option<TransactionReceipt>, Error> {
+		with_cancel(self.receipt(opts), &cancel).await.transpose()
+	}
+
 	pub async fn outcome(&self, opts: impl Into<WaitOption>) -> Result<SubmissionOutcome, Error> {
 		let receipt = self.receipt(opts).await?;
 		let events = receipt.events().await?;
 		Ok((receipt, events))
 	}
+
+	/// Returns true when this transaction has left the pool without being included and `other` took
+	/// its place, either still pending or already included in a block.
+	///
+	/// Use it after re-submitting the same nonce with a higher tip to confirm the replacement.
+	pub async fn was_replaced_by(&self, other: &SubmittedTransaction) -> Result<bool, Error> {
+		let pending = self.client.chain().pending_extrinsic_hashes().await?;
+		if pending.contains(&self.ext_hash) || self.is_included().await? {
+			return Ok(false);
+		}
+		if pending.contains(&other.ext_hash) {
+			return Ok(true);
+		}
+
+		other.is_included().await
+	}
+
+	/// Replaces this pending transaction with a zero-value transfer from `signer` to itself at the same
+	/// nonce.
+	///
+	/// The nonce is read from the node's transaction pool, so this fails once the transaction has been
+	/// included or dropped. `tip` must be higher than the original tip for the node to accept the swap.
+	pub async fn cancel(&self, signer: &Keypair, tip: u128) -> Result<SubmittedTransaction, Error> {
+		let account_id = signer.public_key().to_account_id();
+		let extrinsics = self.client.chain().pending_extrinsics().await?;
+		let pending = extrinsics
+			.iter()
+			.find(|x| H256(sp_crypto_hashing::blake2_256(x)) == self.ext_hash)
+			.ok_or_else(|| {
+				Error::not_found_with_op(
+					ErrorOperation::SubmissionCancel,
+					std::format!("Transaction {:?} is not in the transaction pool", self.ext_hash),
+				)
+			})?;
+
+		let extrinsic = Extrinsic::decode(&mut pending.as_slice())
+			.map_err(|e| Error::decode_with_op(ErrorOperation::SubmissionCancel, e.to_string()))?;
+		let nonce = match extrinsic.preamble {
+			Preamble::Signed(MultiAddress::Id(address), _, extension) if address == account_id => extension.nonce,
+			_ => {
+				return Err(Error::validation_with_op(
+					ErrorOperation::SubmissionCancel,
+					"Transaction was not signed by the given signer",
+				));
+			},
+		};
+
+		let tx = self.client.tx().balances().transfer_keep_alive(account_id, 0)?;
+		tx.submit(signer, Options::new().nonce(nonce).tip(tip)).await
+	}
+
+	/// Searches the blocks from `block_start` up to the current best block without waiting for new ones.
+	async fn is_included(&self) -> Result<bool, Error> {
+		let best_height = self.client.best().block_height().await?;
+		if best_height < self.block_start {
+			return Ok(false);
+		}
+
+		let opts = WaitOption::new(BlockQueryMode::Best).max_block_height(Some(best_height.saturating_sub(1)));
+		let outcome = find_receipt(self.client.clone(), self.ext_hash, self.block_start, opts).await?;
+		Ok(matches!(outcome, FindReceiptOutcome::Found(_)))
+	}
+
+	/// Returns how many blocks remain before the transaction's mortality era expires.
+	///
+	/// The era is refined the same way it was when signing, so the result reflects the block at which
+	/// the node stops accepting the transaction. Returns `0` once the finalized head has passed it.
+	pub async fn blocks_until_expiry(&self) -> Result<u64, Error> {
+		let finalized_height = self.client.finalized().block_height().await?;
+		let period = self.block_end.saturating_sub(self.block_start) as u64;
+		let death = era_death(period, self.block_start as u64);
+		Ok(death.saturating_sub(finalized_height as u64))
+	}
+}
+
+/// First block height at which a transaction signed at `block_height` for `period` blocks is no
+/// longer valid.
+fn era_death(period: u64, block_height: u64) -> u64 {
+	let era = DecodedEra::from(&Era::mortal(period, block_height));
+	era.death(block_height).unwrap_or(u64::MAX)
 }
 
 #[derive(Debug, Clone)]
@@ -128,12 +278,36 @@ async fn find_receipt_inner(
 	ext_hash: H256,
 	from_block_height: u32,
 	opts: WaitOption,
+) -> Result<FindReceiptOutcome, Error> {
+	// Transport failures (e.g. a node restart) restart the search from the first unchecked block.
+	let mut next_height = from_block_height;
+	loop {
+		match search_receipt(&client, ext_hash, &mut next_height, opts).await {
+			Err(e) if e.code() == ErrorCode::Transport => {
+				#[cfg(feature = "tracing")]
+				crate::utils::trace_warn(&std::format!(
+					"Receipt search interrupted at block {}, resuming: {}",
+					next_height,
+					e
+				));
+				platform::sleep(RECEIPT_RESUME_DELAY).await;
+			},
+			result => return result,
+		}
+	}
+}
+
+async fn search_receipt(
+	client: &Client,
+	ext_hash: H256,
+	next_height: &mut u32,
+	opts: WaitOption,
 ) -> Result<FindReceiptOutcome, Error> {
 	let allow_list = Some(vec![ext_hash.into()]);
 	let mut sub = client
 		.subscribe()
 		.blocks()
-		.from_height(from_block_height)
+		.from_height(*next_height)
 		.mode(opts.mode)
 		.build()
 		.await?;
@@ -152,6 +326,7 @@ async fn find_receipt_inner(
 				TransactionReceipt::new(client.clone(), block.block_hash, block.block_height, ext_hash, ext_index);
 			return Ok(FindReceiptOutcome::Found(receipt));
 		}
+		*next_height = block.block_height + 1;
 
 		if let Some(max_height) = opts.max_block_height
 			&& block.block_height > max_height
@@ -215,6 +390,54 @@ impl TransactionReceipt {
 		Ok(events)
 	}
 
+	/// Confirms that the receipt's block is finalized and returns a stable reference to it.
+	///
+	/// Fails when the block is not finalized yet or is no longer part of the canonical chain.
+	pub async fn finalized_ref(&self) -> Result<BlockInfo, Error> {
+		let finalized_height = self.client.finalized().block_height().await?;
+		if self.block_height > finalized_height {
+			return Err(Error::validation_with_op(
+				ErrorOperation::SubmissionFinalizedRef,
+				std::format!(
+					"Block {} is not finalized yet; finalized height is {}",
+					self.block_height,
+					finalized_height
+				),
+			));
+		}
+
+		let canonical_hash = self.client.chain().block_hash(Some(self.block_height)).await?;
+		if canonical_hash != Some(self.block_hash) {
+			return Err(Error::not_found_with_op(
+				ErrorOperation::SubmissionFinalizedRef,
+				std::format!("Block {:?} is not part of the finalized chain", self.block_hash),
+			));
+		}
+
+		Ok(BlockInfo::from((self.block_hash, self.block_height)))
+	}
+
+	/// Waits for several submitted transactions and returns whichever is included first, with its index.
+	///
+	/// Transactions whose search fails drop out of the race; an error is returned only once every
+	/// search has failed, carrying the last failure.
+	pub async fn race(
+		submitted: Vec<SubmittedTransaction>,
+		opts: impl Into<WaitOption>,
+	) -> Result<(usize, TransactionReceipt), Error> {
+		if submitted.is_empty() {
+			return Err(UserError::ValidationFailed("At least one transaction is needed to race".into()).into());
+		}
+
+		let opts = opts.into();
+		let searches = submitted
+			.into_iter()
+			.enumerate()
+			.map(|(index, tx)| Box::pin(async move { tx.receipt(opts).await.map(|receipt| (index, receipt)) }));
+		let (winner, _) = futures::future::select_ok(searches).await?;
+		Ok(winner)
+	}
+
 	/// Searches a block range (inclusive) for the given extrinsic hash.
 	/// Returns `Ok(None)` when no match is found.
 	pub async fn from_range(
@@ -258,3 +481,119 @@ impl TransactionReceipt {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		LOCAL_ENDPOINT, RetryPolicy,
+		clients::mock_client::{CommandManagerHelper, MockClient, metadata_bytes},
+		subxt_rpcs::RpcClient,
+	};
+	use serde_json::value::RawValue;
+
+	/// Queues `count` chain info responses whose best and finalized head is `hash` at `height`.
+	fn queue_chain_info(commander: &mut CommandManagerHelper, hash: H256, height: u32, count: usize) {
+		let hash = serde_json::to_string(&hash).unwrap();
+		let info = std::format!(
+			r#"{{"best_hash":{hash},"best_height":{height},"finalized_hash":{hash},"finalized_height":{height},"genesis_hash":{hash}}}"#
+		);
+		for _ in 0..count {
+			commander.add_ok("custom_chainInfo", RawValue::from_string(info.clone()).unwrap());
+		}
+	}
+
+	#[test]
+	fn era_death_follows_refined_era() {
+		// Period 32 at height 100 has phase 4, so the era was born at block 100.
+		assert_eq!(era_death(32, 100), 132);
+		// A period of 50 is rounded up to 64.
+		assert_eq!(era_death(50, 100), 164);
+		// Mortal eras never start before the checkpoint block or end after `checkpoint + period`.
+		for height in 0..200u64 {
+			let death = era_death(32, height);
+			assert!(death > height && death <= height + 32);
+		}
+	}
+
+	#[tokio::test]
+	async fn with_cancel_returns_promptly() {
+		let cancel = CancellationToken::new();
+		let trigger = cancel.clone();
+		tokio::spawn(async move {
+			tokio::time::sleep(Duration::from_millis(50)).await;
+			trigger.cancel();
+		});
+
+		let wait = with_cancel(futures::future::pending::<()>(), &cancel);
+		let result = tokio::time::timeout(Duration::from_secs(2), wait).await;
+		assert_eq!(result, Ok(None));
+		assert!(cancel.is_cancelled());
+	}
+
+	#[tokio::test]
+	async fn with_cancel_passes_through_value() {
+		let cancel = CancellationToken::new();
+		assert_eq!(with_cancel(async { 5 }, &cancel).await, Some(5));
+	}
+
+	#[tokio::test]
+	async fn receipt_survives_transport_failure() -> Result<(), Error> {
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+		client.set_retry_policy(RetryPolicy::Disabled);
+
+		let (block_hash, block_height) = (H256::repeat_byte(5), 5u32);
+		let ext_hash = H256::repeat_byte(7);
+		// Every attempt reads the chain info twice before asking for the block's extrinsics.
+		queue_chain_info(&mut commander, block_hash, block_height, 6);
+		for _ in 0..2 {
+			let reset = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "node restarted");
+			commander.extrinsics_err(Some(crate::subxt_rpcs::Error::Client(Box::new(reset))));
+		}
+		commander.extrinsics_ok(vec![avail_rust_core::rpc::Extrinsic {
+			data: String::new(),
+			ext_hash,
+			ext_index: 1,
+			pallet_id: 0,
+			variant_id: 0,
+			account_id: None,
+			nonce: None,
+		}]);
+
+		let submitted = SubmittedTransaction::new(client, ext_hash, block_height, block_height + 10);
+		let receipt = submitted.receipt(BlockQueryMode::Finalized).await?;
+		assert_eq!((receipt.block_hash, receipt.block_height), (block_hash, block_height));
+		assert_eq!((receipt.ext_hash, receipt.ext_index), (ext_hash, 1));
+		assert_eq!(commander.call_count("custom_extrinsics"), 3);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn receipt_with_cancel_stops_waiting() -> Result<(), Error> {
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+		client.set_retry_policy(RetryPolicy::Disabled);
+
+		// The chain stays below the first block of the search, so the receipt keeps waiting for it.
+		queue_chain_info(&mut commander, H256::repeat_byte(5), 5, 20);
+		let submitted = SubmittedTransaction::new(client, H256::repeat_byte(7), 10, 20);
+
+		let cancel = CancellationToken::new();
+		let trigger = cancel.clone();
+		tokio::spawn(async move {
+			tokio::time::sleep(Duration::from_millis(100)).await;
+			trigger.cancel();
+		});
+
+		let wait = submitted.receipt_with_cancel(BlockQueryMode::Finalized, cancel);
+		let result = tokio::time::timeout(Duration::from_secs(2), wait).await;
+		assert!(matches!(result, Ok(Ok(None))));
+		assert_eq!(commander.call_count("custom_extrinsics"), 0);
+
+		Ok(())
+	}
+}
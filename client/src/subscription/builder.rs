@@ -1,9 +1,10 @@
 use super::{
 	fetcher::Fetcher,
+	source::BlockSource,
 	sub::{Sub, SubConfig, Subscription},
 };
 use crate::{Client, Error, RetryPolicy};
-use std::time::Duration;
+use std::{collections::VecDeque, time::Duration};
 
 use super::sub::BlockQueryMode;
 
@@ -15,6 +16,9 @@ pub struct SubscriptionBuilder<F: Fetcher> {
 	poll_interval: Duration,
 	retry_policy: RetryPolicy,
 	skip_empty: bool,
+	skip_errors: bool,
+	catch_up: bool,
+	source: Option<BlockSource>,
 }
 
 impl<F: Fetcher> SubscriptionBuilder<F> {
@@ -27,6 +31,9 @@ impl<F: Fetcher> SubscriptionBuilder<F> {
 			poll_interval: Duration::from_secs(3),
 			retry_policy: RetryPolicy::Inherit,
 			skip_empty: false,
+			skip_errors: false,
+			catch_up: false,
+			source: None,
 		}
 	}
 
@@ -40,6 +47,20 @@ impl<F: Fetcher> SubscriptionBuilder<F> {
 		self
 	}
 
+	/// Starts at `height` and streams finalized history as fast as possible before following the
+	/// finalized head.
+	///
+	/// Historical blocks are fetched in concurrent batches instead of one poll per block: the
+	/// hashes of a batch are looked up together, then the fetcher's value (header, block body,
+	/// events, ...) is fetched for every block of the batch at once. Once the cursor reaches the tip
+	/// the subscription polls like a regular one.
+	pub fn catch_up_from(mut self, height: u32) -> Self {
+		self.mode = BlockQueryMode::Finalized;
+		self.start_height = Some(height);
+		self.catch_up = true;
+		self
+	}
+
 	pub fn poll_interval(mut self, interval: Duration) -> Self {
 		self.poll_interval = interval;
 		self
@@ -55,17 +76,45 @@ impl<F: Fetcher> SubscriptionBuilder<F> {
 		self
 	}
 
+	/// When enabled, blocks whose data fails to decode are logged and skipped instead of ending
+	/// the subscription with an error. Transport and RPC errors are still returned.
+	pub fn skip_errors(mut self, value: bool) -> Self {
+		self.skip_errors = value;
+		self
+	}
+
+	/// Reads blocks from a shared source instead of polling independently.
+	///
+	/// The source's mode takes precedence over `mode`, and `from_height` is ignored.
+	pub fn source(mut self, source: BlockSource) -> Self {
+		self.source = Some(source);
+		self
+	}
+
 	pub async fn build(self) -> Result<Subscription<F>, Error> {
 		let sub = self.init_sub().await?;
-		Ok(Subscription { sub, fetcher: self.fetcher, skip_empty: self.skip_empty })
+		Ok(Subscription {
+			sub,
+			fetcher: self.fetcher,
+			skip_empty: self.skip_empty,
+			skip_errors: self.skip_errors,
+			pending: VecDeque::new(),
+		})
 	}
 
 	async fn init_sub(&self) -> Result<Sub, Error> {
+		if let Some(source) = &self.source {
+			let mut shared = source.subscribe().await?;
+			shared.retry_on_error = self.retry_policy;
+			return Ok(Sub::Shared(shared));
+		}
+
 		let config = SubConfig {
 			mode: self.mode,
 			start_height: self.start_height,
 			poll_interval: self.poll_interval,
 			retry_policy: self.retry_policy,
+			catch_up: self.catch_up,
 		};
 		Sub::init(self.client.clone(), config).await.map_err(Error::from)
 	}
@@ -15,7 +15,7 @@ use avail_rust_core::{
 	rpc::{AllowedEvents, AllowedExtrinsic, LegacyBlock, PhaseEvents, SignatureFilter},
 };
 use codec::Decode;
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 /// Transforms a block reference into a domain-specific value.
 ///
@@ -213,3 +213,59 @@ impl Fetcher for GrandpaJustificationFetcher {
 			.await
 	}
 }
+
+// ---------------------------------------------------------------------------
+// 9. Predicate-filtered extrinsics
+// ---------------------------------------------------------------------------
+
+/// Yields the untyped extrinsics of each block that satisfy a predicate.
+pub struct MatchingExtrinsicFetcher<P> {
+	pub(crate) predicate: Arc<P>,
+}
+
+impl<P> Clone for MatchingExtrinsicFetcher<P> {
+	fn clone(&self) -> Self {
+		Self { predicate: self.predicate.clone() }
+	}
+}
+
+#[async_trait]
+impl<P: Fn(&block::UntypedExtrinsic) -> bool + Send + Sync> Fetcher for MatchingExtrinsicFetcher<P> {
+	type Output = Vec<block::UntypedExtrinsic>;
+
+	async fn fetch(&self, client: &Client, info: BlockInfo, retry: RetryPolicy) -> Result<Self::Output, Error> {
+		let mut block = Block::new(client.clone(), info.hash).extrinsics();
+		block.set_retry_policy(retry);
+		let mut extrinsics = block.all(None, SignatureFilter::default()).await?;
+		extrinsics.retain(|x| (self.predicate)(x));
+		Ok(extrinsics)
+	}
+
+	fn is_empty(&self, value: &Self::Output) -> bool {
+		value.is_empty()
+	}
+}
+
+// ---------------------------------------------------------------------------
+// 10. Legacy block together with its events
+// ---------------------------------------------------------------------------
+
+/// Yields each [`LegacyBlock`] alongside its filtered events, fetched concurrently.
+#[derive(Debug, Clone)]
+pub struct LegacyBlockWithEventsFetcher {
+	pub(crate) allow_list: AllowedEvents,
+}
+
+#[async_trait]
+impl Fetcher for LegacyBlockWithEventsFetcher {
+	type Output = Option<(LegacyBlock, Vec<PhaseEvents>)>;
+
+	async fn fetch(&self, client: &Client, info: BlockInfo, retry: RetryPolicy) -> Result<Self::Output, Error> {
+		let chain = client.chain().retry_policy(retry, RetryPolicy::Inherit);
+		let query = EventsQuery::new(client.clone(), info.hash);
+		let block = async { chain.legacy_block(Some(info.hash)).await.map_err(Error::from) };
+		let (block, events) = futures::future::try_join(block, query.rpc(self.allow_list.clone(), true)).await?;
+
+		Ok(block.map(|block| (block, events)))
+	}
+}
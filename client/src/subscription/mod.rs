@@ -1,21 +1,26 @@
 pub mod builder;
 pub mod fetcher;
+pub mod source;
+pub mod storage;
 pub mod sub;
 
 pub use builder::SubscriptionBuilder;
 pub use fetcher::{
 	BlockEventsFetcher, BlockFetcher, BlockHeaderFetcher, BlockInfoFetcher, ExtrinsicFetcher, Fetcher,
-	GrandpaJustificationFetcher, LegacyBlockFetcher, UntypedExtrinsicFetcher,
+	GrandpaJustificationFetcher, LegacyBlockFetcher, LegacyBlockWithEventsFetcher, MatchingExtrinsicFetcher,
+	UntypedExtrinsicFetcher,
 };
+pub use source::{BlockSource, BlockSourceError};
+pub use storage::{StorageMapChange, StorageMapChanges};
 pub use sub::{BlockQueryMode, Subscription, SubscriptionItem};
 
-use crate::Client;
+use crate::{Client, block::UntypedExtrinsic};
 use avail_rust_core::{
 	HasHeader,
 	rpc::{AllowedEvents, AllowedExtrinsic, SignatureFilter},
 };
 use codec::Decode;
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 pub struct SubscribeApi(pub(crate) Client);
 
@@ -40,6 +45,16 @@ impl SubscribeApi {
 		SubscriptionBuilder::new(self.0.clone(), LegacyBlockFetcher)
 	}
 
+	/// Yields each block together with its events, saving indexers a second subscription.
+	///
+	/// Events are filtered by `allow_list` and fetched alongside the block rather than after it.
+	pub fn legacy_blocks_with_events(
+		&self,
+		allow_list: AllowedEvents,
+	) -> SubscriptionBuilder<LegacyBlockWithEventsFetcher> {
+		SubscriptionBuilder::new(self.0.clone(), LegacyBlockWithEventsFetcher { allow_list })
+	}
+
 	pub fn extrinsics<T: HasHeader + Decode + Clone + Sync>(
 		&self,
 		sig_filter: SignatureFilter,
@@ -55,6 +70,21 @@ impl SubscribeApi {
 		SubscriptionBuilder::new(self.0.clone(), UntypedExtrinsicFetcher { allow_list, sig_filter })
 	}
 
+	/// Yields, per block, the extrinsics for which `predicate` returns `true`.
+	///
+	/// Combine with [`SubscriptionBuilder::skip_empty`] to only receive blocks with at least one match.
+	pub fn matching_extrinsics<P>(&self, predicate: P) -> SubscriptionBuilder<MatchingExtrinsicFetcher<P>>
+	where
+		P: Fn(&UntypedExtrinsic) -> bool + Send + Sync,
+	{
+		SubscriptionBuilder::new(self.0.clone(), MatchingExtrinsicFetcher { predicate: Arc::new(predicate) })
+	}
+
+	/// Creates a shared block source that several subscriptions can read from.
+	pub fn source(&self, mode: BlockQueryMode) -> BlockSource {
+		BlockSource::new(self.0.clone(), mode, Duration::from_secs(3))
+	}
+
 	pub fn justification(&self) -> SubscriptionBuilder<GrandpaJustificationFetcher> {
 		SubscriptionBuilder::new(self.0.clone(), GrandpaJustificationFetcher)
 	}
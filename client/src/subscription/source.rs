@@ -0,0 +1,272 @@
+use super::sub::{BlockQueryMode, Sub, SubConfig};
+use crate::{
+	BlockInfo, Client, RetryPolicy, RpcError,
+	platform::{sleep, spawn},
+	subxt_rpcs,
+};
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicUsize, Ordering},
+	},
+	time::Duration,
+};
+use tokio::sync::{Mutex, broadcast};
+
+const BLOCK_SOURCE_CAPACITY: usize = 64;
+
+/// Error reported by a subscription fed from a [`BlockSource`].
+///
+/// It reaches callers wrapped in [`RpcError::Rpc`] as a client error, so it can be recovered with
+/// `downcast_ref::<BlockSourceError>()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockSourceError {
+	/// The subscriber fell behind and this many blocks were dropped before it could read them.
+	Lagged(u64),
+	/// The shared upstream cursor failed; it retries on its own and later blocks keep flowing.
+	Upstream(String),
+	/// The source stopped and will not yield more blocks.
+	Closed,
+}
+
+impl std::fmt::Display for BlockSourceError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Lagged(n) => write!(f, "Shared block source skipped {} blocks", n),
+			Self::Upstream(e) => write!(f, "Shared block source upstream failed: {}", e),
+			Self::Closed => f.write_str("Shared block source has been closed"),
+		}
+	}
+}
+
+impl std::error::Error for BlockSourceError {}
+
+impl From<BlockSourceError> for RpcError {
+	fn from(value: BlockSourceError) -> Self {
+		RpcError::Rpc(subxt_rpcs::Error::Client(Box::new(value)))
+	}
+}
+
+/// Shared block cursor that feeds many subscriptions from a single underlying poller.
+///
+/// The upstream cursor is started lazily by the first subscriber and stops once every
+/// subscriber has been dropped. Cloning a `BlockSource` shares the same upstream.
+#[derive(Clone)]
+pub struct BlockSource {
+	client: Client,
+	config: SubConfig,
+	shared: Arc<SharedState>,
+}
+
+struct SharedState {
+	sender: broadcast::Sender<Result<BlockInfo, String>>,
+	running: Mutex<bool>,
+	upstream_count: AtomicUsize,
+	upstream_errors: AtomicUsize,
+}
+
+impl BlockSource {
+	/// Creates a source following best or finalized blocks, polling at the given interval.
+	pub fn new(client: Client, mode: BlockQueryMode, poll_interval: Duration) -> Self {
+		let (sender, _) = broadcast::channel(BLOCK_SOURCE_CAPACITY);
+		let config = SubConfig { mode, poll_interval, ..Default::default() };
+		let shared = SharedState {
+			sender,
+			running: Mutex::new(false),
+			upstream_count: AtomicUsize::new(0),
+			upstream_errors: AtomicUsize::new(0),
+		};
+		Self { client, config, shared: Arc::new(shared) }
+	}
+
+	/// Returns the block query mode followed by this source.
+	pub fn mode(&self) -> BlockQueryMode {
+		self.config.mode
+	}
+
+	/// Returns how many underlying block cursors have been started by this source.
+	pub fn upstream_count(&self) -> usize {
+		self.shared.upstream_count.load(Ordering::Relaxed)
+	}
+
+	/// Returns how many times the underlying cursor failed and had to retry.
+	///
+	/// Each failure is also delivered to every subscriber as [`BlockSourceError::Upstream`].
+	pub fn upstream_error_count(&self) -> usize {
+		self.shared.upstream_errors.load(Ordering::Relaxed)
+	}
+
+	/// Returns the number of subscribers currently attached to this source.
+	pub fn subscriber_count(&self) -> usize {
+		self.shared.sender.receiver_count()
+	}
+
+	pub(crate) async fn subscribe(&self) -> Result<SharedBlockSub, RpcError> {
+		let mut running = self.shared.running.lock().await;
+		let receiver = self.shared.sender.subscribe();
+		if !*running {
+			let sub = Sub::init(self.client.clone(), self.config.clone()).await?;
+			self.shared.upstream_count.fetch_add(1, Ordering::Relaxed);
+			*running = true;
+
+			let shared = self.shared.clone();
+			let poll_rate = self.config.poll_interval;
+			_ = spawn(async move { Self::task(sub, shared, poll_rate).await });
+		}
+
+		Ok(SharedBlockSub {
+			client: self.client.clone(),
+			receiver,
+			retry_on_error: self.config.retry_policy,
+			last: None,
+			replay: None,
+		})
+	}
+
+	async fn task(mut sub: Sub, shared: Arc<SharedState>, poll_rate: Duration) {
+		loop {
+			let item = match sub.next().await {
+				Ok(x) => Ok(x),
+				Err(e) => {
+					let _count = shared.upstream_errors.fetch_add(1, Ordering::Relaxed) + 1;
+					#[cfg(feature = "tracing")]
+					crate::utils::trace_warn(&std::format!("Shared block source failed ({} so far): {}", _count, e));
+					Err(e.to_string())
+				},
+			};
+			let failed = item.is_err();
+
+			if shared.sender.send(item).is_ok() {
+				if failed {
+					sleep(poll_rate).await;
+				}
+				continue;
+			}
+
+			let mut running = shared.running.lock().await;
+			if shared.sender.receiver_count() == 0 {
+				*running = false;
+				return;
+			}
+		}
+	}
+}
+
+/// Subscriber side of a [`BlockSource`], used by [`Sub::Shared`].
+pub(crate) struct SharedBlockSub {
+	pub(crate) client: Client,
+	receiver: broadcast::Receiver<Result<BlockInfo, String>>,
+	pub(crate) retry_on_error: RetryPolicy,
+	last: Option<BlockInfo>,
+	replay: Option<BlockInfo>,
+}
+
+impl SharedBlockSub {
+	/// Waits for the next block published by the source.
+	///
+	/// # Errors
+	/// Returns [`BlockSourceError::Lagged`] when this subscriber fell behind and blocks were dropped,
+	/// [`BlockSourceError::Upstream`] when the shared cursor failed, and [`BlockSourceError::Closed`]
+	/// once the source stopped. Calling `next` again after the first two resumes with later blocks.
+	pub async fn next(&mut self) -> Result<BlockInfo, RpcError> {
+		if let Some(info) = self.replay.take() {
+			return Ok(info);
+		}
+
+		let info = match self.receiver.recv().await {
+			Ok(Ok(info)) => info,
+			Ok(Err(e)) => return Err(BlockSourceError::Upstream(e).into()),
+			Err(broadcast::error::RecvError::Lagged(n)) => return Err(BlockSourceError::Lagged(n).into()),
+			Err(broadcast::error::RecvError::Closed) => return Err(BlockSourceError::Closed.into()),
+		};
+		self.last = Some(info);
+		Ok(info)
+	}
+
+	/// Makes the next call to [`SharedBlockSub::next`] yield the last block again, if it is at `height`.
+	///
+	/// # Errors
+	/// Shared sources cannot seek, so any other height fails with [`RpcError::UnexpectedInput`].
+	pub fn rewind_to(&mut self, height: u32) -> Result<(), RpcError> {
+		match self.last {
+			Some(info) if info.height == height => {
+				self.replay = Some(info);
+				Ok(())
+			},
+			_ => Err(RpcError::UnexpectedInput(std::format!(
+				"Shared block sources cannot seek to block {}; only the last yielded block can be replayed",
+				height
+			))),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{error::Error, prelude::*};
+
+	#[test]
+	fn block_source_error_is_recoverable_from_rpc_error() {
+		let err = RpcError::from(BlockSourceError::Lagged(3));
+		let RpcError::Rpc(subxt_rpcs::Error::Client(inner)) = &err else {
+			panic!("Expected a client error");
+		};
+		assert_eq!(inner.downcast_ref::<BlockSourceError>(), Some(&BlockSourceError::Lagged(3)));
+		assert!(err.to_string().contains("skipped 3 blocks"));
+	}
+
+	#[tokio::test]
+	async fn block_source_shares_upstream() -> Result<(), Error> {
+		use crate::{
+			clients::mock_client::{MockClient, metadata_bytes},
+			subxt_rpcs::RpcClient,
+		};
+		use avail_rust_core::{AvailHeader, H256};
+		use serde_json::value::RawValue;
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+
+		let (tip_hash, tip) = (H256::repeat_byte(9), 9u32);
+		let hash = serde_json::to_string(&tip_hash).unwrap();
+		let info = std::format!(
+			r#"{{"best_hash":{hash},"best_height":{tip},"finalized_hash":{hash},"finalized_height":{tip},"genesis_hash":{hash}}}"#
+		);
+		// One cursor reads the chain info once to start, twice to yield the tip and twice more on
+		// the poll that finds no newer block.
+		for _ in 0..5 {
+			commander.add_ok("custom_chainInfo", RawValue::from_string(info.clone()).unwrap());
+		}
+		let header = serde_json::to_string(&AvailHeader { number: tip, ..Default::default() }).unwrap();
+		for _ in 0..3 {
+			commander.add_ok("chain_getHeader", RawValue::from_string(header.clone()).unwrap());
+		}
+
+		let source = BlockSource::new(client.clone(), BlockQueryMode::Finalized, Duration::from_secs(60));
+		let mut subs = Vec::new();
+		for _ in 0..3 {
+			subs.push(
+				client
+					.subscribe()
+					.block_headers()
+					.source(source.clone())
+					.build()
+					.await?,
+			);
+		}
+		assert_eq!(source.upstream_count(), 1);
+		assert_eq!(source.subscriber_count(), 3);
+
+		for sub in subs.iter_mut() {
+			let item = sub.next().await?;
+			assert_eq!((item.block_hash, item.block_height), (tip_hash, tip));
+		}
+		assert_eq!(source.upstream_count(), 1);
+		assert_eq!(commander.call_count("custom_chainInfo"), 5);
+		assert_eq!(commander.call_count("chain_getHeader"), 3);
+
+		Ok(())
+	}
+}
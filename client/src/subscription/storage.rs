@@ -0,0 +1,327 @@
+//! Change tracking for storage maps across finalized blocks.
+
+use super::sub::{BlockQueryMode, Sub, SubConfig};
+use crate::{Client, Error, H256, RpcError, chain::Chain};
+use avail_rust_core::{
+	rpc::{self, state::StorageChangeSet},
+	substrate::StorageMap,
+};
+use futures::{
+	StreamExt, TryStreamExt,
+	future::Either,
+	stream::{self, Stream},
+};
+use std::collections::BTreeMap;
+
+const KEYS_PAGE_SIZE: u32 = 1000;
+
+type RawEntries = BTreeMap<Vec<u8>, Vec<u8>>;
+type RawChanges = Vec<(Vec<u8>, Option<Vec<u8>>)>;
+
+/// A changed storage map entry; `None` means the entry was removed.
+pub type StorageMapChange<T> = (<T as StorageMap>::KEY, Option<<T as StorageMap>::VALUE>);
+
+/// Extension for observing how a [`StorageMap`] changes from one finalized block to the next.
+pub trait StorageMapChanges: StorageMap + Sized {
+	/// Streams the entries of this map that changed in each new block.
+	///
+	/// Every item holds the keys whose values were inserted or modified (`Some`) or removed (`None`)
+	/// in one block. Blocks without changes are skipped.
+	///
+	/// The changes come from a `state_subscribeStorage` subscription over all keys, filtered by the
+	/// map's prefix, and follow imported best blocks. Nodes only allow that subscription with unsafe
+	/// RPC methods enabled, and HTTP transports cannot subscribe at all; in both cases the stream
+	/// falls back to reading the map at every finalized block, in pages of keys and values through
+	/// the retrying [`Chain`] calls, and diffing it by key against the previous block. The first
+	/// snapshot is then taken at the current finalized block. The fallback is meant for small maps.
+	fn subscribe_changes(client: Client) -> impl Stream<Item = Result<Vec<StorageMapChange<Self>>, Error>> {
+		let prefix = Self::encode_partial_key();
+		stream::once(async move {
+			match rpc::state::subscribe_storage(&client.rpc_client, None).await {
+				Ok(sub) => Either::Left(subscribed_changes::<Self>(sub.map_err(RpcError::from), prefix)),
+				Err(_) => Either::Right(map_changes::<Self>(client)),
+			}
+		})
+		.flatten()
+	}
+}
+
+impl<T: StorageMap> StorageMapChanges for T {}
+
+/// Keeps the changes of each notified change set that fall under `prefix`, skipping sets without any.
+fn subscribed_changes<T: StorageMap>(
+	sub: impl Stream<Item = Result<StorageChangeSet, RpcError>>,
+	prefix: [u8; 32],
+) -> impl Stream<Item = Result<Vec<StorageMapChange<T>>, Error>> {
+	sub.map_err(Error::from).try_filter_map(move |set| async move {
+		let mut changes = RawChanges::new();
+		for (key, value) in set.changes {
+			let key = const_hex::decode(key.trim_start_matches("0x")).map_err(RpcError::from)?;
+			if !key.starts_with(&prefix) {
+				continue;
+			}
+			let value = match value {
+				Some(x) => Some(const_hex::decode(x.trim_start_matches("0x")).map_err(RpcError::from)?),
+				None => None,
+			};
+			changes.push((key, value));
+		}
+		if changes.is_empty() {
+			return Ok(None);
+		}
+
+		Ok(Some(decode_changes::<T>(changes)?))
+	})
+}
+
+struct ChangesState {
+	client: Client,
+	sub: Option<Sub>,
+	entries: RawEntries,
+}
+
+fn map_changes<T: StorageMap>(client: Client) -> impl Stream<Item = Result<Vec<StorageMapChange<T>>, Error>> {
+	let state = ChangesState { client, sub: None, entries: RawEntries::new() };
+	stream::try_unfold(state, |mut state| async move {
+		let ChangesState { client, sub, entries } = &mut state;
+		let sub = match sub {
+			Some(x) => x,
+			None => {
+				let config = SubConfig { mode: BlockQueryMode::Finalized, ..Default::default() };
+				let mut value = Sub::init(client.clone(), config).await?;
+				let info = value.next().await?;
+				*entries = fetch_entries::<T>(&client.chain(), info.hash).await?;
+				sub.insert(value)
+			},
+		};
+
+		loop {
+			let info = sub.next().await?;
+			let next = fetch_entries::<T>(&client.chain(), info.hash).await?;
+			let changes = diff_entries(entries, &next);
+			*entries = next;
+			if changes.is_empty() {
+				continue;
+			}
+
+			let decoded = decode_changes::<T>(changes)?;
+			return Ok(Some((decoded, state)));
+		}
+	})
+}
+
+/// Reads every entry of `T` at `at`, keyed by raw storage key.
+pub(crate) async fn fetch_entries<T: StorageMap>(chain: &Chain, at: H256) -> Result<RawEntries, Error> {
+	let prefix = T::hex_encode_partial_key();
+	let mut entries = RawEntries::new();
+	let mut start_key: Option<String> = None;
+	loop {
+		let page = chain
+			.state_get_keys_paged(Some(prefix.as_str()), KEYS_PAGE_SIZE, start_key.as_deref(), Some(at))
+			.await?;
+
+		let mut keys: Vec<Vec<u8>> = Vec::with_capacity(page.len());
+		for key in &page {
+			let key = const_hex::decode(key.trim_start_matches("0x")).map_err(RpcError::from)?;
+			keys.push(key);
+		}
+		if !keys.is_empty() {
+			let values = chain.fetch_many(&keys, Some(at)).await?;
+			entries.extend(
+				keys.into_iter()
+					.zip(values)
+					.filter_map(|(key, value)| Some((key, value?))),
+			);
+		}
+
+		if (page.len() as u32) < KEYS_PAGE_SIZE {
+			break;
+		}
+		start_key = page.last().cloned();
+	}
+
+	Ok(entries)
+}
+
+fn diff_entries(prev: &RawEntries, next: &RawEntries) -> RawChanges {
+	let mut changes = Vec::new();
+	for (key, value) in next {
+		if prev.get(key) != Some(value) {
+			changes.push((key.clone(), Some(value.clone())));
+		}
+	}
+	for key in prev.keys() {
+		if !next.contains_key(key) {
+			changes.push((key.clone(), None));
+		}
+	}
+
+	changes
+}
+
+fn decode_changes<T: StorageMap>(changes: RawChanges) -> Result<Vec<StorageMapChange<T>>, Error> {
+	let mut result = Vec::with_capacity(changes.len());
+	for (key, value) in changes {
+		let key = T::decode_storage_key(&mut key.as_slice()).map_err(|e| RpcError::DecodingFailed(e.to_string()))?;
+		let value = match value {
+			Some(v) => {
+				Some(T::decode_storage_value(&mut v.as_slice()).map_err(|e| RpcError::DecodingFailed(e.to_string()))?)
+			},
+			None => None,
+		};
+		result.push((key, value));
+	}
+
+	Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		clients::mock_client::{CommandManagerHelper, MockClient, metadata_bytes},
+		prelude::*,
+		subxt_rpcs::RpcClient,
+	};
+	use avail_rust_core::avail::data_availability::{storage::AppKeys, types::AppKey};
+	use codec::Encode;
+	use futures::StreamExt;
+	use serde_json::value::{RawValue, to_raw_value};
+
+	fn keys_page(keys: &[&str]) -> Box<RawValue> {
+		let keys: Vec<String> = keys
+			.iter()
+			.map(|x| AppKeys::hex_encode_storage_key(&x.as_bytes().to_vec()))
+			.collect();
+		to_raw_value(&keys).unwrap()
+	}
+
+	fn values(at: H256, entries: &[(&str, u32)]) -> Box<RawValue> {
+		let changes: Vec<(String, Option<String>)> = entries
+			.iter()
+			.map(|(key, id)| {
+				let value = AppKey { owner: AccountId::from([1u8; 32]), id: *id };
+				let key = AppKeys::hex_encode_storage_key(&key.as_bytes().to_vec());
+				(key, Some(std::format!("0x{}", const_hex::encode(value.encode()))))
+			})
+			.collect();
+		to_raw_value(&serde_json::json!([{ "block": at, "changes": changes }])).unwrap()
+	}
+
+	fn chain_info(hash: H256, height: u32) -> Box<RawValue> {
+		let hash = serde_json::to_string(&hash).unwrap();
+		let info = std::format!(
+			r#"{{"best_hash":{hash},"best_height":{height},"finalized_hash":{hash},"finalized_height":{height},"genesis_hash":{hash}}}"#
+		);
+		RawValue::from_string(info).unwrap()
+	}
+
+	async fn mocked_client() -> Result<(Client, CommandManagerHelper), Error> {
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+		Ok((client, commander))
+	}
+
+	#[tokio::test]
+	async fn storage_map_changes_diff_mocked_snapshots() -> Result<(), Error> {
+		let (client, mut commander) = mocked_client().await?;
+		let (first_at, second_at) = (H256::repeat_byte(1), H256::repeat_byte(2));
+
+		commander.add_ok("state_getKeysPaged", keys_page(&["kept", "changed", "removed"]));
+		commander.add_ok("state_queryStorageAt", values(first_at, &[("kept", 1), ("changed", 2), ("removed", 3)]));
+		commander.add_ok("state_getKeysPaged", keys_page(&["kept", "changed", "added"]));
+		commander.add_ok("state_queryStorageAt", values(second_at, &[("kept", 1), ("changed", 20), ("added", 4)]));
+
+		let chain = client.chain();
+		let first = fetch_entries::<AppKeys>(&chain, first_at).await?;
+		let second = fetch_entries::<AppKeys>(&chain, second_at).await?;
+		assert_eq!(commander.call_count("state_getKeysPaged"), 2);
+
+		let mut changes: Vec<(String, Option<u32>)> = decode_changes::<AppKeys>(diff_entries(&first, &second))?
+			.into_iter()
+			.map(|(key, value)| (String::from_utf8(key).unwrap(), value.map(|x| x.id)))
+			.collect();
+		changes.sort();
+		let expected = vec![
+			("added".to_string(), Some(4)),
+			("changed".to_string(), Some(20)),
+			("removed".to_string(), None),
+		];
+		assert_eq!(changes, expected);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn subscribe_changes_filters_storage_notifications() -> Result<(), Error> {
+		let (client, mut commander) = mocked_client().await?;
+
+		let value = AppKey { owner: AccountId::from([1u8; 32]), id: 7 };
+		let added = AppKeys::hex_encode_storage_key(&b"added".to_vec());
+		let removed = AppKeys::hex_encode_storage_key(&b"removed".to_vec());
+		let other = std::format!("0x{}", const_hex::encode([0u8; 40]));
+		let sets = [
+			serde_json::json!({ "block": H256::repeat_byte(1), "changes": [[other, "0x00"]] }),
+			serde_json::json!({
+				"block": H256::repeat_byte(2),
+				"changes": [[added, std::format!("0x{}", const_hex::encode(value.encode()))], [removed, null]],
+			}),
+		];
+		commander.add_subscription("state_subscribeStorage", sets.iter().map(|x| to_raw_value(x).unwrap()).collect());
+
+		let items: Vec<_> = AppKeys::subscribe_changes(client).collect().await;
+		let items = items.into_iter().collect::<Result<Vec<_>, _>>()?;
+		assert_eq!(items.len(), 1);
+		let changes: Vec<(String, Option<u32>)> = items[0]
+			.iter()
+			.map(|(key, value)| (String::from_utf8(key.clone()).unwrap(), value.as_ref().map(|x| x.id)))
+			.collect();
+		assert_eq!(changes, vec![("added".to_string(), Some(7)), ("removed".to_string(), None)]);
+		assert_eq!(commander.call_count("state_getKeysPaged"), 0);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn subscribe_changes_polls_without_subscriptions() -> Result<(), Error> {
+		let (client, mut commander) = mocked_client().await?;
+		let (first_at, second_at) = (H256::repeat_byte(1), H256::repeat_byte(2));
+
+		// Starting the cursor and yielding the first finalized block, then yielding the next one.
+		for _ in 0..3 {
+			commander.add_ok("custom_chainInfo", chain_info(first_at, 10));
+		}
+		for _ in 0..2 {
+			commander.add_ok("custom_chainInfo", chain_info(second_at, 11));
+		}
+		commander.add_ok("state_getKeysPaged", keys_page(&["kept", "removed"]));
+		commander.add_ok("state_queryStorageAt", values(first_at, &[("kept", 1), ("removed", 2)]));
+		commander.add_ok("state_getKeysPaged", keys_page(&["kept", "added"]));
+		commander.add_ok("state_queryStorageAt", values(second_at, &[("kept", 1), ("added", 3)]));
+
+		let mut stream = std::pin::pin!(AppKeys::subscribe_changes(client));
+		let changes: Vec<(String, Option<u32>)> = stream
+			.next()
+			.await
+			.expect("Stream should yield")?
+			.into_iter()
+			.map(|(key, value)| (String::from_utf8(key).unwrap(), value.map(|x| x.id)))
+			.collect();
+		assert_eq!(changes, vec![("added".to_string(), Some(3)), ("removed".to_string(), None)]);
+		assert_eq!(commander.call_count("state_subscribeStorage"), 1);
+		assert_eq!(commander.call_count("state_getKeysPaged"), 2);
+
+		Ok(())
+	}
+
+	#[test]
+	fn diff_entries_reports_inserts_updates_and_removals() {
+		let prev: RawEntries = [(vec![1], vec![10]), (vec![2], vec![20]), (vec![3], vec![30])].into();
+		let next: RawEntries = [(vec![1], vec![10]), (vec![2], vec![21]), (vec![4], vec![40])].into();
+
+		let changes = diff_entries(&prev, &next);
+		assert_eq!(changes, vec![(vec![2], Some(vec![21])), (vec![4], Some(vec![40])), (vec![3], None)]);
+		assert!(diff_entries(&next, &next).is_empty());
+	}
+}
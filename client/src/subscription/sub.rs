@@ -1,7 +1,10 @@
-use super::fetcher::Fetcher;
-use crate::{BlockInfo, Client, Error, H256, RetryPolicy, RpcError, platform::sleep};
+use super::{fetcher::Fetcher, source::SharedBlockSub};
+use crate::{BlockInfo, Client, Error, H256, RetryPolicy, RpcError, error::ErrorCode, platform::sleep};
 use futures::stream::{self, Stream};
-use std::time::Duration;
+use std::{collections::VecDeque, time::Duration};
+
+/// Number of historical blocks fetched concurrently while catching up.
+const CATCH_UP_BATCH_SIZE: u32 = 16;
 
 /// Selects whether subscriptions follow best blocks or finalized blocks.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -19,6 +22,8 @@ pub(crate) struct SubConfig {
 	pub start_height: Option<u32>,
 	pub poll_interval: Duration,
 	pub retry_policy: RetryPolicy,
+	/// Fetch historical finalized blocks in concurrent batches until the tip is reached.
+	pub catch_up: bool,
 }
 
 impl Default for SubConfig {
@@ -28,6 +33,7 @@ impl Default for SubConfig {
 			start_height: None,
 			poll_interval: Duration::from_secs(3),
 			retry_policy: RetryPolicy::Inherit,
+			catch_up: false,
 		}
 	}
 }
@@ -35,6 +41,7 @@ impl Default for SubConfig {
 pub(crate) enum Sub {
 	BestBlock(BestBlockSub),
 	FinalizedBlock(FinalizedBlockSub),
+	Shared(SharedBlockSub),
 }
 
 impl Sub {
@@ -61,6 +68,8 @@ impl Sub {
 				next_block_height: height,
 				retry_on_error: config.retry_policy,
 				processed_previous_block: false,
+				catch_up: config.catch_up,
+				prefetched: VecDeque::new(),
 			}),
 		};
 
@@ -71,6 +80,15 @@ impl Sub {
 		match self {
 			Self::BestBlock(s) => s.next().await,
 			Self::FinalizedBlock(s) => s.next().await,
+			Self::Shared(s) => s.next().await,
+		}
+	}
+
+	/// Returns the next blocks to yield: a whole prefetched batch while catching up, otherwise one block.
+	pub(crate) async fn next_batch(&mut self) -> Result<Vec<BlockInfo>, RpcError> {
+		match self {
+			Self::FinalizedBlock(s) => s.next_batch().await,
+			_ => Ok(vec![self.next().await?]),
 		}
 	}
 
@@ -78,10 +96,14 @@ impl Sub {
 		match self {
 			Self::BestBlock(s) => s.prev().await,
 			Self::FinalizedBlock(s) => s.prev().await,
+			Self::Shared(_) => Err(RpcError::UnexpectedInput("Shared block sources cannot move backwards".into())),
 		}
 	}
 
-	pub(crate) fn set_block_height(&mut self, value: u32) {
+	/// Moves the cursor so that the next block yielded is at `value`.
+	///
+	/// Shared cursors can only replay the block they last yielded and fail for any other height.
+	pub(crate) fn set_block_height(&mut self, value: u32) -> Result<(), RpcError> {
 		match self {
 			Self::BestBlock(x) => {
 				x.current_block_height = value;
@@ -90,14 +112,18 @@ impl Sub {
 			Self::FinalizedBlock(x) => {
 				x.next_block_height = value;
 				x.processed_previous_block = false;
+				x.prefetched.clear();
 			},
+			Self::Shared(x) => x.rewind_to(value)?,
 		}
+		Ok(())
 	}
 
 	pub(crate) fn client_ref(&self) -> &Client {
 		match self {
 			Sub::BestBlock(x) => &x.client,
 			Sub::FinalizedBlock(x) => &x.client,
+			Sub::Shared(x) => &x.client,
 		}
 	}
 
@@ -105,6 +131,7 @@ impl Sub {
 		let policy = match self {
 			Self::BestBlock(s) => s.retry_on_error,
 			Self::FinalizedBlock(s) => s.retry_on_error,
+			Self::Shared(s) => s.retry_on_error,
 		};
 		if should_retry(self.client_ref(), policy) {
 			RetryPolicy::Enabled
@@ -128,6 +155,9 @@ pub(crate) struct FinalizedBlockSub {
 	pub(crate) next_block_height: u32,
 	retry_on_error: RetryPolicy,
 	processed_previous_block: bool,
+	catch_up: bool,
+	/// Historical blocks fetched ahead of time while catching up.
+	prefetched: VecDeque<BlockInfo>,
 }
 
 impl FinalizedBlockSub {
@@ -144,6 +174,15 @@ impl FinalizedBlockSub {
 	}
 
 	pub async fn next(&mut self) -> Result<BlockInfo, RpcError> {
+		if self.catch_up && self.prefetched.is_empty() {
+			self.prefetch_historical().await?;
+		}
+		if let Some(info) = self.prefetched.pop_front() {
+			self.next_block_height = info.height + 1;
+			self.processed_previous_block = true;
+			return Ok(info);
+		}
+
 		let latest_finalized_height = self.fetch_latest_finalized_height().await?;
 
 		let (hash, height) = if latest_finalized_height > self.next_block_height {
@@ -157,12 +196,27 @@ impl FinalizedBlockSub {
 		Ok(BlockInfo { hash, height })
 	}
 
+	/// Drains the blocks prefetched while catching up, or polls a single block once the tip is reached.
+	pub(crate) async fn next_batch(&mut self) -> Result<Vec<BlockInfo>, RpcError> {
+		if self.catch_up && self.prefetched.is_empty() {
+			self.prefetch_historical().await?;
+		}
+		if let Some(last) = self.prefetched.back() {
+			self.next_block_height = last.height + 1;
+			self.processed_previous_block = true;
+			return Ok(self.prefetched.drain(..).collect());
+		}
+
+		Ok(vec![self.next().await?])
+	}
+
 	pub async fn prev(&mut self) -> Result<BlockInfo, RpcError> {
 		self.next_block_height = self.next_block_height.saturating_sub(1);
 		if self.processed_previous_block {
 			self.next_block_height = self.next_block_height.saturating_sub(1);
 		}
 		self.processed_previous_block = false;
+		self.prefetched.clear();
 
 		self.next().await
 	}
@@ -183,6 +237,32 @@ impl FinalizedBlockSub {
 		Ok((hash, height))
 	}
 
+	/// Fetches the hashes of up to [`CATCH_UP_BATCH_SIZE`] blocks below the finalized tip at once.
+	///
+	/// Leaves the buffer empty once the cursor has reached the tip, so polling takes over.
+	async fn prefetch_historical(&mut self) -> Result<(), RpcError> {
+		let latest_finalized_height = self.fetch_latest_finalized_height().await?;
+		if latest_finalized_height <= self.next_block_height {
+			return Ok(());
+		}
+
+		let end = latest_finalized_height.min(self.next_block_height.saturating_add(CATCH_UP_BATCH_SIZE));
+		let futures = (self.next_block_height..end).map(|height| {
+			let chain = self.chain(RetryPolicy::Inherit);
+			let client = self.client.clone();
+			async move {
+				let _permit = client.concurrency_permit().await;
+				let hash = chain.block_hash(Some(height)).await?;
+				let hash = hash.ok_or(RpcError::ExpectedData("Expected to fetch block hash".into()))?;
+				Ok::<_, RpcError>(BlockInfo { hash, height })
+			}
+		});
+
+		let blocks = futures::future::try_join_all(futures).await?;
+		self.prefetched.extend(blocks);
+		Ok(())
+	}
+
 	async fn run_head(&mut self) -> Result<(H256, u32), RpcError> {
 		loop {
 			let head = self.chain(RetryPolicy::Inherit).info().await?;
@@ -320,13 +400,22 @@ pub struct Subscription<F: Fetcher> {
 	pub(super) sub: Sub,
 	pub(super) fetcher: F,
 	pub(super) skip_empty: bool,
+	pub(super) skip_errors: bool,
+	/// Values fetched ahead of time for a catch-up batch, in block order.
+	pub(super) pending: VecDeque<(BlockInfo, Result<F::Output, Error>)>,
 }
 
 impl<F: Fetcher> Subscription<F> {
 	pub async fn next(&mut self) -> Result<SubscriptionItem<F::Output>, Error> {
 		loop {
-			let info = self.sub.next().await?;
-			match self.fetch_at(info).await {
+			if self.pending.is_empty() {
+				let infos = self.sub.next_batch().await?;
+				self.fetch_batch(infos).await;
+			}
+			let Some((info, value)) = self.pending.pop_front() else {
+				continue;
+			};
+			match self.finish(info, value) {
 				Ok(Some(item)) => return Ok(item),
 				Ok(None) => continue,
 				Err(e) => return Err(e),
@@ -335,6 +424,12 @@ impl<F: Fetcher> Subscription<F> {
 	}
 
 	pub async fn prev(&mut self) -> Result<SubscriptionItem<F::Output>, Error> {
+		// Blocks fetched ahead were never yielded, so step back from the last one that was.
+		if let Some((info, _)) = self.pending.front() {
+			self.sub.set_block_height(info.height.saturating_sub(1))?;
+			self.pending.clear();
+		}
+
 		loop {
 			let info = self.sub.prev().await?;
 			match self.fetch_at(info).await {
@@ -356,15 +451,44 @@ impl<F: Fetcher> Subscription<F> {
 		let client = self.sub.client_ref().clone();
 		let retry = self.sub.resolved_retry_policy();
 
-		match self.fetcher.fetch(&client, info, retry).await {
+		let value = self.fetcher.fetch(&client, info, retry).await;
+		self.finish(info, value)
+	}
+
+	/// Fetches the values of `infos` concurrently and queues them in block order.
+	async fn fetch_batch(&mut self, infos: Vec<BlockInfo>) {
+		let client = self.sub.client_ref().clone();
+		let retry = self.sub.resolved_retry_policy();
+
+		let fetcher = &self.fetcher;
+		let fetches = infos
+			.iter()
+			.map(|info| client.limited(fetcher.fetch(&client, *info, retry)));
+		let values = futures::future::join_all(fetches).await;
+		self.pending.extend(infos.into_iter().zip(values));
+	}
+
+	fn finish(
+		&mut self,
+		info: BlockInfo,
+		value: Result<F::Output, Error>,
+	) -> Result<Option<SubscriptionItem<F::Output>>, Error> {
+		match value {
 			Ok(value) => {
 				if self.skip_empty && self.fetcher.is_empty(&value) {
 					return Ok(None);
 				}
 				Ok(Some(SubscriptionItem { value, block_height: info.height, block_hash: info.hash }))
 			},
+			Err(e) if self.skip_errors && e.code() == ErrorCode::Decode => {
+				#[cfg(feature = "tracing")]
+				crate::utils::trace_warn(&std::format!("Skipping block {} ({:?}): {}", info.height, info.hash, e));
+				Ok(None)
+			},
 			Err(e) => {
-				self.sub.set_block_height(info.height);
+				// Every cursor, shared ones included, can replay the block it just yielded.
+				_ = self.sub.set_block_height(info.height);
+				self.pending.clear();
 				Err(e)
 			},
 		}
@@ -374,7 +498,87 @@ impl<F: Fetcher> Subscription<F> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::{error::Error, prelude::*};
+	use crate::{clients::mock_client::MockClient, error::Error, prelude::*, subxt_rpcs::RpcClient};
+	use avail_rust_core::rpc;
+
+	#[tokio::test]
+	async fn subscription_catch_up_fetches_batches_concurrently() -> Result<(), Error> {
+		use crate::clients::mock_client::metadata_bytes;
+		use serde_json::value::RawValue;
+
+		let json = |value: String| RawValue::from_string(value).unwrap();
+		let block_hash = |height: u32| H256::repeat_byte(height as u8 + 1);
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+
+		let tip = 4u32;
+		let tip_hash = serde_json::to_string(&block_hash(tip)).unwrap();
+		let info = std::format!(
+			r#"{{"best_hash":{tip_hash},"best_height":{tip},"finalized_hash":{tip_hash},"finalized_height":{tip},"genesis_hash":{tip_hash}}}"#
+		);
+		commander.add_ok("custom_chainInfo", json(info));
+		for height in 0..tip {
+			commander.add_ok("chain_getBlockHash", json(serde_json::to_string(&block_hash(height)).unwrap()));
+			let header = AvailHeader { number: height, ..Default::default() };
+			commander.add_ok("chain_getHeader", json(serde_json::to_string(&header).unwrap()));
+		}
+		commander.set_delay(Some(Duration::from_millis(20)));
+
+		let mut sub = client.subscribe().block_headers().catch_up_from(0).build().await?;
+		let first = sub.next().await?;
+		assert_eq!(first.block_height, 0);
+		// The headers of the whole batch were requested together, before the first one was yielded.
+		assert_eq!(commander.call_count("chain_getHeader"), tip as usize);
+		assert_eq!(commander.max_in_flight(), tip as usize);
+
+		for height in 1..tip {
+			let item = sub.next().await?;
+			assert_eq!((item.block_height, item.block_hash), (height, block_hash(height)));
+			assert_eq!(item.value.map(|x| x.number), Some(height));
+		}
+		assert_eq!(commander.call_count("custom_chainInfo"), 1);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn subscription_catch_up_respects_max_concurrency() -> Result<(), Error> {
+		use crate::clients::mock_client::metadata_bytes;
+		use serde_json::value::RawValue;
+
+		let json = |value: String| RawValue::from_string(value).unwrap();
+		let block_hash = |height: u32| H256::repeat_byte(height as u8 + 1);
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+		client.set_max_concurrency(2);
+
+		let tip = 6u32;
+		let tip_hash = serde_json::to_string(&block_hash(tip)).unwrap();
+		let info = std::format!(
+			r#"{{"best_hash":{tip_hash},"best_height":{tip},"finalized_hash":{tip_hash},"finalized_height":{tip},"genesis_hash":{tip_hash}}}"#
+		);
+		commander.add_ok("custom_chainInfo", json(info));
+		for height in 0..tip {
+			commander.add_ok("chain_getBlockHash", json(serde_json::to_string(&block_hash(height)).unwrap()));
+			let header = AvailHeader { number: height, ..Default::default() };
+			commander.add_ok("chain_getHeader", json(serde_json::to_string(&header).unwrap()));
+		}
+		commander.set_delay(Some(Duration::from_millis(20)));
+
+		let mut sub = client.subscribe().block_headers().catch_up_from(0).build().await?;
+		for height in 0..tip {
+			assert_eq!(sub.next().await?.block_height, height);
+		}
+		// Both the hash prefetch and the header fetches of the batch stay under the cap.
+		assert_eq!(commander.call_count("chain_getHeader"), tip as usize);
+		assert_eq!(commander.max_in_flight(), 2);
+
+		Ok(())
+	}
 
 	#[tokio::test]
 	async fn sub_init_finalized() -> Result<(), Error> {
@@ -429,4 +633,64 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[tokio::test]
+	async fn subscription_skips_undecodable_block() -> Result<(), Error> {
+		use crate::clients::mock_client::metadata_bytes;
+		use serde_json::value::RawValue;
+
+		let json = |value: String| RawValue::from_string(value).unwrap();
+		let block_hash = |height: u32| H256::repeat_byte(height as u8 + 1);
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+
+		let (height, tip) = (5u32, 10u32);
+		let tip_hash = serde_json::to_string(&block_hash(tip)).unwrap();
+		let info = std::format!(
+			r#"{{"best_hash":{tip_hash},"best_height":{tip},"finalized_hash":{tip_hash},"finalized_height":{tip},"genesis_hash":{tip_hash}}}"#
+		);
+		let mut queue_block = |height: u32| {
+			commander.add_ok("custom_chainInfo", json(info.clone()));
+			commander.add_ok("chain_getBlockHash", json(serde_json::to_string(&block_hash(height)).unwrap()));
+		};
+		queue_block(height);
+		queue_block(height);
+		queue_block(height + 1);
+
+		let corrupt = rpc::Extrinsic {
+			data: "0xdeadbeef".into(),
+			ext_hash: H256::repeat_byte(0xAA),
+			ext_index: 0,
+			pallet_id: 0,
+			variant_id: 0,
+			account_id: None,
+			nonce: None,
+		};
+
+		commander.extrinsics_ok(vec![corrupt.clone()]);
+		let mut sub = client
+			.subscribe()
+			.untyped_extrinsics(None, Default::default())
+			.from_height(height)
+			.build()
+			.await?;
+		assert!(matches!(sub.next().await, Err(Error::Decode(_))));
+
+		commander.extrinsics_ok(vec![corrupt]);
+		commander.extrinsics_ok(Vec::new());
+		let mut sub = client
+			.subscribe()
+			.untyped_extrinsics(None, Default::default())
+			.from_height(height)
+			.skip_errors(true)
+			.build()
+			.await?;
+		let item = sub.next().await?;
+		assert_eq!((item.block_height, item.block_hash), (height + 1, block_hash(height + 1)));
+		assert_eq!(commander.call_count("custom_extrinsics"), 3);
+
+		Ok(())
+	}
 }
@@ -1,15 +1,18 @@
 //! Builders for transactions targeting specific Avail pallets.
 
-use crate::{Client, SubmittableTransaction};
+use crate::{Client, RpcError, SubmittableTransaction, error_ops::ErrorOperation};
 use avail_rust_core::{
 	AccountId, AccountIdLike, ExtrinsicCall, H256, MultiAddress,
 	avail::{
 		self,
+		identity::types::{IdentityInfo, MAX_RAW_DATA_LEN},
 		multisig::types::Timepoint,
 		nomination_pools::types::{BondExtraValue, ClaimPermission, ConfigOpAccount, PoolState},
 		proxy::types::ProxyType,
 		staking::types::{RewardDestination, ValidatorPrefs},
+		system::types::DispatchFeeModifier,
 	},
+	substrate::{StorageMap, StorageValue},
 	types::{
 		HashString,
 		metadata::{MultiAddressLike, StringOrBytes},
@@ -129,6 +132,178 @@ impl TransactionApi {
 	pub fn session(&self) -> Session {
 		Session(self.0.clone())
 	}
+
+	/// Returns helpers for privileged calls via the sudo pallet.
+	///
+	/// Returns a [`Sudo`] builder that clones this client.
+	pub fn sudo(&self) -> Sudo {
+		Sudo(self.0.clone())
+	}
+
+	/// Returns helpers for scheduling calls via the scheduler pallet.
+	///
+	/// Returns a [`Scheduler`] builder that clones this client.
+	pub fn scheduler(&self) -> Scheduler {
+		Scheduler(self.0.clone())
+	}
+
+	/// Returns helpers for managing on-chain identities.
+	///
+	/// Returns an [`Identity`] builder that clones this client.
+	pub fn identity(&self) -> Identity {
+		Identity(self.0.clone())
+	}
+}
+
+/// Builds extrinsics for the `sudo` pallet.
+///
+/// Only available on chains that include the sudo pallet (typically dev and test networks); every
+/// method fails with a validation error when the pallet is missing from the runtime metadata.
+pub struct Sudo(Client);
+impl Sudo {
+	/// Dispatches `call` with root origin.
+	///
+	pub fn sudo(&self, call: impl Into<ExtrinsicCall>) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let value = avail::sudo::tx::Sudo { call: call.into() };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Dispatches `call` with root origin, charging the provided `weight` instead of the call's own.
+	///
+	pub fn sudo_unchecked_weight(
+		&self,
+		call: impl Into<ExtrinsicCall>,
+		weight: Weight,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let value = avail::sudo::tx::SudoUncheckedWeight { call: call.into(), weight };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Dispatches `call` with a signed origin of `who`.
+	///
+	pub fn sudo_as(
+		&self,
+		who: impl Into<MultiAddressLike>,
+		call: impl Into<ExtrinsicCall>,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let who = parse_multi_address(who)?;
+		let value = avail::sudo::tx::SudoAs { who, call: call.into() };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	fn ensure_pallet(&self) -> Result<(), crate::Error> {
+		let metadata = self.0.online_client().metadata();
+		if metadata.pallet_by_index(avail::sudo::PALLET_ID).is_none() {
+			return Err(invalid_input("Sudo pallet is not present in runtime metadata"));
+		}
+
+		Ok(())
+	}
+}
+
+/// Builds extrinsics for the `scheduler` pallet.
+///
+/// Scheduling requires root origin on Avail, so the resulting calls are usually wrapped with
+/// [`Sudo::sudo`]. Every method fails with a validation error when the pallet is missing from the
+/// runtime metadata.
+pub struct Scheduler(Client);
+impl Scheduler {
+	/// Schedules `call` to be dispatched at block `when`, optionally repeating every `period.0` blocks
+	/// `period.1` times. Lower `priority` values run first.
+	pub fn schedule(
+		&self,
+		when: u32,
+		maybe_periodic: Option<(u32, u32)>,
+		priority: u8,
+		call: impl Into<ExtrinsicCall>,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let value = avail::scheduler::tx::Schedule { when, maybe_periodic, priority, call: call.into() };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Same as [`Scheduler::schedule`] but `after` is relative to the block the call is included in.
+	pub fn schedule_after(
+		&self,
+		after: u32,
+		maybe_periodic: Option<(u32, u32)>,
+		priority: u8,
+		call: impl Into<ExtrinsicCall>,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let value = avail::scheduler::tx::ScheduleAfter { after, maybe_periodic, priority, call: call.into() };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Schedules `call` under the name `id` so it can later be cancelled with [`Scheduler::cancel_named`].
+	pub fn schedule_named(
+		&self,
+		id: [u8; 32],
+		when: u32,
+		maybe_periodic: Option<(u32, u32)>,
+		priority: u8,
+		call: impl Into<ExtrinsicCall>,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let value = avail::scheduler::tx::ScheduleNamed { id, when, maybe_periodic, priority, call: call.into() };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Cancels the task at agenda position `index` of block `when`.
+	pub fn cancel(&self, when: u32, index: u32) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let value = avail::scheduler::tx::Cancel { when, index };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Cancels the task scheduled under the name `id`.
+	pub fn cancel_named(&self, id: [u8; 32]) -> Result<SubmittableTransaction, crate::Error> {
+		self.ensure_pallet()?;
+		let value = avail::scheduler::tx::CancelNamed { id };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	fn ensure_pallet(&self) -> Result<(), crate::Error> {
+		let metadata = self.0.online_client().metadata();
+		if metadata.pallet_by_index(avail::scheduler::PALLET_ID).is_none() {
+			return Err(invalid_input("Scheduler pallet is not present in runtime metadata"));
+		}
+
+		Ok(())
+	}
+}
+
+/// Builds extrinsics for the `identity` pallet.
+///
+/// The helper clones the underlying client; composing calls does not contact the node until the
+/// resulting [`SubmittableTransaction`] is signed or submitted.
+pub struct Identity(Client);
+impl Identity {
+	/// Sets the signer's on-chain identity, replacing any previous one and clearing its judgements.
+	///
+	/// Fails with a validation error when a raw field is longer than 32 bytes.
+	pub fn set_identity(&self, info: IdentityInfo) -> Result<SubmittableTransaction, crate::Error> {
+		let too_long = info
+			.fields()
+			.filter_map(|x| x.as_raw())
+			.any(|x| x.len() > MAX_RAW_DATA_LEN);
+		if too_long {
+			return Err(invalid_input("Identity fields cannot be longer than 32 bytes"));
+		}
+
+		let value = avail::identity::tx::SetIdentity { info };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Clears the signer's identity and returns the deposit.
+	///
+	pub fn clear_identity(&self) -> SubmittableTransaction {
+		let value = avail::identity::tx::ClearIdentity {};
+		SubmittableTransaction::from_encodable(self.0.clone(), value)
+	}
 }
 
 /// Builds extrinsics for the `session` pallet.
@@ -623,6 +798,36 @@ impl Balances {
 		let value = avail::balances::tx::TransferAll { dest, keep_alive };
 		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
 	}
+
+	/// Moves `amount` from `source` to `dest` regardless of who signs.
+	///
+	/// Requires root origin, so wrap the result in [`Sudo::sudo`] to submit it.
+	pub fn force_transfer(
+		&self,
+		source: impl Into<MultiAddressLike>,
+		dest: impl Into<MultiAddressLike>,
+		amount: u128,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		let source = parse_multi_address(source)?;
+		let dest = parse_multi_address(dest)?;
+
+		let value = avail::balances::tx::ForceTransfer { source, dest, value: amount };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
+
+	/// Sets the free balance of `who` to `new_free`.
+	///
+	/// Requires root origin, so wrap the result in [`Sudo::sudo`] to submit it.
+	pub fn force_set_balance(
+		&self,
+		who: impl Into<MultiAddressLike>,
+		new_free: u128,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		let who = parse_multi_address(who)?;
+
+		let value = avail::balances::tx::ForceSetBalance { who, new_free };
+		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
+	}
 }
 
 /// Builds extrinsics for the `multisig` pallet.
@@ -802,12 +1007,80 @@ impl DataAvailability {
 
 	/// Submits application data for availability guarantees.
 	///
-	pub fn submit_data<'a>(&self, app_id: u32, data: impl Into<StringOrBytes<'a>>) -> SubmittableTransaction {
+	/// Rejects data longer than the chain's `MaxAppDataLength` with
+	/// [`UserError::DataTooLarge`](crate::UserError::DataTooLarge) instead of letting the node refuse the extrinsic.
+	pub fn submit_data<'a>(
+		&self,
+		app_id: u32,
+		data: impl Into<StringOrBytes<'a>>,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		let data: Vec<u8> = Into::<StringOrBytes>::into(data).into();
+		let max = self.0.max_app_data_length()?;
+		if data.len() > max as usize {
+			return Err(crate::Error::User(crate::UserError::DataTooLarge { len: data.len(), max }));
+		}
+
+		Ok(self.submit_data_unchecked(app_id, data))
+	}
+
+	/// Same as [`DataAvailability::submit_data`] but skips the `MaxAppDataLength` check.
+	///
+	pub fn submit_data_unchecked<'a>(&self, app_id: u32, data: impl Into<StringOrBytes<'a>>) -> SubmittableTransaction {
 		let data: Vec<u8> = Into::<StringOrBytes>::into(data).into();
 		let value = avail::data_availability::tx::SubmitData { app_id, data };
 		SubmittableTransaction::from_encodable(self.0.clone(), value)
 	}
 
+	/// Submits application data under the app id registered for `key`.
+	///
+	/// The id is read from `DataAvailability::AppKeys` at the best block; a missing key is reported as
+	/// a not-found error. Data is checked against the length limit like
+	/// [`DataAvailability::submit_data`].
+	pub async fn submit_data_by_key<'a>(
+		&self,
+		key: &str,
+		data: impl Into<StringOrBytes<'a>>,
+	) -> Result<SubmittableTransaction, crate::Error> {
+		let app_key =
+			avail::data_availability::storage::AppKeys::fetch(&self.0.rpc_client, &key.as_bytes().to_vec(), None)
+				.await?;
+		let Some(app_key) = app_key else {
+			return Err(crate::Error::not_found_with_op(
+				ErrorOperation::TxSubmitDataByKey,
+				std::format!("No application key named {:?}", key),
+			));
+		};
+
+		self.submit_data(app_key.id, data)
+	}
+
+	/// Proposes a new block matrix size. Requires root origin, so wrap it in a sudo or governance call.
+	///
+	pub fn submit_block_length_proposal(&self, rows: u32, cols: u32) -> SubmittableTransaction {
+		let value = avail::data_availability::tx::SubmitBlockLengthProposal { rows, cols };
+		SubmittableTransaction::from_encodable(self.0.clone(), value)
+	}
+
+	/// Renames an existing application key. Requires root origin.
+	///
+	pub fn set_application_key<'a>(
+		&self,
+		old_key: impl Into<StringOrBytes<'a>>,
+		new_key: impl Into<StringOrBytes<'a>>,
+	) -> SubmittableTransaction {
+		let old_key: Vec<u8> = Into::<StringOrBytes>::into(old_key).into();
+		let new_key: Vec<u8> = Into::<StringOrBytes>::into(new_key).into();
+		let value = avail::data_availability::tx::SetApplicationKey { old_key, new_key };
+		SubmittableTransaction::from_encodable(self.0.clone(), value)
+	}
+
+	/// Updates the fee modifier applied to `submit_data`. Requires root origin.
+	///
+	pub fn set_submit_data_fee_modifier(&self, modifier: DispatchFeeModifier) -> SubmittableTransaction {
+		let value = avail::data_availability::tx::SetSubmitDataFeeModifier { modifier };
+		SubmittableTransaction::from_encodable(self.0.clone(), value)
+	}
+
 	/// Submits metadata describing an out-of-band blob.
 	///
 	pub fn submit_blob_metadata(
@@ -1007,6 +1280,45 @@ impl Vector {
 		Ok(SubmittableTransaction::from_encodable(self.0.clone(), value))
 	}
 
+	/// Estimates the fee of sending an arbitrary vector message of `message_len` bytes to `domain`.
+	///
+	/// Adds the runtime's fee estimate for the equivalent `send_message` call, which covers its weight
+	/// and length, to the per-message fee kept in `Vector::MessageFee` storage at `at` (or the best
+	/// block when `at` is `None`). Runtimes whose metadata does not declare that storage item charge no
+	/// per-message fee.
+	pub async fn message_fee(&self, message_len: u32, domain: u32, at: Option<H256>) -> Result<u128, crate::Error> {
+		let message = avail::vector::types::Message::ArbitraryMessage(vec![0u8; message_len as usize]);
+		let value = avail::vector::tx::SendMessage { message, to: H256::zero(), domain };
+		let tx = SubmittableTransaction::from_encodable(self.0.clone(), value);
+
+		let call_fee = self.0.limited(tx.estimate_call_fees(at));
+		let message_fee = self.0.limited(self.stored_message_fee(at));
+		let (call_fee, message_fee) = futures::future::try_join(call_fee, message_fee)
+			.await
+			.map_err(crate::Error::from)?;
+		Ok(call_fee.final_fee().saturating_add(message_fee))
+	}
+
+	async fn stored_message_fee(&self, at: Option<H256>) -> Result<u128, RpcError> {
+		use avail::vector::storage::MessageFee;
+
+		let metadata = self.0.online_client().metadata();
+		let declared = metadata
+			.pallet_by_name(MessageFee::PALLET_NAME)
+			.and_then(|x| x.storage())
+			.and_then(|x| x.entry_by_name(MessageFee::STORAGE_NAME))
+			.is_some();
+		if !declared {
+			return Ok(0);
+		}
+
+		let key = MessageFee::hex_encode_storage_key();
+		let Some(value) = self.0.chain().state_get_storage(&key, at).await? else {
+			return Ok(0);
+		};
+		MessageFee::decode_storage_value(&mut value.as_slice()).map_err(|e| RpcError::DecodingFailed(e.to_string()))
+	}
+
 	/// Marks previous outbound messages as failed by index.
 	///
 	pub fn failed_send_message_txs(&self, failed_txs: Vec<u32>) -> SubmittableTransaction {
@@ -1,13 +1,23 @@
 //! Builders for configuring transaction submission defaults (nonce, tip, mortality).
 
-use crate::{Client, RetryPolicy};
-use avail_rust_core::{AccountId, Era, Extension, H256};
+use crate::{Client, RetryPolicy, error_ops::ErrorOperation};
+use avail_rust_core::{AccountId, Era, Extension, H256, MetadataHashMode};
 
+/// Per-transaction overrides for nonce, tip, mortality and signing inputs.
+///
+/// The SDK runs no balance or nonce sanity checks before submitting; the node's transaction pool
+/// is the only validator. Fields left unset are read from the chain, so setting both `nonce` and
+/// [`MortalityOption::Full`] submits without any lookup, which suits tight benchmark loops.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Options {
 	pub mortality: Option<MortalityOption>,
 	pub nonce: Option<u32>,
 	pub tip: Option<u128>,
+	pub genesis_hash: Option<H256>,
+	/// `CheckMetadataHash` mode; see [`Options::metadata_hash_mode`].
+	pub metadata_hash_mode: Option<MetadataHashMode>,
+	/// Hash signed with [`MetadataHashMode::Enabled`]; see [`Options::metadata_hash`].
+	pub metadata_hash: Option<H256>,
 }
 
 impl Options {
@@ -20,6 +30,11 @@ impl Options {
 		self
 	}
 
+	/// Signs with `value` instead of the account's next nonce.
+	///
+	/// The nonce may be ahead of the on-chain one. Such a transaction is accepted into the pool's future
+	/// queue and only executes once every nonce before it has been used, so nonce pipelines can submit
+	/// `N + 1` before `N` lands.
 	pub fn nonce(mut self, value: u32) -> Self {
 		self.nonce = Some(value);
 		self
@@ -30,26 +45,113 @@ impl Options {
 		self
 	}
 
+	/// Signs for the chain with this genesis hash instead of the one the client is connected to.
+	pub fn genesis_hash(mut self, value: H256) -> Self {
+		self.genesis_hash = Some(value);
+		self
+	}
+
+	/// Includes the `CheckMetadataHash` extension with `value` as its mode.
+	///
+	/// Leave unset for runtimes that do not include the extension, which is the case for current chains.
+	/// [`MetadataHashMode::Enabled`] also needs [`Options::metadata_hash`]. Only the submit paths sign
+	/// with the extension; [`Chain::build_extrinsic_from_call`](crate::chain::Chain::build_extrinsic_from_call)
+	/// and signer payloads reject it.
+	pub fn metadata_hash_mode(mut self, value: MetadataHashMode) -> Self {
+		self.metadata_hash_mode = Some(value);
+		self
+	}
+
+	/// Sets the runtime's RFC-78 metadata hash signed with [`MetadataHashMode::Enabled`].
+	///
+	/// The SDK does not merkleize metadata itself, so the hash has to be supplied, for example from the
+	/// tooling that built the runtime.
+	pub fn metadata_hash(mut self, value: H256) -> Self {
+		self.metadata_hash = Some(value);
+		self
+	}
+
+	/// Pins every field to the values of an already resolved configuration.
+	///
+	/// Resolving the result again performs no chain lookups, so a base resolved once can be reused
+	/// for many submissions; use [`ResolvedOptions::with_nonce`] to vary the nonce between them.
+	pub fn from_resolved(value: &ResolvedOptions) -> Self {
+		Self {
+			mortality: Some(MortalityOption::Full(value.mortality)),
+			nonce: Some(value.nonce),
+			tip: Some(value.tip),
+			genesis_hash: Some(value.genesis_hash),
+			metadata_hash_mode: value.metadata_hash_mode,
+			metadata_hash: value.metadata_hash,
+		}
+	}
+
+	/// Returns the explicit genesis hash, falling back to the connected chain's.
+	///
+	/// A mismatch with the connected chain is reported as a warning but the explicit value is kept.
+	pub fn resolve_genesis_hash(self, client: &Client) -> H256 {
+		let connected = client.online_client().genesis_hash();
+		let Some(value) = self.genesis_hash else {
+			return connected;
+		};
+
+		#[cfg(feature = "tracing")]
+		if value != connected {
+			crate::utils::trace_warn(&std::format!(
+				"Explicit genesis hash {:?} does not match connected chain genesis hash {:?}",
+				value,
+				connected
+			));
+		}
+
+		value
+	}
+
+	/// Returns the explicit nonce or reads the account's next nonce from the node.
+	///
+	/// The nonce is read through the client's RPC transport, the same one used to submit the transaction,
+	/// so both calls reach the same node.
 	pub async fn resolve_nonce(
 		self,
 		client: &Client,
 		account_id: &AccountId,
 		retry_on_error: RetryPolicy,
 	) -> Result<u32, crate::Error> {
-		let nonce = match self.nonce {
-			Some(x) => x,
-			None => {
-				client
-					.chain()
-					.retry_policy(retry_on_error, RetryPolicy::Inherit)
-					.account_nonce(account_id.clone())
-					.await?
-			},
-		};
+		if let Some(nonce) = self.nonce {
+			return Ok(nonce);
+		}
+
+		let chain = client.chain().retry_policy(retry_on_error, RetryPolicy::Inherit);
+		let online_client = client.online_client();
+		if !online_client.is_nonce_cache_enabled() {
+			return chain.account_nonce(account_id.clone()).await;
+		}
+
+		let finalized_height = chain.info().await?.finalized_height;
+		if let Some(nonce) = online_client.cached_nonce(account_id, finalized_height) {
+			return Ok(nonce);
+		}
+
+		let nonce = chain.account_nonce(account_id.clone()).await?;
+		online_client.cache_nonce(account_id.clone(), nonce, finalized_height);
 
 		Ok(nonce)
 	}
 
+	/// Returns the metadata hash to sign for the configured `CheckMetadataHash` mode.
+	///
+	/// Fails when [`MetadataHashMode::Enabled`] is set without [`Options::metadata_hash`].
+	pub fn resolve_metadata_hash(self) -> Result<Option<H256>, crate::Error> {
+		match (self.metadata_hash_mode, self.metadata_hash) {
+			(Some(MetadataHashMode::Enabled), None) => Err(crate::Error::validation_with_op(
+				ErrorOperation::OptionsMetadataHash,
+				"CheckMetadataHash mode is enabled but no metadata hash was provided",
+			)),
+			(Some(MetadataHashMode::Enabled), hash) => Ok(hash),
+			_ => Ok(None),
+		}
+	}
+
 	pub async fn resolve_mortality(self, client: &Client) -> Result<Mortality, crate::Error> {
 		let mortality = self.mortality.unwrap_or(MortalityOption::Period(32));
 		let mortality = match mortality {
@@ -69,8 +171,17 @@ impl Options {
 		let tip = self.tip.unwrap_or_default();
 		let nonce = self.resolve_nonce(client, account_id, retry_on_error).await?;
 		let mortality = self.resolve_mortality(client).await?;
+		let genesis_hash = self.resolve_genesis_hash(client);
+		let metadata_hash = self.resolve_metadata_hash()?;
 
-		Ok(ResolvedOptions { mortality, nonce, tip })
+		Ok(ResolvedOptions {
+			mortality,
+			nonce,
+			tip,
+			genesis_hash,
+			metadata_hash_mode: self.metadata_hash_mode,
+			metadata_hash,
+		})
 	}
 }
 
@@ -79,6 +190,31 @@ pub struct ResolvedOptions {
 	pub mortality: Mortality,
 	pub nonce: u32,
 	pub tip: u128,
+	pub genesis_hash: H256,
+	/// `None` when the `CheckMetadataHash` extension is not included.
+	pub metadata_hash_mode: Option<MetadataHashMode>,
+	/// Set only when the mode is [`MetadataHashMode::Enabled`].
+	pub metadata_hash: Option<H256>,
+}
+
+impl ResolvedOptions {
+	/// Returns a copy of these options that signs with `nonce`.
+	pub fn with_nonce(&self, nonce: u32) -> Self {
+		Self { nonce, ..self.clone() }
+	}
+
+	/// Fails with a validation error when these options include the `CheckMetadataHash` extension.
+	///
+	/// Used by the paths whose output only carries the regular [`Extension`].
+	pub fn ensure_without_metadata_hash(&self) -> Result<(), crate::Error> {
+		if self.metadata_hash_mode.is_none() {
+			return Ok(());
+		}
+		Err(crate::Error::validation_with_op(
+			ErrorOperation::OptionsMetadataHash,
+			"CheckMetadataHash is only supported when submitting through the SDK",
+		))
+	}
 }
 
 impl From<&ResolvedOptions> for Extension {
@@ -112,3 +248,105 @@ impl Mortality {
 		Ok(Self { period, block_hash, block_height })
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{clients::mock_client::MockClient, prelude::*, subxt_rpcs::RpcClient};
+
+	#[test]
+	fn from_resolved_pins_every_field() {
+		let resolved = ResolvedOptions {
+			mortality: Mortality::new(64, H256::repeat_byte(1), 10),
+			nonce: 5,
+			tip: 7,
+			genesis_hash: H256::repeat_byte(2),
+			metadata_hash_mode: None,
+			metadata_hash: None,
+		};
+
+		let options = Options::from_resolved(&resolved.with_nonce(6));
+		assert_eq!(options.nonce, Some(6));
+		assert_eq!(options.tip, Some(7));
+		assert_eq!(options.genesis_hash, Some(resolved.genesis_hash));
+		let Some(MortalityOption::Full(mortality)) = options.mortality else {
+			panic!("Expected full mortality");
+		};
+		assert_eq!((mortality.period, mortality.block_hash, mortality.block_height), (64, H256::repeat_byte(1), 10));
+	}
+
+	#[test]
+	fn metadata_hash_follows_mode() {
+		let hash = H256::repeat_byte(3);
+		assert_eq!(Options::new().metadata_hash(hash).resolve_metadata_hash().unwrap(), None);
+
+		let disabled = Options::new().metadata_hash_mode(MetadataHashMode::Disabled);
+		assert_eq!(disabled.metadata_hash(hash).resolve_metadata_hash().unwrap(), None);
+
+		let enabled = Options::new().metadata_hash_mode(MetadataHashMode::Enabled);
+		assert_eq!(enabled.metadata_hash(hash).resolve_metadata_hash().unwrap(), Some(hash));
+		let err = enabled.resolve_metadata_hash().unwrap_err();
+		assert_eq!(err.code(), crate::ErrorCode::Validation);
+	}
+
+	#[tokio::test]
+	async fn nonce_cache_follows_submission_results() -> Result<(), crate::Error> {
+		use crate::clients::mock_client::{CommandManagerHelper, metadata_bytes};
+		use avail_rust_core::{Era, Extension, ExtrinsicBorrowed};
+		use codec::Encode;
+		use serde_json::value::RawValue;
+
+		let (rpc_client, mut commander) = MockClient::new(LOCAL_ENDPOINT);
+		commander.bootstrap_ok(&metadata_bytes(&[]));
+		let client = Client::from_rpc_client(RpcClient::new(rpc_client)).await?;
+		client.enable_nonce_cache(16);
+		let chain = client.chain().retry_policy(RetryPolicy::Disabled, RetryPolicy::Inherit);
+		let account_id = alice().public_key().to_account_id();
+		let resolve = || Options::new().resolve_nonce(&client, &account_id, RetryPolicy::Disabled);
+		// Every cached lookup first reads the finalized height from the chain info.
+		let finalized_at = |commander: &mut CommandManagerHelper, height: u32| {
+			let hash = serde_json::to_string(&H256::zero()).unwrap();
+			let info = std::format!(
+				r#"{{"best_hash":{hash},"best_height":{height},"finalized_hash":{hash},"finalized_height":{height},"genesis_hash":{hash}}}"#
+			);
+			commander.add_ok("custom_chainInfo", RawValue::from_string(info).unwrap());
+		};
+		let nonce_lookups = |commander: &CommandManagerHelper| commander.call_count("system_accountNextIndex");
+
+		// A miss reads the nonce from the node, a hit at the same finalized height does not.
+		finalized_at(&mut commander, 10);
+		finalized_at(&mut commander, 10);
+		commander.add_ok("system_accountNextIndex", RawValue::from_string("5".into()).unwrap());
+		assert_eq!(resolve().await?, 5);
+		assert_eq!(resolve().await?, 5);
+		assert_eq!(nonce_lookups(&commander), 1);
+
+		let extension = Extension::new(Era::Immortal, 5, 0);
+		let extrinsic = ExtrinsicBorrowed::new_signed(account_id.clone(), [0u8; 64], extension, &[0, 1]).encode();
+		let ext_hash = std::format!("\"{:?}\"", H256::zero());
+
+		// An accepted submission bumps the cached nonce without another lookup.
+		commander.add_ok("author_submitExtrinsic", RawValue::from_string(ext_hash).unwrap());
+		chain.submit(&extrinsic).await?;
+		finalized_at(&mut commander, 10);
+		assert_eq!(resolve().await?, 6);
+		assert_eq!(nonce_lookups(&commander), 1);
+
+		// A rejected submission drops the entry, so the next lookup asks the node again.
+		let error = subxt_rpcs::Error::DisconnectedWillReconnect("rejected".into());
+		commander.add_err("author_submitExtrinsic", error);
+		assert!(chain.submit(&extrinsic).await.is_err());
+		finalized_at(&mut commander, 10);
+		commander.add_ok("system_accountNextIndex", RawValue::from_string("9".into()).unwrap());
+		assert_eq!(resolve().await?, 9);
+		assert_eq!(nonce_lookups(&commander), 2);
+
+		// Once the finalized height advances the cached nonce is stale and read again.
+		finalized_at(&mut commander, 11);
+		commander.add_ok("system_accountNextIndex", RawValue::from_string("12".into()).unwrap());
+		assert_eq!(resolve().await?, 12);
+		assert_eq!(nonce_lookups(&commander), 3);
+
+		Ok(())
+	}
+}
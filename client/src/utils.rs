@@ -1,4 +1,6 @@
 use crate::platform::sleep;
+#[cfg(feature = "tracing")]
+use avail_rust_core::{AccountId, H256};
 use std::{fmt::Debug, time::Duration};
 
 #[cfg(feature = "tracing")]
@@ -6,6 +8,39 @@ pub(crate) fn trace_warn(message: &str) {
 	tracing::warn!(target: "lib", message);
 }
 
+/// Creates the span that wraps signing and submitting a transaction.
+///
+/// `tx_hash` is left empty and recorded once the node accepts the transaction.
+#[cfg(feature = "tracing")]
+pub(crate) fn tx_submit_span(account_id: &AccountId, nonce: u32, call: &[u8]) -> tracing::Span {
+	let app_id = call_app_id(call);
+	tracing::info_span!(target: "lib", "tx_submit", tx_hash = tracing::field::Empty, account = %account_id, nonce, app_id)
+}
+
+/// Creates the span that wraps waiting for a transaction receipt.
+#[cfg(feature = "tracing")]
+pub(crate) fn tx_receipt_span(tx_hash: H256) -> tracing::Span {
+	tracing::info_span!(target: "lib", "tx_receipt", tx_hash = ?tx_hash)
+}
+
+/// Extracts the application id from an encoded data availability call, if it carries one.
+#[cfg(feature = "tracing")]
+fn call_app_id(call: &[u8]) -> Option<u32> {
+	use avail_rust_core::{
+		HasHeader,
+		avail::data_availability::tx::{SubmitBlobMetadata, SubmitData},
+	};
+	use codec::{Compact, Decode};
+
+	let header = (*call.first()?, *call.get(1)?);
+	if header != SubmitData::HEADER_INDEX && header != SubmitBlobMetadata::HEADER_INDEX {
+		return None;
+	}
+
+	let mut data = &call[2..];
+	Compact::<u32>::decode(&mut data).ok().map(|x| x.0)
+}
+
 /// Repeatedly executes an asynchronous operation until it succeeds or retries are exhausted.
 ///
 /// Returns the successful output of `f` or propagates the last encountered error.
@@ -119,3 +154,80 @@ where
 		};
 	}
 }
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+	use super::*;
+	use avail_rust_core::{HasHeader, avail::data_availability::tx::SubmitData};
+	use codec::Encode;
+	use std::sync::{Arc, Mutex};
+	use tracing::{
+		Event, Metadata, Subscriber,
+		field::{Field, Visit},
+		span::{Attributes, Id, Record},
+	};
+
+	#[derive(Default)]
+	struct FieldCapture(Vec<(String, String)>);
+	impl Visit for FieldCapture {
+		fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+			self.0.push((field.name().to_owned(), std::format!("{:?}", value)));
+		}
+	}
+
+	type CapturedSpan = (String, Vec<(String, String)>);
+
+	#[derive(Default, Clone)]
+	struct SpanCapture {
+		spans: Arc<Mutex<Vec<CapturedSpan>>>,
+	}
+
+	impl Subscriber for SpanCapture {
+		fn enabled(&self, _: &Metadata<'_>) -> bool {
+			true
+		}
+
+		fn new_span(&self, span: &Attributes<'_>) -> Id {
+			let mut fields = FieldCapture::default();
+			span.record(&mut fields);
+			let mut spans = self.spans.lock().unwrap();
+			spans.push((span.metadata().name().to_owned(), fields.0));
+			Id::from_u64(spans.len() as u64)
+		}
+
+		fn record(&self, span: &Id, values: &Record<'_>) {
+			let mut fields = FieldCapture::default();
+			values.record(&mut fields);
+			let mut spans = self.spans.lock().unwrap();
+			spans[span.into_u64() as usize - 1].1.extend(fields.0);
+		}
+
+		fn record_follows_from(&self, _: &Id, _: &Id) {}
+		fn event(&self, _: &Event<'_>) {}
+		fn enter(&self, _: &Id) {}
+		fn exit(&self, _: &Id) {}
+	}
+
+	#[test]
+	fn tx_submit_span_records_tx_hash() {
+		let capture = SpanCapture::default();
+		let account_id = crate::dev_accounts::alice().public_key().to_account_id();
+		let call = SubmitData { app_id: 7, data: vec![1, 2, 3] };
+		let mut encoded = vec![SubmitData::HEADER_INDEX.0, SubmitData::HEADER_INDEX.1];
+		encoded.extend(call.encode());
+		let tx_hash = H256::repeat_byte(0xAB);
+
+		tracing::subscriber::with_default(capture.clone(), || {
+			let span = tx_submit_span(&account_id, 3, &encoded);
+			span.record("tx_hash", tracing::field::debug(tx_hash));
+		});
+
+		let spans = capture.spans.lock().unwrap();
+		let (_, fields) = spans.iter().find(|x| x.0 == "tx_submit").expect("tx_submit span");
+		let field = |name: &str| fields.iter().find(|x| x.0 == name).map(|x| x.1.clone());
+		assert_eq!(field("tx_hash"), Some(std::format!("{:?}", tx_hash)));
+		assert_eq!(field("account"), Some(account_id.to_string()));
+		assert_eq!(field("nonce"), Some("3".to_owned()));
+		assert_eq!(field("app_id"), Some("7".to_owned()));
+	}
+}
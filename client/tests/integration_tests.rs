@@ -3,7 +3,10 @@ use avail_rust_client::{
 	prelude::*,
 };
 use avail_rust_core::{
-	avail::balances::types::AccountData,
+	avail::{
+		balances::types::AccountData,
+		identity::{events::IdentitySet, types::IdentityInfo},
+	},
 	rpc::{self, AllowedExtrinsic},
 };
 use std::str::FromStr;
@@ -18,12 +21,79 @@ pub async fn submit_works() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+pub async fn local_node_works() -> Result<(), Error> {
+	let client = Client::connect(LOCAL_ENDPOINT).await?;
+	events_by_tx_hash_test(&client).await?;
+	call_weight_test(&client).await?;
+	submit_with_resolved_test(&client).await?;
+	endpoint_test(&client).await?;
+	system_info_test(&client).await?;
+	finalized_ref_test(&client).await?;
+	vector_message_fee_test(&client).await?;
+	storage_raw_test(&client).await?;
+	sudo_test(&client).await?;
+	tx_sequence_test(&client).await?;
+	identity_test(&client).await?;
+	best_and_finalized_block_test(&client).await?;
+	ss58_prefix_test(&client).await?;
+	wait_for_height_test(&client).await?;
+	explicit_genesis_hash_test(&client).await?;
+	verify_justification_test(&client).await?;
+	signing_context_test(&client).await?;
+	pallet_event_filter_test(&client).await?;
+	block_dimensions_test(&client).await?;
+	header_da_commitment_test(&client).await?;
+	submit_data_by_key_test(&client).await?;
+	app_key_changes_test(&client).await?;
+	matching_extrinsics_test(&client).await?;
+	query_fee_info_test(&client).await?;
+	account_exists_test(&client).await?;
+	submit_to_many_test(&client).await?;
+	header_author_test(&client).await?;
+	submission_without_lookups_test(&client).await?;
+	scheduler_test(&client).await?;
+	options_from_resolved_test(&client).await?;
+	network_test(&client).await?;
+	events_from_storage_test(&client).await?;
+	call_data_hex_test(&client).await?;
+	submit_data_size_check_test(&client).await?;
+	app_submissions_pager_test(&client).await?;
+	submit_runtime_call_test(&client).await?;
+	blocks_until_expiry_test(&client).await?;
+	storage_fetch_or_default_test(&client).await?;
+	submit_and_wait_for_receipt_test(&client).await?;
+	all_app_keys_test(&client).await?;
+	force_batch_summary_test(&client).await?;
+	cross_check_events_test(&client).await?;
+	future_nonce_test(&client).await?;
+	dispatch_info_test(&client).await?;
+	header_hash_test(&client).await?;
+	headers_range_test(&client).await?;
+	next_app_id_test(&client).await?;
+	was_replaced_by_test(&client).await?;
+	force_set_balance_test(&client).await?;
+	legacy_blocks_with_events_test(&client).await?;
+	estimate_fees_reports_dispatch_class_test(&client).await?;
+	block_time_test(&client).await?;
+	receipt_race_test(&client).await?;
+	app_key_by_id_test(&client).await?;
+	extrinsic_call_as_subxt_payload_test(&client).await?;
+	check_balance_test(&client).await?;
+	signer_payload_test(&client).await?;
+	data_cost_per_byte_test(&client).await?;
+	cancel_pending_transaction_test(&client).await?;
+	block_tx_index_test(&client).await?;
+
+	Ok(())
+}
+
 // fn hex_encode(value: &[u8]) -> String {
 // 	const_hex::encode_prefixed(value)
 // }
 
 fn hex_decode(value: &str) -> Vec<u8> {
-	const_hex::decode(&value).unwrap()
+	const_hex::decode(value).unwrap()
 }
 
 fn h256(value: &str) -> H256 {
@@ -36,9 +106,9 @@ pub async fn block_extrinsics_test(client: &Client) -> Result<(), Error> {
 	let query = block.extrinsics();
 
 	assert_eq!(query.count(None, Default::default()).await?, 4);
-	assert_eq!(query.exists(None, Default::default()).await?, true);
+	assert!(query.exists(None, Default::default()).await?);
 	let allowed = Some(vec![AllowedExtrinsic::TxIndex(100)]);
-	assert_eq!(query.exists(allowed, Default::default()).await?, false);
+	assert!(!query.exists(allowed, Default::default()).await?);
 
 	let extrinsics = query.all(None, Default::default()).await?;
 	assert_eq!(extrinsics.len(), 4);
@@ -196,6 +266,7 @@ pub async fn block_information_test(client: &Client) -> Result<(), Error> {
 	let event_count_1 = client.chain().block_event_count(at).await?;
 	let event_count_2 = block.event_count().await?;
 	let extrinsic_count = block.extrinsic_count().await?;
+	let extrinsic_count_2 = client.chain().block_extrinsic_count(at).await?;
 	let header_1 = client.chain().block_header(Some(at)).await?.unwrap();
 	let header_2 = block.header().await?;
 	let block_info = block.info().await?;
@@ -207,6 +278,7 @@ pub async fn block_information_test(client: &Client) -> Result<(), Error> {
 	assert_eq!(event_count_1, event_count_2);
 	assert_eq!(event_count_2, 9);
 	assert_eq!(extrinsic_count, 4);
+	assert_eq!(extrinsic_count, extrinsic_count_2);
 	assert_eq!(author_1.to_string(), author_2.to_string());
 	assert_eq!(author_2.to_string(), "5EseWFKtQyQCYYchaepYtkGbgKLhzrAbo9qQ9KczBfF5WURW");
 	assert_eq!(total_weight, 54553175162);
@@ -218,8 +290,8 @@ pub async fn block_information_test(client: &Client) -> Result<(), Error> {
 	);
 	assert_eq!(header_1.number, header_2.number);
 	assert_eq!(header_2.number, at);
-	assert_eq!(justification.is_none(), true);
-	assert_eq!(client.block(1).justification().await?.is_some(), true);
+	assert!(justification.is_none());
+	assert!(client.block(1).justification().await?.is_some());
 
 	Ok(())
 }
@@ -254,5 +326,1059 @@ pub async fn account_test(client: &Client) -> Result<(), Error> {
 	assert_eq!(info_1.nonce, info_2.nonce);
 	assert_eq!(info_2.nonce, expected);
 
+	// reference counters must be decoded as well
+	assert!(info_1.providers >= 1);
+	assert_eq!(info_1.providers, info_2.providers);
+	assert_eq!(info_1.consumers, info_2.consumers);
+	assert_eq!(info_1.sufficients, info_2.sufficients);
+
+	Ok(())
+}
+
+pub async fn events_by_tx_hash_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+
+	let tx = client.tx().system().remark_with_event(b"events by tx hash".to_vec());
+	let receipt = tx
+		.submit_and_wait_for_receipt(&signer, Options::new(), BlockQueryMode::Finalized)
+		.await?;
+
+	let found = client.event_client().events_by_tx_hash(receipt.ext_hash, 10).await?;
+	let found = found.expect("transaction should be found within search depth");
+	assert_eq!((found.block_hash, found.ext_index), (receipt.block_hash, receipt.ext_index));
+	assert!(found.events.is_extrinsic_success_present());
+	assert_eq!(found.events.len(), receipt.events().await?.len());
+
+	let missing = client.event_client().events_by_tx_hash(H256::zero(), 5).await?;
+	assert!(missing.is_none());
+
+	Ok(())
+}
+
+pub async fn call_weight_test(client: &Client) -> Result<(), Error> {
+	let tx = client
+		.tx()
+		.balances()
+		.transfer_keep_alive(bob().public_key().to_account_id(), ONE_AVAIL)?;
+	let weight_1 = tx.call_info(None).await?.weight;
+	let weight_2 = client.chain().call_weight(&tx.call, None).await?;
+	assert_eq!(weight_1, weight_2);
+
+	Ok(())
+}
+
+pub async fn submit_with_resolved_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+
+	let tx = client.tx().system().remark(b"resolved options".to_vec());
+	let mut resolved = tx.resolve_options(&signer, Options::new()).await?;
+	assert_eq!(resolved.tip, 0);
+	resolved.tip = 1_000;
+
+	let submitted = tx.submit_with_resolved(&signer, &resolved).await?;
+	let receipt = submitted.receipt(BlockQueryMode::Best).await?;
+	let ext = receipt.untyped_extrinsic().await?;
+	assert_eq!(ext.tip(), Some(1_000));
+	assert_eq!(ext.nonce(), Some(resolved.nonce));
+
+	Ok(())
+}
+
+pub async fn endpoint_test(client: &Client) -> Result<(), Error> {
+	assert_eq!(client.endpoint(), Some(LOCAL_ENDPOINT));
+	assert_eq!(client.transport_kind(), TransportKind::Http);
+
+	Ok(())
+}
+
+pub async fn system_info_test(client: &Client) -> Result<(), Error> {
+	assert_eq!(client.chain().system_chain().await?, "Avail Development Network");
+	assert!(!client.chain().system_name().await?.is_empty());
+	assert!(!client.chain().system_version().await?.is_empty());
+
+	Ok(())
+}
+
+pub async fn finalized_ref_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+
+	let tx = client.tx().system().remark(b"finalized ref".to_vec());
+	let receipt = tx
+		.submit_and_wait_for_receipt(&signer, Options::new(), BlockQueryMode::Finalized)
+		.await?;
+
+	let block_ref = receipt.finalized_ref().await?;
+	assert_eq!(block_ref.hash, receipt.block_hash);
+	assert_eq!(block_ref.height, receipt.block_height);
+
+	let best = client.best().block_info().await?;
+	let pending = TransactionReceipt::new(client.clone(), best.hash, best.height + 1, receipt.ext_hash, 0);
+	assert!(pending.finalized_ref().await.is_err());
+
+	Ok(())
+}
+
+pub async fn vector_message_fee_test(client: &Client) -> Result<(), Error> {
+	let fee = client.tx().vector().message_fee(32, 2, None).await?;
+	assert!(fee > 0);
+	assert!(client.tx().vector().message_fee(1024, 2, None).await? >= fee);
+
+	Ok(())
+}
+
+pub async fn storage_raw_test(client: &Client) -> Result<(), Error> {
+	use avail::timestamp::storage::Now;
+	use codec::Decode;
+
+	let at = client.finalized().block_hash().await?;
+
+	let raw = client.chain().storage_raw(&Now::encode_storage_key(), Some(at)).await?;
+	let raw = raw.expect("Timestamp::Now should be set");
+	let now = u64::decode(&mut raw.as_slice()).unwrap();
+	assert_eq!(now, client.chain().block_timestamp(at).await?);
+
+	Ok(())
+}
+
+pub async fn sudo_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+
+	let call = client
+		.tx()
+		.balances()
+		.transfer_keep_alive(bob().public_key().to_account_id(), ONE_AVAIL)?;
+	let tx = client.tx().sudo().sudo(&call)?;
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&signer, Options::new(), BlockQueryMode::Best)
+		.await?;
+
+	let sudid = events.first::<avail::sudo::events::Sudid>().expect("Sudid event");
+	assert!(sudid.sudo_result.is_ok());
+
+	Ok(())
+}
+
+pub async fn tx_sequence_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+	let nonce = client
+		.chain()
+		.account_nonce(signer.public_key().to_account_id())
+		.await?;
+
+	let submitted = client
+		.tx_sequence(&signer)
+		.push(
+			client
+				.tx()
+				.data_availability()
+				.create_application_key("tx sequence key"),
+		)
+		.push(client.tx().data_availability().submit_data(2, "tx sequence data")?)
+		.push(
+			client
+				.tx()
+				.balances()
+				.transfer_keep_alive(bob().public_key().to_account_id(), ONE_AVAIL)?,
+		)
+		.submit_all()
+		.await?;
+	assert_eq!(submitted.len(), 3);
+
+	for tx in submitted {
+		let (_, events) = tx.outcome(BlockQueryMode::Finalized).await?;
+		assert!(events.is_extrinsic_success_present());
+	}
+	assert_eq!(
+		client
+			.chain()
+			.account_nonce(signer.public_key().to_account_id())
+			.await?,
+		nonce + 3
+	);
+
+	Ok(())
+}
+
+pub async fn identity_test(client: &Client) -> Result<(), Error> {
+	let signer = bob();
+
+	let info = IdentityInfo::new().display("Bob").web("https://example.com");
+	let tx = client.tx().identity().set_identity(info)?;
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&signer, Options::new(), BlockQueryMode::Finalized)
+		.await?;
+
+	let event = events
+		.first::<IdentitySet>()
+		.expect("IdentitySet event should be emitted");
+	assert_eq!(event.who, signer.public_key().to_account_id());
+
+	let too_long = IdentityInfo::new().display("x".repeat(33));
+	assert!(client.tx().identity().set_identity(too_long).is_err());
+
+	Ok(())
+}
+
+pub async fn best_and_finalized_block_test(client: &Client) -> Result<(), Error> {
+	let block = client.best_block().await?;
+	let height = client.best().block_height().await?;
+	let block_height = block.height().expect("height should be known");
+	assert!(block_height <= height && height - block_height <= 1);
+	assert_eq!(block.info().await?.height, block_height);
+
+	let block = client.finalized_block().await?;
+	let height = client.finalized().block_height().await?;
+	let block_height = block.height().expect("height should be known");
+	assert!(block_height <= height && height - block_height <= 1);
+	assert_eq!(block.hash(), client.chain().block_hash(Some(block_height)).await?);
+
+	Ok(())
+}
+
+pub async fn ss58_prefix_test(client: &Client) -> Result<(), Error> {
+	assert_eq!(client.ss58_prefix()?, 42);
+
+	let account_id = alice().public_key().to_account_id();
+	assert_eq!(client.ss58_address(account_id.clone())?, account_id.to_string());
+
+	Ok(())
+}
+
+pub async fn wait_for_height_test(client: &Client) -> Result<(), Error> {
+	let target = client.best().block_height().await? + 1;
+	let info = client
+		.wait_for_height(target, true, std::time::Duration::from_secs(60))
+		.await?;
+	assert!(info.height >= target);
+
+	let result = client
+		.wait_for_height(u32::MAX, false, std::time::Duration::from_secs(2))
+		.await;
+	assert!(matches!(result, Err(Error::Timeout(_))));
+
+	Ok(())
+}
+
+pub async fn explicit_genesis_hash_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_client::subxt_signer::sr25519::{Signature, verify};
+	use avail_rust_core::{MultiSignature, ext::codec::Encode, substrate::extrinsic::Preamble};
+
+	let signer = alice();
+	let genesis_hash = H256::repeat_byte(7);
+
+	let tx = client.tx().system().remark(b"explicit genesis".to_vec());
+	let resolved = tx
+		.resolve_options(&signer, Options::new().genesis_hash(genesis_hash))
+		.await?;
+	assert_eq!(resolved.genesis_hash, genesis_hash);
+
+	let options = Options::new()
+		.genesis_hash(genesis_hash)
+		.nonce(resolved.nonce)
+		.mortality(MortalityOption::Full(resolved.mortality));
+	let signed = tx.sign(&signer, options).await?;
+	let Preamble::Signed(_, MultiSignature::Sr25519(signature), extension) = &signed.preamble else {
+		panic!("expected a signed extrinsic");
+	};
+
+	let implicit = client.chain().extension_implicit(&resolved);
+	assert_eq!(implicit.genesis_hash, genesis_hash);
+	let mut payload = tx.call.0.clone();
+	payload.extend(extension.encode());
+	payload.extend(implicit.encode());
+	assert!(payload.len() <= 256);
+	assert!(verify(&Signature(*signature), &payload, &signer.public_key()));
+
+	Ok(())
+}
+
+pub async fn verify_justification_test(client: &Client) -> Result<(), Error> {
+	let hash = client.chain().block_hash(Some(1)).await?.expect("Block 1 must exist");
+	assert!(client.chain().verify_justification(hash).await?);
+
+	Ok(())
+}
+
+pub async fn signing_context_test(client: &Client) -> Result<(), Error> {
+	let account_id = alice().public_key().to_account_id();
+
+	let context = client.signing_context(&account_id).await?;
+	assert_eq!(context.genesis, client.online_client().genesis_hash());
+	assert_eq!(context.spec_version, client.online_client().spec_version());
+	assert_eq!(context.transaction_version, client.online_client().transaction_version());
+	assert_eq!(context.nonce, client.chain().account_nonce(account_id).await?);
+	assert_ne!(context.finalized_info.hash, H256::zero());
+	assert!(context.finalized_info.height <= client.finalized().block_height().await?);
+
+	Ok(())
+}
+
+pub async fn pallet_event_filter_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::avail::balances;
+
+	let tx = client
+		.tx()
+		.balances()
+		.transfer_keep_alive(bob().public_key().to_account_id(), ONE_AVAIL)?;
+	let receipt = tx
+		.submit_and_wait_for_receipt(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+
+	let block = client.block(receipt.block_hash);
+	let all = block.events().all(AllowedEvents::All).await?;
+	assert!(all.0.iter().any(|e| e.pallet_id != balances::PALLET_ID));
+
+	let only = block.events().all(AllowedEvents::Pallet(balances::PALLET_ID)).await?;
+	assert!(!only.0.is_empty());
+	assert!(only.0.iter().all(|e| e.pallet_id == balances::PALLET_ID));
+	assert_eq!(only.0.len(), all.0.iter().filter(|e| e.pallet_id == balances::PALLET_ID).count());
+
+	Ok(())
+}
+
+pub async fn block_dimensions_test(client: &Client) -> Result<(), Error> {
+	let dimensions = client.chain().block_dimensions(None).await?;
+	assert!(dimensions.rows.is_power_of_two());
+	assert!(dimensions.cols.is_power_of_two());
+	assert!(dimensions.chunk_size > 0);
+
+	let length = client.chain().kate_block_length(None).await?;
+	assert_eq!((dimensions.rows, dimensions.cols), (length.rows, length.cols));
+
+	Ok(())
+}
+
+pub async fn header_da_commitment_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().data_availability().submit_data(2, "header commitment")?;
+	let receipt = tx
+		.submit_and_wait_for_receipt(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+
+	let header = client
+		.chain()
+		.block_header(Some(receipt.block_hash))
+		.await?
+		.expect("Header must exist");
+	let info = header.da_commitment().expect("Local chain uses KZG headers");
+	assert_ne!(info.data_root, H256::zero());
+	assert_eq!(info.data_root, header.data_root());
+	assert!(info.rows > 0 && info.cols > 0);
+	assert!(!info.commitment.is_empty());
+
+	Ok(())
+}
+
+pub async fn submit_data_by_key_test(client: &Client) -> Result<(), Error> {
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.unwrap()
+		.as_millis();
+	let key = std::format!("submit by key {}", now);
+
+	let tx = client.tx().data_availability().create_application_key(key.as_str());
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+	assert!(events.is_extrinsic_success_present());
+
+	let tx = client
+		.tx()
+		.data_availability()
+		.submit_data_by_key(&key, "data by key")
+		.await?;
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+	assert!(events.is_extrinsic_success_present());
+
+	let result = client
+		.tx()
+		.data_availability()
+		.submit_data_by_key("this key does not exist", "data")
+		.await;
+	assert!(matches!(result, Err(Error::NotFound(_))));
+
+	Ok(())
+}
+
+pub async fn app_key_changes_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_client::StorageMapChanges;
+	use avail_rust_core::avail::data_availability::storage::AppKeys;
+	use futures::StreamExt;
+
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.unwrap()
+		.as_millis();
+	let key = std::format!("watched key {}", now).into_bytes();
+
+	// The first snapshot is taken once the stream is polled, so watch while submitting.
+	let watch = async {
+		let mut changes = std::pin::pin!(AppKeys::subscribe_changes(client.clone()));
+		while let Some(block_changes) = changes.next().await {
+			if let Some((_, value)) = block_changes?.into_iter().find(|(changed, _)| changed == &key) {
+				return Ok(value);
+			}
+		}
+		Ok::<_, Error>(None)
+	};
+	let submit = async {
+		let tx = client.tx().data_availability().create_application_key(key.as_slice());
+		tx.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Finalized)
+			.await
+	};
+	let (value, outcome) = tokio::join!(tokio::time::timeout(std::time::Duration::from_secs(60), watch), submit);
+	assert!(outcome?.1.is_extrinsic_success_present());
+
+	let value = value.expect("Change should be observed")?;
+	let value = value.expect("A created key should map to a value");
+	assert_eq!(value.owner, alice().public_key().to_account_id());
+
+	Ok(())
+}
+
+pub async fn matching_extrinsics_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::{HasHeader, avail::data_availability};
+
+	let (pallet_id, variant_id) = data_availability::tx::SubmitData::HEADER_INDEX;
+	let mut sub = client
+		.subscribe()
+		.matching_extrinsics(move |ext| ext.metadata.pallet_id == pallet_id && ext.metadata.variant_id == variant_id)
+		.mode(BlockQueryMode::Best)
+		.skip_empty()
+		.build()
+		.await?;
+
+	let tx = client.tx().data_availability().submit_data(2, "matching extrinsics")?;
+	let submitted = tx.submit(&alice(), Options::new()).await?;
+
+	loop {
+		let item = sub.next().await?;
+		assert!(!item.value.is_empty());
+		assert!(item.value.iter().all(|x| x.metadata.pallet_id == pallet_id));
+		if item.value.iter().any(|x| x.metadata.ext_hash == submitted.ext_hash) {
+			break;
+		}
+	}
+
+	Ok(())
+}
+
+pub async fn query_fee_info_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().data_availability().submit_data(2, "offline signed")?;
+	let signed = tx.sign(&alice(), Options::new()).await?;
+	let bytes = codec::Encode::encode(&signed);
+
+	let info = client.chain().query_fee_info(&bytes, None).await?;
+	assert!(info.partial_fee > 0);
+	assert!(client.chain().query_fee_info(&bytes[1..], None).await.is_err());
+
+	Ok(())
+}
+
+pub async fn account_exists_test(client: &Client) -> Result<(), Error> {
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.unwrap()
+		.as_millis();
+	let uri = SecretUri::from_str(&std::format!("//AccountExists{}", now)).unwrap();
+	let fresh = Keypair::from_uri(&uri).unwrap().public_key().to_account_id();
+	assert!(!client.finalized().account_exists(fresh.clone()).await?);
+
+	let tx = client.tx().balances().transfer_keep_alive(fresh.clone(), ONE_AVAIL)?;
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+	assert!(events.is_extrinsic_success_present());
+	assert!(client.finalized().account_exists(fresh).await?);
+
+	Ok(())
+}
+
+pub async fn submit_to_many_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().data_availability().submit_data(2, "submit to many")?;
+	let signed = tx.sign(&alice(), Options::new()).await?;
+	let bytes = codec::Encode::encode(&signed);
+
+	let endpoints = vec![LOCAL_ENDPOINT.to_string(), "http://127.0.0.1:1/rpc".to_string()];
+	let hash = client.submit_to_many(&bytes, &endpoints).await?;
+	assert_eq!(hash, H256::from(avail_rust_core::ext::sp_crypto_hashing::blake2_256(&bytes)));
+
+	Ok(())
+}
+
+pub async fn header_author_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::avail::session::storage::Validators;
+
+	let header = client.finalized().block_header().await?;
+	let validators = Validators::fetch(&client.rpc_client, Some(header.hash()))
+		.await?
+		.unwrap_or_default();
+
+	let author = header.author(&validators).expect("Local blocks are authored by BABE");
+	let dev_validators: Vec<AccountId> = ["//Alice", "//Alice//stash", "//Bob", "//Bob//stash"]
+		.iter()
+		.map(|uri| Keypair::from_uri(&SecretUri::from_str(uri).unwrap()).unwrap())
+		.map(|x| x.public_key().to_account_id())
+		.collect();
+	assert!(dev_validators.contains(&author));
+	assert_eq!(client.chain().block_author(header.hash()).await?, author);
+
+	Ok(())
+}
+
+pub async fn submission_without_lookups_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+
+	let nonce = client.best().account_nonce(signer.public_key().to_account_id()).await?;
+	let info = client.finalized().block_info().await?;
+	let mortality = Mortality::new(32, info.hash, info.height);
+
+	let mut last = None;
+	for i in 0..3u32 {
+		let options = Options::new()
+			.nonce(nonce + i)
+			.mortality(MortalityOption::Full(mortality));
+		let tx = client
+			.tx()
+			.data_availability()
+			.submit_data(2, std::format!("no lookups {}", i))?;
+		last = Some(tx.submit(&signer, options).await?);
+	}
+
+	let submitted = last.unwrap();
+	let receipt = submitted.receipt(BlockQueryMode::Best).await?;
+	assert_eq!(receipt.ext_hash, submitted.ext_hash);
+
+	Ok(())
+}
+
+pub async fn scheduler_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::avail::scheduler::events::Scheduled;
+
+	let when = client.best().block_height().await? + 10;
+
+	let remark = client.tx().system().remark(b"scheduled".to_vec());
+	let call = client.tx().scheduler().schedule(when, None, 0, &remark)?;
+	let tx = client.tx().sudo().sudo(&call)?;
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Best)
+		.await?;
+
+	let scheduled = events.first::<Scheduled>().expect("Scheduled event");
+	assert_eq!(scheduled.when, when);
+
+	Ok(())
+}
+
+pub async fn options_from_resolved_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+
+	let tx = client.tx().data_availability().submit_data(2, "resolved base 0")?;
+	let base = tx.resolve_options(&signer, Options::new()).await?;
+	let first = tx.submit(&signer, Options::from_resolved(&base)).await?;
+
+	let tx = client.tx().data_availability().submit_data(2, "resolved base 1")?;
+	let options = Options::from_resolved(&base.with_nonce(base.nonce + 1));
+	let second = tx.submit(&signer, options).await?;
+
+	let receipt = second.receipt(BlockQueryMode::Best).await?;
+	assert_eq!(receipt.ext_hash, second.ext_hash);
+	assert_eq!(first.block_start, second.block_start);
+
+	Ok(())
+}
+
+pub async fn network_test(client: &Client) -> Result<(), Error> {
+	let network = client.network().await?;
+	assert!(matches!(network, Network::Local | Network::Unknown));
+
+	Ok(())
+}
+
+pub async fn events_from_storage_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().data_availability().submit_data(2, "events from storage")?;
+	let receipt = tx
+		.submit_and_wait_for_receipt(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+
+	let query = client.block(receipt.block_hash).events();
+	let from_rpc = query.all(rpc::AllowedEvents::All).await?;
+	let from_storage = query.from_storage().await?;
+	assert_eq!(from_rpc.0.len(), from_storage.0.len());
+
+	for (a, b) in from_rpc.0.iter().zip(from_storage.0.iter()) {
+		assert_eq!(a.phase, b.phase);
+		assert_eq!((a.pallet_id, a.variant_id), (b.pallet_id, b.variant_id));
+		assert_eq!(hex_decode(&a.data), hex_decode(&b.data));
+	}
+
+	Ok(())
+}
+
+pub async fn call_data_hex_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::avail::data_availability::tx::SubmitData;
+
+	let tx = client.tx().data_availability().submit_data(2, "call data hex")?;
+
+	let hex = tx.call_data_hex();
+	assert!(hex.starts_with("0x"));
+	let call = ExtrinsicCall::try_from(hex.as_str()).unwrap();
+	assert_eq!(call.0, tx.call.0);
+	assert_eq!(call.try_decode::<SubmitData>().unwrap().data, b"call data hex".to_vec());
+
+	Ok(())
+}
+
+pub async fn submit_data_size_check_test(client: &Client) -> Result<(), Error> {
+	let max = client.max_app_data_length()?;
+
+	let data = vec![1u8; max as usize];
+	assert!(client.tx().data_availability().submit_data(2, data).is_ok());
+
+	let data = vec![1u8; max as usize + 1];
+	let result = client.tx().data_availability().submit_data(2, data);
+	let Err(Error::User(UserError::DataTooLarge { len, max: limit })) = result else {
+		panic!("Expected DataTooLarge");
+	};
+	assert_eq!((len, limit), (max as usize + 1, max));
+
+	Ok(())
+}
+
+pub async fn app_submissions_pager_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.unwrap()
+		.as_millis();
+
+	let tx = client
+		.tx()
+		.data_availability()
+		.create_application_key(std::format!("pager {}", now).as_str());
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&signer, Options::new(), BlockQueryMode::Finalized)
+		.await?;
+	let created = events
+		.first::<avail::data_availability::events::ApplicationKeyCreated>()
+		.expect("ApplicationKeyCreated event");
+
+	let mut first_height = None;
+	for i in 0..3 {
+		let tx = client
+			.tx()
+			.data_availability()
+			.submit_data(created.id, std::format!("page {}", i))?;
+		let receipt = tx
+			.submit_and_wait_for_receipt(&signer, Options::new(), BlockQueryMode::Finalized)
+			.await?;
+		first_height.get_or_insert(receipt.block_height);
+	}
+
+	let mut pager = client.app_submissions(created.id, first_height.unwrap(), 2);
+	assert_eq!(pager.next_page().await?.len(), 2);
+	assert_eq!(pager.next_page().await?.len(), 1);
+	assert!(pager.next_page().await?.is_empty());
+
+	Ok(())
+}
+
+pub async fn submit_runtime_call_test(client: &Client) -> Result<(), Error> {
+	let dest = MultiAddress::Id(bob().public_key().to_account_id());
+	let transfer = avail::balances::tx::TransferKeepAlive { dest, value: ONE_AVAIL };
+	let call = avail::RuntimeCall::BalancesTransferKeepAlive(transfer);
+
+	let submitted = client.submit_runtime_call(&call, &alice(), Options::new()).await?;
+	let (_, events) = submitted.outcome(BlockQueryMode::Finalized).await?;
+	assert!(events.is_extrinsic_success_present());
+
+	Ok(())
+}
+
+pub async fn blocks_until_expiry_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().system().remark(b"expiry".to_vec());
+	let options = Options::new().mortality(MortalityOption::Period(32));
+	let submitted = tx.submit(&alice(), options).await?;
+	let before = submitted.blocks_until_expiry().await?;
+	assert!(before > 0 && before <= 32);
+
+	let height = client.finalized().block_height().await?;
+	client
+		.wait_for_height(height + 2, false, std::time::Duration::from_secs(60))
+		.await?;
+	let after = submitted.blocks_until_expiry().await?;
+	assert!(after < before);
+
+	Ok(())
+}
+
+pub async fn storage_fetch_or_default_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::avail::data_availability::storage::AppKeys;
+
+	let key = b"this app key does not exist".to_vec();
+
+	assert!(AppKeys::fetch(&client.rpc_client, &key, None).await?.is_none());
+	let value = AppKeys::fetch_or_default(&client.rpc_client, &key, None).await?;
+	assert_eq!(value.id, 0);
+	assert_eq!(value.owner, AccountId::from([0u8; 32]));
+
+	Ok(())
+}
+
+pub async fn submit_and_wait_for_receipt_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().system().remark(b"submit and receipt".to_vec());
+	let receipt = tx
+		.submit_and_wait_for_receipt(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+	assert!(receipt.events().await?.is_extrinsic_success_present());
+
+	Ok(())
+}
+
+pub async fn all_app_keys_test(client: &Client) -> Result<(), Error> {
+	let keys = client.data_availability().all_app_keys(None).await?;
+	let avail = keys.iter().find(|(key, _, _)| key == "Avail");
+	let (_, _, id) = avail.expect("genesis app key should be present");
+	assert_eq!(*id, 0);
+
+	Ok(())
+}
+
+pub async fn force_batch_summary_test(client: &Client) -> Result<(), Error> {
+	let good = client.tx().system().remark(b"force batch".to_vec());
+	let bad = client
+		.tx()
+		.balances()
+		.transfer_keep_alive(bob().public_key().to_account_id(), u128::MAX)?;
+	let tx = client.tx().utility().force_batch(vec![&good, &bad]);
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+
+	let summary = events
+		.force_batch_summary()
+		.expect("force batch events should be present");
+	assert_eq!(summary.completed, 1);
+	assert_eq!(summary.errored_indices, vec![1]);
+
+	Ok(())
+}
+
+pub async fn cross_check_events_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().system().remark(b"cross check events".to_vec());
+	let receipt = tx
+		.submit_and_wait_for_receipt(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+	let block = client.block(receipt.block_hash);
+	assert!(block.events().cross_check().await?);
+
+	Ok(())
+}
+
+pub async fn future_nonce_test(client: &Client) -> Result<(), Error> {
+	let signer = ferdie();
+	let account_id = signer.public_key().to_account_id();
+	let nonce = client.best().account_nonce(account_id.clone()).await?;
+
+	let later = client.tx().system().remark(b"future nonce".to_vec());
+	let later = later.submit(&signer, Options::new().nonce(nonce + 1)).await?;
+
+	// The transaction waits in the pool while the nonce gap is open.
+	let height = client.finalized().block_height().await?;
+	client
+		.wait_for_height(height + 2, false, std::time::Duration::from_secs(60))
+		.await?;
+	assert_eq!(client.finalized().account_info(account_id.clone()).await?.nonce, nonce);
+
+	let first = client.tx().system().remark(b"gap filler".to_vec());
+	let first = first.submit(&signer, Options::new().nonce(nonce)).await?;
+	first.receipt(BlockQueryMode::Finalized).await?;
+	later.receipt(BlockQueryMode::Finalized).await?;
+	assert_eq!(client.finalized().account_info(account_id).await?.nonce, nonce + 2);
+
+	Ok(())
+}
+
+pub async fn dispatch_info_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::types::{pallets::system::types::Pays, substrate::DispatchClass};
+
+	let tx = client.tx().system().remark(b"dispatch info".to_vec());
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Finalized)
+		.await?;
+	let outcome = events.outcome().expect("outcome should be present");
+	let dispatch_info = outcome.dispatch_info();
+	assert_eq!(dispatch_info.class, DispatchClass::Normal);
+	assert_eq!(dispatch_info.pays_fee, Pays::Yes);
+	assert!(dispatch_info.weight.ref_time > 0);
+
+	Ok(())
+}
+
+pub async fn header_hash_test(client: &Client) -> Result<(), Error> {
+	let info = client.finalized().block_info().await?;
+	let header = client
+		.chain()
+		.block_header(Some(info.hash))
+		.await?
+		.expect("Finalized header should exist");
+	assert_eq!(header.number, info.height);
+	assert_eq!(header.hash(), info.hash);
+
+	Ok(())
+}
+
+pub async fn headers_range_test(client: &Client) -> Result<(), Error> {
+	let finalized = client.finalized().block_height().await?;
+	let start = finalized.saturating_sub(5);
+
+	let headers = client.chain().headers(start..=finalized).await?;
+	let numbers: Vec<u32> = headers.iter().map(|x| x.number).collect();
+	assert_eq!(numbers, (start..=finalized).collect::<Vec<_>>());
+	for pair in headers.windows(2) {
+		assert_eq!(pair[1].parent_hash, pair[0].hash());
+	}
+
+	Ok(())
+}
+
+pub async fn next_app_id_test(client: &Client) -> Result<(), Error> {
+	let next_app_id = client.data_availability().next_app_id(None).await?;
+	assert!(next_app_id > 0);
+	let keys = client.data_availability().all_app_keys(None).await?;
+	assert!(keys.iter().all(|(_, _, id)| *id < next_app_id));
+
+	Ok(())
+}
+
+pub async fn was_replaced_by_test(client: &Client) -> Result<(), Error> {
+	let signer = eve();
+	let nonce = client.best().account_nonce(signer.public_key().to_account_id()).await?;
+
+	let original = client.tx().system().remark(b"original".to_vec());
+	let original = original.submit(&signer, Options::new().nonce(nonce)).await?;
+	let replacement = client.tx().system().remark(b"replacement".to_vec());
+	let replacement = replacement
+		.submit(&signer, Options::new().nonce(nonce).tip(ONE_AVAIL))
+		.await?;
+
+	assert!(original.was_replaced_by(&replacement).await?);
+	assert!(!replacement.was_replaced_by(&original).await?);
+	let pending = client.chain().pending_extrinsic_hashes().await?;
+	assert!(!pending.contains(&original.ext_hash));
+
+	replacement.receipt(BlockQueryMode::Finalized).await?;
+
+	Ok(())
+}
+
+pub async fn force_set_balance_test(client: &Client) -> Result<(), Error> {
+	let who = AccountId::from([7u8; 32]);
+	let new_free = 1_000 * ONE_AVAIL;
+
+	let call = client.tx().balances().force_set_balance(who.clone(), new_free)?;
+	let tx = client.tx().sudo().sudo(&call)?;
+	let (_, events) = tx
+		.submit_and_wait_for_outcome(&alice(), Options::new(), BlockQueryMode::Best)
+		.await?;
+	let sudid = events.first::<avail::sudo::events::Sudid>().expect("Sudid event");
+	assert!(sudid.sudo_result.is_ok());
+
+	let balance = client.account().balance(who, BlockQueryMode::Best).await?;
+	assert_eq!(balance.free, new_free);
+
+	Ok(())
+}
+
+pub async fn legacy_blocks_with_events_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_client::AllowedEvents;
+
+	let mut sub = client
+		.subscribe()
+		.legacy_blocks_with_events(AllowedEvents::All)
+		.mode(BlockQueryMode::Best)
+		.build()
+		.await?;
+
+	let tx = client.tx().system().remark(b"blocks with events".to_vec());
+	let submitted = tx.submit(&alice(), Options::new()).await?;
+
+	loop {
+		let item = sub.next().await?;
+		let (block, events) = item.value.expect("Block should exist");
+		assert_eq!(block.block.header.hash(), item.block_hash);
+
+		let extrinsic_count = block.block.extrinsics.len() as u32;
+		let phases: Vec<u32> = events.iter().filter_map(|x| x.phase.extrinsic_index()).collect();
+		assert!(phases.iter().all(|x| *x < extrinsic_count));
+		for index in 0..extrinsic_count {
+			assert!(phases.contains(&index));
+		}
+
+		let position = block
+			.block
+			.extrinsics
+			.iter()
+			.position(|x| H256::from(avail_rust_core::ext::sp_crypto_hashing::blake2_256(x)) == submitted.ext_hash);
+		if position.is_some() {
+			break;
+		}
+	}
+
+	Ok(())
+}
+
+pub async fn estimate_fees_reports_dispatch_class_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::{avail::technical_committee, types::substrate::DispatchClass};
+
+	let normal = client.tx().system().remark(b"normal".to_vec());
+	let normal = normal.estimate_fees(None).await?;
+	assert_eq!(normal.dispatch_class, DispatchClass::Normal);
+	assert!(normal.final_fee() > 0);
+
+	let vote = technical_committee::tx::Vote { proposal: H256::repeat_byte(1), index: 0, approve: true };
+	let operational = SubmittableTransaction::from_encodable(client.clone(), vote);
+	let operational = operational.estimate_fees(None).await?;
+	assert_eq!(operational.dispatch_class, DispatchClass::Operational);
+	assert!(operational.final_fee() > 0);
+
+	Ok(())
+}
+
+pub async fn block_time_test(client: &Client) -> Result<(), Error> {
+	let block_time = client.chain().block_time()?;
+	assert_eq!(block_time, std::time::Duration::from_secs(20));
+
+	Ok(())
+}
+
+pub async fn receipt_race_test(client: &Client) -> Result<(), Error> {
+	let first = client.tx().system().remark(b"race one".to_vec());
+	let first = first.submit(&alice(), Options::new()).await?;
+	let second = client.tx().system().remark(b"race two".to_vec());
+	let second = second.submit(&bob(), Options::new()).await?;
+	let hashes = [first.ext_hash, second.ext_hash];
+
+	let (index, receipt) = TransactionReceipt::race(vec![first, second], BlockQueryMode::Best).await?;
+	assert!(index < 2);
+	assert_eq!(receipt.ext_hash, hashes[index]);
+
+	assert!(
+		TransactionReceipt::race(Vec::new(), BlockQueryMode::Best)
+			.await
+			.is_err()
+	);
+
+	Ok(())
+}
+
+pub async fn app_key_by_id_test(client: &Client) -> Result<(), Error> {
+	let da = client.data_availability();
+
+	let (key, _) = da
+		.app_key_by_id(0, None)
+		.await?
+		.expect("genesis app key should be present");
+	assert_eq!(key, "Avail");
+
+	let next_app_id = da.next_app_id(None).await?;
+	assert!(da.app_key_by_id(next_app_id, None).await?.is_none());
+
+	Ok(())
+}
+
+pub async fn extrinsic_call_as_subxt_payload_test(client: &Client) -> Result<(), Error> {
+	use avail_rust_core::{avail::data_availability::tx::SubmitData, subxt_core};
+
+	let metadata = client.online_client().metadata();
+
+	let call = ExtrinsicCall::from(&SubmitData { app_id: 2, data: b"through subxt".to_vec() });
+	subxt_core::tx::validate(&call, &metadata).unwrap();
+	assert_eq!(subxt_core::tx::call_data(&call, &metadata).unwrap(), call.0);
+
+	let unknown = ExtrinsicCall::from_parts(u8::MAX, 0, Vec::new());
+	assert!(subxt_core::tx::call_data(&unknown, &metadata).is_err());
+
+	Ok(())
+}
+
+pub async fn check_balance_test(client: &Client) -> Result<(), Error> {
+	let uri = SecretUri::from_str("//CheckBalanceUnderfunded").unwrap();
+	let underfunded = Keypair::from_uri(&uri).unwrap();
+
+	let tx = client
+		.tx()
+		.balances()
+		.transfer_keep_alive(bob().public_key().to_account_id(), ONE_AVAIL)?;
+	let check = tx.check_balance(&underfunded, Options::new()).await?;
+	assert_eq!(check.free, 0);
+	assert_eq!(check.value, ONE_AVAIL);
+	assert!(!check.is_sufficient());
+	assert_eq!(check.shortfall(), check.fee + ONE_AVAIL);
+
+	let check = tx.check_balance(&alice(), Options::new()).await?;
+	assert!(check.is_sufficient());
+
+	Ok(())
+}
+
+pub async fn signer_payload_test(client: &Client) -> Result<(), Error> {
+	let signer = alice();
+
+	let tx = client.tx().system().remark(b"signed elsewhere".to_vec());
+	let payload = tx
+		.signer_payload(signer.public_key().to_account_id(), Options::new())
+		.await?;
+	let signature = signer.sign(&payload.bytes()).0;
+
+	let bad = payload.clone().into_extrinsic([0u8; 64]);
+	assert!(matches!(bad, Err(Error::Validation(_))));
+
+	let extrinsic = payload.into_extrinsic(signature)?;
+	let ext_hash = client.chain().submit(&extrinsic).await?;
+	let finalized = client.finalized().block_height().await?;
+	let submitted = SubmittedTransaction::new(client.clone(), ext_hash, finalized, finalized + 32);
+	submitted.receipt(BlockQueryMode::Best).await?;
+
+	Ok(())
+}
+
+pub async fn data_cost_per_byte_test(client: &Client) -> Result<(), Error> {
+	let cost = client.data_availability().cost_per_byte(None).await?;
+	assert!(cost > 0);
+
+	Ok(())
+}
+
+pub async fn cancel_pending_transaction_test(client: &Client) -> Result<(), Error> {
+	let signer = ferdie();
+	let account_id = signer.public_key().to_account_id();
+	let nonce = client.best().account_nonce(account_id.clone()).await?;
+
+	let original = client.tx().system().remark(b"to be cancelled".to_vec());
+	let original = original.submit(&signer, Options::new().nonce(nonce)).await?;
+	let cancel = original.cancel(&signer, ONE_AVAIL).await?;
+	assert!(original.was_replaced_by(&cancel).await?);
+
+	let receipt = cancel.receipt(BlockQueryMode::Finalized).await?;
+	let extrinsic = receipt.extrinsic::<avail::balances::tx::TransferKeepAlive>().await?;
+	assert_eq!(extrinsic.nonce(), Some(nonce));
+	assert_eq!(extrinsic.tip(), Some(ONE_AVAIL));
+	assert_eq!(extrinsic.call.value, 0);
+	assert!(original.cancel(&signer, ONE_AVAIL).await.is_err());
+
+	Ok(())
+}
+
+pub async fn block_tx_index_test(client: &Client) -> Result<(), Error> {
+	let tx = client.tx().system().remark(b"find my index".to_vec());
+	let submitted = tx.submit(&bob(), Options::new()).await?;
+	let receipt = submitted.receipt(BlockQueryMode::Best).await?;
+
+	let block = client.block(receipt.block_hash);
+	assert_eq!(block.tx_index(submitted.ext_hash).await?, Some(receipt.ext_index));
+	assert_eq!(block.tx_index(H256::zero()).await?, None);
+
 	Ok(())
 }
@@ -1,5 +1,8 @@
-use crate::{HasHeader, types::metadata::StringOrBytes};
-use codec::{Decode, Encode};
+use crate::{
+	DecodeLimits, H256, HasHeader,
+	types::{metadata::StringOrBytes, substrate::RuntimePhase},
+};
+use codec::{Compact, Decode, Encode};
 use scale_value::{Composite, Value, ValueDef, scale::decode_as_type};
 use subxt_core::events::Phase;
 use subxt_metadata::{Metadata, StorageEntryType};
@@ -120,6 +123,48 @@ pub fn parse_encoded_events(metadata: &Metadata, mut raw_bytes: &[u8]) -> Option
 	Some(encoded_events)
 }
 
+/// Record of the `System::Events` storage with the event kept SCALE-encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawEventRecord {
+	pub phase: RuntimePhase,
+	/// Pallet index, variant index and the encoded event fields.
+	pub event: Vec<u8>,
+	pub topics: Vec<H256>,
+}
+
+/// Splits the raw `System::Events` storage value into its records.
+///
+/// The runtime event type from `metadata` is only used to find where each event ends.
+pub fn decode_event_records(metadata: &Metadata, raw_bytes: &[u8]) -> Result<Vec<RawEventRecord>, String> {
+	decode_event_records_with_limits(metadata, raw_bytes, &DecodeLimits::default())
+}
+
+/// Like [`decode_event_records`], rejecting a record count above `limits.max_vec_len` or above the
+/// number of remaining bytes before reserving space for the records.
+pub fn decode_event_records_with_limits(
+	metadata: &Metadata,
+	mut raw_bytes: &[u8],
+	limits: &DecodeLimits,
+) -> Result<Vec<RawEventRecord>, String> {
+	let event_ty = metadata.outer_enums().event_enum_ty();
+	let count = Compact::<u32>::decode(&mut raw_bytes).map_err(|e| e.to_string())?.0;
+	limits
+		.check_len(count as usize, Some(raw_bytes.len()))
+		.map_err(|e| e.to_string())?;
+
+	let mut records = Vec::with_capacity(count as usize);
+	for _ in 0..count {
+		let phase = RuntimePhase::decode(&mut raw_bytes).map_err(|e| e.to_string())?;
+		let start = raw_bytes;
+		decode_as_type(&mut raw_bytes, event_ty, metadata.types()).map_err(|e| e.to_string())?;
+		let event = start[..start.len() - raw_bytes.len()].to_vec();
+		let topics = Decode::decode(&mut raw_bytes).map_err(|e: codec::Error| e.to_string())?;
+		records.push(RawEventRecord { phase, event, topics });
+	}
+
+	Ok(records)
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct EncodedEvent {
 	pub encoded_phase: Option<Value<u32>>,
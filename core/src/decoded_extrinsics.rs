@@ -1,5 +1,5 @@
 use crate::{decoded_events::check_header, types::metadata::StringOrBytes};
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeLimit, Encode};
 
 pub trait HasHeader {
 	// Pallet ID, Variant ID
@@ -18,6 +18,71 @@ pub trait ExtrinsicDecodable: Sized {
 	fn from_ext<'a>(call: impl Into<StringOrBytes<'a>>) -> Result<Self, String>;
 }
 
+/// Reason a call could not be decoded as a specific call type.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+	#[error("Call is too short to contain a pallet and call index")]
+	MissingHeader,
+	#[error("Wrong call. Actual: PI: {}, VI: {} Expected: PI: {}, VI: {}", actual.0, actual.1, expected.0, expected.1)]
+	WrongCall { expected: (u8, u8), actual: (u8, u8) },
+	#[error("Failed to decode call data: {0}")]
+	Failed(String),
+	#[error("Decode limit exceeded: {0}")]
+	LimitExceeded(String),
+}
+
+/// Bounds applied when decoding untrusted block data such as extrinsics and event records.
+///
+/// Length prefixes above `max_vec_len` are rejected before anything is allocated, and typed calls
+/// nested deeper than `max_depth` fail instead of recursing further.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+	/// Largest accepted length prefix, in bytes for extrinsics and in items for event records.
+	pub max_vec_len: usize,
+	/// Deepest accepted nesting when decoding a call into a concrete type.
+	pub max_depth: u32,
+}
+
+impl DecodeLimits {
+	pub const DEFAULT_MAX_VEC_LEN: usize = 64 * 1024 * 1024;
+	pub const DEFAULT_MAX_DEPTH: u32 = 256;
+
+	/// Returns `LimitExceeded` when `len` is above `max_vec_len` or above the `remaining` input bytes.
+	pub fn check_len(&self, len: usize, remaining: Option<usize>) -> Result<(), DecodeError> {
+		if len > self.max_vec_len {
+			let msg = std::format!("Length prefix {} exceeds the maximum of {}", len, self.max_vec_len);
+			return Err(DecodeError::LimitExceeded(msg));
+		}
+		if let Some(remaining) = remaining.filter(|r| len > *r) {
+			let msg = std::format!("Length prefix {} exceeds the {} remaining input bytes", len, remaining);
+			return Err(DecodeError::LimitExceeded(msg));
+		}
+
+		Ok(())
+	}
+}
+
+impl Default for DecodeLimits {
+	fn default() -> Self {
+		Self {
+			max_vec_len: Self::DEFAULT_MAX_VEC_LEN,
+			max_depth: Self::DEFAULT_MAX_DEPTH,
+		}
+	}
+}
+
+pub(crate) fn try_decode_call<T: HasHeader + Decode>(call: &[u8], limits: &DecodeLimits) -> Result<T, DecodeError> {
+	let [pallet_id, variant_id, data @ ..] = call else {
+		return Err(DecodeError::MissingHeader);
+	};
+	let actual = (*pallet_id, *variant_id);
+	if actual != T::HEADER_INDEX {
+		return Err(DecodeError::WrongCall { expected: T::HEADER_INDEX, actual });
+	}
+
+	T::decode_with_depth_limit(limits.max_depth, &mut &data[..]).map_err(|e| DecodeError::Failed(e.to_string()))
+}
+
 impl<T: HasHeader + Encode> TransactionEncodable for T {
 	fn to_call(&self) -> Vec<u8> {
 		let pallet_id = Self::HEADER_INDEX.0;
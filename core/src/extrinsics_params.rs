@@ -29,6 +29,7 @@ pub type DefaultExtrinsicParams<T> = transaction_extensions::AnyOf<
 		transaction_extensions::CheckMortality<T>,
 		transaction_extensions::CheckNonce,
 		transaction_extensions::ChargeTransactionPayment,
+		transaction_extensions::CheckMetadataHash,
 	),
 >;
 
@@ -124,6 +125,6 @@ impl<T: Config> DefaultExtrinsicParamsBuilder<T> {
 			Some(x) => transaction_extensions::CheckNonceParams::with_nonce(x),
 			None => transaction_extensions::CheckNonceParams::from_chain(),
 		};
-		((), (), (), check_mortality_params, check_nonce_params, charge_transaction_params)
+		((), (), (), check_mortality_params, check_nonce_params, charge_transaction_params, ())
 	}
 }
@@ -6,8 +6,12 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 pub type AuthorityIndex = u64;
 pub type AuthorityWeight = u64;
 pub type AuthorityList = Vec<(AuthorityId, AuthorityWeight)>;
+pub type SetId = u64;
 
-#[derive(Debug, Default, Clone)]
+/// Index of the precommit variant in the GRANDPA vote message enum.
+const PRECOMMIT_MESSAGE_INDEX: u8 = 1;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct AuthorityId(pub [u8; 32]);
 pub type Public = AuthorityId;
 
@@ -142,6 +146,25 @@ impl Encode for SignedPrecommit {
 		self.id.encode_to(dest);
 	}
 }
+impl SignedPrecommit {
+	/// Returns the bytes the voter signed for this precommit in the given round and authority set.
+	pub fn signing_payload(&self, round: u64, set_id: SetId) -> Vec<u8> {
+		let mut payload = vec![PRECOMMIT_MESSAGE_INDEX];
+		self.precommit.encode_to(&mut payload);
+		round.encode_to(&mut payload);
+		set_id.encode_to(&mut payload);
+		payload
+	}
+
+	/// Checks the ed25519 signature of this precommit against its voter id.
+	pub fn verify_signature(&self, round: u64, set_id: SetId) -> bool {
+		let Ok(key) = ed25519_zebra::VerificationKey::try_from(self.id.0) else {
+			return false;
+		};
+		let signature = ed25519_zebra::Signature::from(self.signature.0);
+		key.verify(&signature, &self.signing_payload(round, set_id)).is_ok()
+	}
+}
 impl Decode for SignedPrecommit {
 	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
 		let precommit = Decode::decode(input)?;
@@ -189,6 +212,37 @@ impl Encode for GrandpaJustification {
 		self.votes_ancestries.encode_to(dest);
 	}
 }
+impl GrandpaJustification {
+	/// Verifies that the commit carries valid precommits from more than two thirds of the authority set weight.
+	///
+	/// Each voter is counted at most once, and precommits from ids outside `authorities` or with invalid
+	/// signatures are ignored. The ancestry between precommit targets and the commit target is not checked.
+	pub fn verify(&self, set_id: SetId, authorities: &AuthorityList) -> bool {
+		let total: u64 = authorities.iter().map(|(_, w)| *w).sum();
+		if total == 0 {
+			return false;
+		}
+		let threshold = total - (total - 1) / 3;
+
+		let mut seen: Vec<&AuthorityId> = Vec::new();
+		let mut signed: u64 = 0;
+		for precommit in &self.commit.precommits {
+			if seen.contains(&&precommit.id) {
+				continue;
+			}
+			let Some((_, weight)) = authorities.iter().find(|(id, _)| *id == precommit.id) else {
+				continue;
+			};
+			if !precommit.verify_signature(self.round, set_id) {
+				continue;
+			}
+			seen.push(&precommit.id);
+			signed += *weight;
+		}
+
+		signed >= threshold
+	}
+}
 impl Decode for GrandpaJustification {
 	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
 		let round = Decode::decode(input)?;
@@ -197,3 +251,47 @@ impl Decode for GrandpaJustification {
 		Ok(Self { round, commit, votes_ancestries })
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ed25519_zebra::{SigningKey, VerificationKey};
+
+	fn signed_precommit(seed: u8, precommit: &Precommit, round: u64, set_id: SetId) -> SignedPrecommit {
+		let key = SigningKey::from([seed; 32]);
+		let id = AuthorityId(VerificationKey::from(&key).into());
+		let mut value = SignedPrecommit {
+			precommit: precommit.clone(),
+			signature: Signature::default(),
+			id,
+		};
+		value.signature = Signature(key.sign(&value.signing_payload(round, set_id)).into());
+		value
+	}
+
+	#[test]
+	fn justification_verify_requires_supermajority() {
+		let precommit = Precommit { target_hash: H256::repeat_byte(3), target_number: 10 };
+		let (round, set_id) = (5, 2);
+		let signed: Vec<SignedPrecommit> = (1..=3)
+			.map(|s| signed_precommit(s, &precommit, round, set_id))
+			.collect();
+		let authorities: AuthorityList = signed.iter().map(|p| (p.id.clone(), 1)).collect();
+
+		let commit = Commit {
+			target_hash: precommit.target_hash,
+			target_number: 10,
+			precommits: signed.clone(),
+		};
+		let mut justification = GrandpaJustification { round, commit, votes_ancestries: Vec::new() };
+		assert!(justification.verify(set_id, &authorities));
+		assert!(!justification.verify(set_id + 1, &authorities));
+
+		justification.commit.precommits = vec![signed[0].clone(), signed[1].clone(), signed[1].clone()];
+		assert!(!justification.verify(set_id, &authorities));
+
+		justification.commit.precommits = signed.clone();
+		justification.commit.precommits[2].signature = Signature::default();
+		assert!(!justification.verify(set_id, &authorities));
+	}
+}
@@ -1,3 +1,4 @@
+use crate::{consensus::babe, types::AccountId};
 use codec::{Compact, Decode, Encode};
 use primitive_types::H256;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -27,9 +28,59 @@ impl AvailHeader {
 		}
 	}
 
+	/// KZG commitment data of this block, or `None` for Fri headers.
+	pub fn da_commitment(&self) -> Option<DaCommitmentInfo> {
+		match &self.extension {
+			HeaderExtension::Kzg(KzgHeader::V4(ext)) => Some(DaCommitmentInfo::from(&ext.commitment)),
+			HeaderExtension::Fri(_) => None,
+		}
+	}
+
+	/// Block hash of this header: the blake2-256 hash of its SCALE encoding.
+	///
+	/// Comparing it with the hash a node reports for the block confirms the header was not altered.
+	#[doc(alias = "compute_hash")]
 	pub fn hash(&self) -> H256 {
 		BlakeTwo256.hash_of(self)
 	}
+
+	/// BABE pre-runtime digest of this block, if present and decodable.
+	pub fn babe_pre_digest(&self) -> Option<babe::PreDigest> {
+		self.digest.logs.iter().find_map(|item| match item {
+			DigestItem::PreRuntime(id, value) if *id == babe::BABE_ENGINE_ID => {
+				babe::PreDigest::decode(&mut value.as_slice()).ok()
+			},
+			_ => None,
+		})
+	}
+
+	/// Maps the BABE slot author index to an entry of `validators`, the session validator set
+	/// active at this block.
+	pub fn author(&self, validators: &[AccountId]) -> Option<AccountId> {
+		let index = self.babe_pre_digest()?.authority_index();
+		validators.get(index as usize).cloned()
+	}
+}
+
+/// Commitment data of a KZG header: matrix size, row commitments and data root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaCommitmentInfo {
+	pub rows: u16,
+	pub cols: u16,
+	/// Concatenated 48-byte KZG commitments, one per extended row.
+	pub commitment: Vec<u8>,
+	pub data_root: H256,
+}
+
+impl From<&KateCommitment> for DaCommitmentInfo {
+	fn from(value: &KateCommitment) -> Self {
+		Self {
+			rows: value.rows,
+			cols: value.cols,
+			commitment: value.commitment.clone(),
+			data_root: value.data_root,
+		}
+	}
 }
 
 impl SubxtHeader for AvailHeader {
@@ -181,3 +232,30 @@ pub struct FriV1HeaderExtension {
 	pub data_root: H256,
 	pub params_version: FriParamsVersion,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn author_maps_babe_authority_index() {
+		let pre_digest = babe::PreDigest::SecondaryPlain(babe::SecondaryPlainPreDigest { authority_index: 1, slot: 5 });
+		let mut header = AvailHeader::default();
+		header
+			.digest
+			.logs
+			.push(DigestItem::PreRuntime(babe::BABE_ENGINE_ID, pre_digest.encode()));
+
+		let validators = [AccountId::from([1u8; 32]), AccountId::from([2u8; 32])];
+		assert_eq!(header.author(&validators), Some(validators[1].clone()));
+		assert_eq!(header.author(&validators[..1]), None);
+		assert_eq!(AvailHeader::default().author(&validators), None);
+	}
+
+	#[test]
+	fn hash_is_blake2_256_of_encoding() {
+		let header = AvailHeader { number: 42, ..Default::default() };
+		let expected = H256(sp_crypto_hashing::blake2_256(&header.encode()));
+		assert_eq!(header.hash(), expected);
+	}
+}
@@ -11,19 +11,20 @@ pub mod utils;
 
 pub use decoded_events::{TransactionEventDecodable, TransactionEventEncodable};
 
-pub use decoded_extrinsics::{ExtrinsicDecodable, HasHeader};
+pub use decoded_extrinsics::{DecodeError, DecodeLimits, ExtrinsicDecodable, HasHeader};
 pub use extrinsics_params::DefaultExtrinsicParams;
-pub use header::{AvailHeader, HeaderExtension, KateCommitment};
+pub use header::{AvailHeader, DaCommitmentInfo, HeaderExtension, KateCommitment};
 pub use rpc::{DataFormat, Error as RpcError};
 pub use substrate::{
-	Extension, ExtensionImplicit, Extrinsic, ExtrinsicBorrowed, ExtrinsicCall, ExtrinsicCallBorrowed, Preamble,
-	SignedPayload,
+	DecodedEra, DecodedExtensions, Extension, ExtensionImplicit, ExtensionImplicitWithMetadataHash,
+	ExtensionWithMetadataHash, Extrinsic, ExtrinsicBorrowed, ExtrinsicCall, ExtrinsicCallBorrowed, MetadataHashMode,
+	Preamble, SignedPayload,
 };
 pub use types::{
-	AccountId, AccountIdLike, BlakeTwo256, BlockHash, BlockInfo, Era, H256, HashNumber, MultiAddress, MultiSignature,
-	U256, pallets as avail,
+	AccountId, AccountIdLike, BlakeTwo256, BlockHash, BlockInfo, Era, EventPhase, H256, HashNumber, MultiAddress,
+	MultiSignature, U256, pallets as avail,
 };
-pub use utils::multi_account_id;
+pub use utils::{account_id_to_ss58, multi_account_id, multi_account_id_ss58, storage_prefix};
 
 pub use scale_info;
 pub use scale_value;
@@ -42,3 +42,11 @@ pub async fn submit_extrinsic(client: &RpcClient, extrinsic: &[u8]) -> Result<H2
 	let value: H256 = client.request("author_submitExtrinsic", params).await?;
 	Ok(value)
 }
+
+pub async fn pending_extrinsics(client: &RpcClient) -> Result<Vec<Vec<u8>>, Error> {
+	let value: Vec<String> = client.request("author_pendingExtrinsics", rpc_params![]).await?;
+	value
+		.iter()
+		.map(|x| const_hex::decode(x.trim_start_matches("0x")).map_err(Error::from))
+		.collect()
+}
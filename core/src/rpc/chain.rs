@@ -2,7 +2,7 @@ use super::{AvailHeader, Error};
 use primitive_types::H256;
 use serde::{Deserialize, Deserializer};
 use subxt_core::config::substrate::ConsensusEngineId;
-use subxt_rpcs::{RpcClient, rpc_params};
+use subxt_rpcs::{RpcClient, client::RpcSubscription, rpc_params};
 
 /// The response from `chain_getBlock`
 #[derive(Debug, Clone, Deserialize)]
@@ -66,3 +66,10 @@ pub async fn get_finalized_head(client: &RpcClient) -> Result<H256, Error> {
 	let value = client.request("chain_getFinalizedHead", rpc_params![]).await?;
 	Ok(value)
 }
+
+pub async fn subscribe_new_heads(client: &RpcClient) -> Result<RpcSubscription<AvailHeader>, Error> {
+	let value = client
+		.subscribe("chain_subscribeNewHeads", rpc_params![], "chain_unsubscribeNewHeads")
+		.await?;
+	Ok(value)
+}
@@ -11,9 +11,33 @@ pub async fn fetch_events(
 	allow_list: AllowedEvents,
 	fetch_data: bool,
 ) -> Result<Vec<PhaseEvents>, Error> {
+	// Pallet filtering is not understood by the node, so all events are requested and filtered here.
+	let pallet_id = match allow_list {
+		AllowedEvents::Pallet(x) => Some(x),
+		_ => None,
+	};
+	let allow_list = if pallet_id.is_some() {
+		AllowedEvents::All
+	} else {
+		allow_list
+	};
+
 	let params = rpc_params![at, allow_list, fetch_data];
 	let value: Vec<PhaseEvents> = client.request("custom_events", params).await?;
-	Ok(value)
+	match pallet_id {
+		Some(pallet_id) => Ok(filter_pallet_events(value, pallet_id)),
+		None => Ok(value),
+	}
+}
+
+fn filter_pallet_events(value: Vec<PhaseEvents>, pallet_id: u8) -> Vec<PhaseEvents> {
+	value
+		.into_iter()
+		.filter_map(|mut phase| {
+			phase.events.retain(|e| e.pallet_id == pallet_id);
+			(!phase.events.is_empty()).then_some(phase)
+		})
+		.collect()
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -24,6 +48,8 @@ pub enum AllowedEvents {
 	OnlyExtrinsics = 1,
 	OnlyNonExtrinsics = 2,
 	Only(Vec<u32>) = 3,
+	/// Only events emitted by the given pallet. Applied client-side on top of [`AllowedEvents::All`].
+	Pallet(u8) = 4,
 }
 
 impl From<u32> for AllowedEvents {
@@ -45,3 +71,32 @@ pub struct RuntimeEvent {
 	pub variant_id: u8,
 	pub data: String,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn event(index: u32, pallet_id: u8) -> RuntimeEvent {
+		RuntimeEvent { index, pallet_id, variant_id: 0, data: String::new() }
+	}
+
+	#[test]
+	fn filter_pallet_events_keeps_only_matching_pallet() {
+		let value = vec![
+			PhaseEvents {
+				phase: RuntimePhase::Initialization,
+				events: vec![event(0, 0)],
+			},
+			PhaseEvents {
+				phase: RuntimePhase::ApplyExtrinsic(1),
+				events: vec![event(1, 6), event(2, 0), event(3, 6)],
+			},
+		];
+
+		let filtered = filter_pallet_events(value, 6);
+		assert_eq!(filtered.len(), 1);
+		assert_eq!(filtered[0].phase, RuntimePhase::ApplyExtrinsic(1));
+		let indices: Vec<u32> = filtered[0].events.iter().map(|e| e.index).collect();
+		assert_eq!(indices, vec![1, 3]);
+	}
+}
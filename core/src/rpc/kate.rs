@@ -68,6 +68,24 @@ pub struct BlockLength {
 	pub chunk_size: u32,
 }
 
+/// Maximum data matrix of a block: row and column counts and the size of a cell in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockDimensions {
+	pub rows: u32,
+	pub cols: u32,
+	pub chunk_size: u32,
+}
+
+impl From<&BlockLength> for BlockDimensions {
+	fn from(value: &BlockLength) -> Self {
+		Self {
+			rows: value.rows,
+			cols: value.cols,
+			chunk_size: value.chunk_size,
+		}
+	}
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProofResponse {
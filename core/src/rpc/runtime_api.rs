@@ -1,5 +1,8 @@
 use super::Error;
-use crate::types::substrate::{FeeDetails, RuntimeDispatchInfo};
+use crate::{
+	grandpa::{AuthorityList, SetId},
+	types::substrate::{FeeDetails, RuntimeDispatchInfo},
+};
 use primitive_types::H256;
 use subxt_rpcs::RpcClient;
 
@@ -63,3 +66,11 @@ pub async fn api_transaction_payment_query_call_fee_details(
 
 	raw_call(client, "TransactionPaymentCallApi_query_call_fee_details", &call, at).await
 }
+
+pub async fn api_grandpa_authorities(client: &RpcClient, at: Option<H256>) -> Result<AuthorityList, Error> {
+	raw_call(client, "GrandpaApi_grandpa_authorities", &[], at).await
+}
+
+pub async fn api_grandpa_current_set_id(client: &RpcClient, at: Option<H256>) -> Result<SetId, Error> {
+	raw_call(client, "GrandpaApi_current_set_id", &[], at).await
+}
@@ -1,8 +1,9 @@
 use super::Error;
 use codec::Decode;
 use primitive_types::H256;
+use serde::Deserialize;
 use subxt_metadata::Metadata;
-use subxt_rpcs::{RpcClient, methods::legacy::RuntimeVersion, rpc_params};
+use subxt_rpcs::{RpcClient, client::RpcSubscription, methods::legacy::RuntimeVersion, rpc_params};
 
 pub async fn call(client: &RpcClient, method: &str, data: &[u8], at: Option<H256>) -> Result<String, Error> {
 	let data = std::format!("0x{}", const_hex::encode(data));
@@ -29,6 +30,40 @@ pub async fn get_storage(client: &RpcClient, key: &str, at: Option<H256>) -> Res
 	Ok(Some(value))
 }
 
+/// Storage values observed at a single block, as returned by `state_queryStorageAt` and notified by
+/// `state_subscribeStorage`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StorageChangeSet {
+	/// Block the values were read at.
+	pub block: H256,
+	/// Hex encoded storage keys paired with their hex encoded values.
+	pub changes: Vec<(String, Option<String>)>,
+}
+
+pub async fn query_storage_at(
+	client: &RpcClient,
+	keys: &[String],
+	at: Option<H256>,
+) -> Result<Vec<StorageChangeSet>, Error> {
+	let params = rpc_params![keys, at];
+	let value = client.request("state_queryStorageAt", params).await?;
+	Ok(value)
+}
+
+/// Subscribes to changes of the given storage keys, or of every key when `keys` is `None`.
+///
+/// Nodes only accept the `None` form when unsafe RPC methods are enabled.
+pub async fn subscribe_storage(
+	client: &RpcClient,
+	keys: Option<&[String]>,
+) -> Result<RpcSubscription<StorageChangeSet>, Error> {
+	let params = rpc_params![keys];
+	let value = client
+		.subscribe("state_subscribeStorage", params, "state_unsubscribeStorage")
+		.await?;
+	Ok(value)
+}
+
 pub async fn get_keys_paged(
 	client: &RpcClient,
 	prefix: Option<&str>,
@@ -1,12 +1,15 @@
 use crate::{
 	HasHeader,
+	decoded_extrinsics::{DecodeError, DecodeLimits, try_decode_call},
 	types::{AccountId, H256, MultiAddress, MultiSignature},
 	utils::decode_already_decoded,
 };
 use codec::{Compact, CountedInput, Decode, Encode, Input};
 use serde::{Deserialize, Serialize};
 use subxt_core::{
+	Metadata,
 	config::{Hasher, substrate::BlakeTwo256},
+	error::MetadataError,
 	utils::Era,
 };
 use subxt_signer::sr25519::Keypair;
@@ -24,8 +27,10 @@ pub const EXTENSION_VERSION: ExtensionVersion = 0;
 pub const LEGACY_EXTRINSIC_FORMAT_VERSION: ExtrinsicVersion = 4;
 pub const EXTRINSIC_FORMAT_VERSION: ExtrinsicVersion = 5;
 
+/// Extrinsic preamble, generic over the explicit extension data so runtimes that include
+/// `CheckMetadataHash` can use [`ExtensionWithMetadataHash`].
 #[derive(Clone, PartialEq, Eq)]
-pub enum Preamble {
+pub enum Preamble<E = Extension> {
 	/// An extrinsic without a signature or any extension. This means it's either an inherent or
 	/// an old-school "Unsigned" (we don't use that terminology any more since it's confusable with
 	/// the general transaction which is without a signature but does have an extension).
@@ -35,43 +40,47 @@ pub enum Preamble {
 	Bare(ExtrinsicVersion),
 	/// An old-school transaction extrinsic which includes a signature of some hard-coded crypto.
 	/// Available only on extrinsic version 4.
-	Signed(MultiAddress, MultiSignature, Extension),
+	Signed(MultiAddress, MultiSignature, E),
 	/// A new-school transaction extrinsic which does not include a signature by default. The
 	/// origin authorization, through signatures or other means, is performed by the transaction
 	/// extension in this extrinsic. Available starting with extrinsic version 5.
-	General(ExtensionVersion, Extension),
+	General(ExtensionVersion, E),
 }
 
 impl Decode for Preamble {
 	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
-		let version_and_type = input.read_byte()?;
+		decode_preamble(input)
+	}
+}
 
-		let version = version_and_type & VERSION_MASK;
-		let xt_type = version_and_type & TYPE_MASK;
+fn decode_preamble<E: Decode, I: codec::Input>(input: &mut I) -> Result<Preamble<E>, codec::Error> {
+	let version_and_type = input.read_byte()?;
 
-		let preamble = match (version, xt_type) {
-			(extrinsic_version @ LEGACY_EXTRINSIC_FORMAT_VERSION..=EXTRINSIC_FORMAT_VERSION, BARE_EXTRINSIC) => {
-				Self::Bare(extrinsic_version)
-			},
-			(LEGACY_EXTRINSIC_FORMAT_VERSION, SIGNED_EXTRINSIC) => {
-				let address = MultiAddress::decode(input)?;
-				let signature = MultiSignature::decode(input)?;
-				let ext = Extension::decode(input)?;
-				Self::Signed(address, signature, ext)
-			},
-			(EXTRINSIC_FORMAT_VERSION, GENERAL_EXTRINSIC) => {
-				let ext_version = ExtensionVersion::decode(input)?;
-				let ext = Extension::decode(input)?;
-				Self::General(ext_version, ext)
-			},
-			(_, _) => return Err("Invalid transaction version".into()),
-		};
+	let version = version_and_type & VERSION_MASK;
+	let xt_type = version_and_type & TYPE_MASK;
 
-		Ok(preamble)
-	}
+	let preamble = match (version, xt_type) {
+		(extrinsic_version @ LEGACY_EXTRINSIC_FORMAT_VERSION..=EXTRINSIC_FORMAT_VERSION, BARE_EXTRINSIC) => {
+			Preamble::Bare(extrinsic_version)
+		},
+		(LEGACY_EXTRINSIC_FORMAT_VERSION, SIGNED_EXTRINSIC) => {
+			let address = MultiAddress::decode(input)?;
+			let signature = MultiSignature::decode(input)?;
+			let ext = E::decode(input)?;
+			Preamble::Signed(address, signature, ext)
+		},
+		(EXTRINSIC_FORMAT_VERSION, GENERAL_EXTRINSIC) => {
+			let ext_version = ExtensionVersion::decode(input)?;
+			let ext = E::decode(input)?;
+			Preamble::General(ext_version, ext)
+		},
+		(_, _) => return Err("Invalid transaction version".into()),
+	};
+
+	Ok(preamble)
 }
 
-impl Encode for Preamble {
+impl<E: Encode> Encode for Preamble<E> {
 	fn size_hint(&self) -> usize {
 		match &self {
 			Preamble::Bare(_) => EXTRINSIC_FORMAT_VERSION.size_hint(),
@@ -107,9 +116,9 @@ impl Encode for Preamble {
 	}
 }
 
-impl Preamble {
+impl<E> Preamble<E> {
 	/// Returns `Some` if this is a signed extrinsic, together with the relevant inner fields.
-	pub fn to_signed(self) -> Option<(MultiAddress, MultiSignature, Extension)> {
+	pub fn to_signed(self) -> Option<(MultiAddress, MultiSignature, E)> {
 		match self {
 			Self::Signed(a, s, e) => Some((a, s, e)),
 			_ => None,
@@ -117,7 +126,7 @@ impl Preamble {
 	}
 
 	/// Returns `Some` if this is a signed extrinsic, together with the relevant inner fields.
-	pub fn to_signed_ref(&self) -> Option<(&MultiAddress, &MultiSignature, &Extension)> {
+	pub fn to_signed_ref(&self) -> Option<(&MultiAddress, &MultiSignature, &E)> {
 		match self {
 			Self::Signed(a, s, e) => Some((a, s, e)),
 			_ => None,
@@ -125,7 +134,7 @@ impl Preamble {
 	}
 }
 
-impl std::fmt::Debug for Preamble {
+impl<E: std::fmt::Debug> std::fmt::Debug for Preamble<E> {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		match self {
 			Self::Bare(_) => write!(f, "Bare"),
@@ -135,6 +144,36 @@ impl std::fmt::Debug for Preamble {
 	}
 }
 
+/// Mode byte of the `CheckMetadataHash` transaction extension for runtimes that include it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataHashMode {
+	/// Encoded as `0`; the signed payload carries no metadata hash.
+	Disabled,
+	/// Encoded as `1`; the signed payload carries the metadata hash of
+	/// [`ExtensionImplicitWithMetadataHash`].
+	Enabled,
+}
+
+impl Encode for MetadataHashMode {
+	fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+		let mode: u8 = match self {
+			Self::Disabled => 0,
+			Self::Enabled => 1,
+		};
+		mode.encode_to(dest);
+	}
+}
+
+impl Decode for MetadataHashMode {
+	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+		match input.read_byte()? {
+			0 => Ok(Self::Disabled),
+			1 => Ok(Self::Enabled),
+			_ => Err("Invalid CheckMetadataHash mode".into()),
+		}
+	}
+}
+
 #[derive(Debug, Clone, Encode, Decode, PartialEq, Eq)]
 pub struct Extension {
 	pub era: Era,
@@ -144,6 +183,88 @@ pub struct Extension {
 	pub tip: u128,
 }
 
+impl Extension {
+	pub fn new(era: Era, nonce: u32, tip: u128) -> Self {
+		Self { era, nonce, tip }
+	}
+
+	/// Returns a typed breakdown of the extensions, with the era split into its mortality parameters.
+	pub fn decoded_extensions(&self) -> DecodedExtensions {
+		DecodedExtensions {
+			era: DecodedEra::from(&self.era),
+			nonce: self.nonce,
+			tip: self.tip,
+			metadata_hash_mode: None,
+		}
+	}
+}
+
+/// Explicit extension data for runtimes that include `CheckMetadataHash`, which appends its mode byte
+/// after the regular fields.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq)]
+pub struct ExtensionWithMetadataHash {
+	pub extension: Extension,
+	pub mode: MetadataHashMode,
+}
+
+impl ExtensionWithMetadataHash {
+	pub fn new(extension: Extension, mode: MetadataHashMode) -> Self {
+		Self { extension, mode }
+	}
+
+	/// Same as [`Extension::decoded_extensions`], with the `CheckMetadataHash` mode filled in.
+	pub fn decoded_extensions(&self) -> DecodedExtensions {
+		DecodedExtensions {
+			metadata_hash_mode: Some(self.mode),
+			..self.extension.decoded_extensions()
+		}
+	}
+}
+
+/// Typed view of the explicit extensions of a signed extrinsic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodedExtensions {
+	pub era: DecodedEra,
+	pub nonce: u32,
+	pub tip: u128,
+	/// `None` when the runtime does not include `CheckMetadataHash`.
+	pub metadata_hash_mode: Option<MetadataHashMode>,
+}
+
+/// Transaction era in `{mortal, period, phase}` form; `period` and `phase` are zero when immortal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodedEra {
+	pub mortal: bool,
+	pub period: u64,
+	pub phase: u64,
+}
+
+impl DecodedEra {
+	/// Returns the block the transaction became valid at, given any block `current` inside its window.
+	///
+	/// Returns `None` for immortal transactions.
+	pub fn birth(&self, current: u64) -> Option<u64> {
+		if !self.mortal {
+			return None;
+		}
+		Some((current.max(self.phase) - self.phase) / self.period * self.period + self.phase)
+	}
+
+	/// Returns the first block at which the transaction is no longer valid, or `None` if immortal.
+	pub fn death(&self, current: u64) -> Option<u64> {
+		Some(self.birth(current)? + self.period)
+	}
+}
+
+impl From<&Era> for DecodedEra {
+	fn from(value: &Era) -> Self {
+		match value {
+			Era::Immortal => Self { mortal: false, period: 0, phase: 0 },
+			Era::Mortal { period, phase } => Self { mortal: true, period: *period, phase: *phase },
+		}
+	}
+}
+
 #[derive(Debug, Clone)]
 pub struct ExtrinsicCall(pub Vec<u8>);
 
@@ -167,6 +288,16 @@ impl ExtrinsicCall {
 	pub fn hash(&self) -> [u8; 32] {
 		sp_crypto_hashing::blake2_256(&self.0)
 	}
+
+	/// Decodes the call as `T`, reporting whether the header did not match or the data was malformed.
+	pub fn try_decode<T: HasHeader + Decode>(&self) -> Result<T, DecodeError> {
+		self.try_decode_with_limits(&DecodeLimits::default())
+	}
+
+	/// Like [`ExtrinsicCall::try_decode`], failing once the call nests deeper than `limits.max_depth`.
+	pub fn try_decode_with_limits<T: HasHeader + Decode>(&self, limits: &DecodeLimits) -> Result<T, DecodeError> {
+		try_decode_call(&self.0, limits)
+	}
 }
 
 impl Encode for ExtrinsicCall {
@@ -215,6 +346,26 @@ impl TryFrom<&str> for ExtrinsicCall {
 	}
 }
 
+/// Lets SDK-built calls go through subxt's transaction machinery.
+///
+/// The encoded call is used as is; encoding only checks that the metadata knows its pallet and call.
+impl subxt_core::tx::payload::Payload for ExtrinsicCall {
+	fn encode_call_data_to(&self, metadata: &Metadata, out: &mut Vec<u8>) -> Result<(), subxt_core::Error> {
+		let (Some(pallet_id), Some(variant_id)) = (self.0.first(), self.0.get(1)) else {
+			return Err(subxt_core::Error::Codec("Call is missing its pallet and variant index".into()));
+		};
+		let pallet = metadata
+			.pallet_by_index(*pallet_id)
+			.ok_or(MetadataError::PalletIndexNotFound(*pallet_id))?;
+		pallet
+			.call_variant_by_index(*variant_id)
+			.ok_or(MetadataError::VariantIndexNotFound(*variant_id))?;
+
+		out.extend_from_slice(&self.0);
+		Ok(())
+	}
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ExtrinsicCallBorrowed<'a>(pub &'a [u8]);
 
@@ -230,6 +381,11 @@ impl<'a> ExtrinsicCallBorrowed<'a> {
 	pub fn hash(&self) -> [u8; 32] {
 		sp_crypto_hashing::blake2_256(self.0)
 	}
+
+	/// See [`ExtrinsicCall::try_decode`].
+	pub fn try_decode<T: HasHeader + Decode>(&self) -> Result<T, DecodeError> {
+		try_decode_call(self.0, &DecodeLimits::default())
+	}
 }
 
 impl<'a> Encode for ExtrinsicCallBorrowed<'a> {
@@ -238,7 +394,7 @@ impl<'a> Encode for ExtrinsicCallBorrowed<'a> {
 	}
 }
 
-fn encode_extrinsic(preamble: &Preamble, call: &[u8]) -> Vec<u8> {
+fn encode_extrinsic<E: Encode>(preamble: &Preamble<E>, call: &[u8]) -> Vec<u8> {
 	let mut tmp = preamble.encode();
 	tmp.extend_from_slice(call);
 
@@ -252,8 +408,8 @@ fn encode_extrinsic(preamble: &Preamble, call: &[u8]) -> Vec<u8> {
 }
 
 #[derive(Debug, Clone)]
-pub struct Extrinsic {
-	pub preamble: Preamble,
+pub struct Extrinsic<E = Extension> {
+	pub preamble: Preamble<E>,
 	pub call: ExtrinsicCall,
 }
 
@@ -262,22 +418,24 @@ impl Extrinsic {
 		Self { preamble, call }
 	}
 
-	pub fn new_signed(account_id: AccountId, signature: [u8; 64], extension: Extension, call: ExtrinsicCall) -> Self {
-		let address = MultiAddress::Id(account_id);
-		let signature = MultiSignature::Sr25519(signature);
-		let preamble = Preamble::Signed(address, signature, extension);
+	pub fn new_bare(extrinsic_version: ExtrinsicVersion, call: ExtrinsicCall) -> Self {
+		let preamble = Preamble::Bare(extrinsic_version);
 
 		Self { preamble, call }
 	}
+}
 
-	pub fn new_general(extension_version: ExtensionVersion, extension: Extension, call: ExtrinsicCall) -> Self {
-		let preamble = Preamble::General(extension_version, extension);
+impl<E: Encode> Extrinsic<E> {
+	pub fn new_signed(account_id: AccountId, signature: [u8; 64], extension: E, call: ExtrinsicCall) -> Self {
+		let address = MultiAddress::Id(account_id);
+		let signature = MultiSignature::Sr25519(signature);
+		let preamble = Preamble::Signed(address, signature, extension);
 
 		Self { preamble, call }
 	}
 
-	pub fn new_bare(extrinsic_version: ExtrinsicVersion, call: ExtrinsicCall) -> Self {
-		let preamble = Preamble::Bare(extrinsic_version);
+	pub fn new_general(extension_version: ExtensionVersion, extension: E, call: ExtrinsicCall) -> Self {
+		let preamble = Preamble::General(extension_version, extension);
 
 		Self { preamble, call }
 	}
@@ -314,38 +472,68 @@ impl TryFrom<&[u8]> for Extrinsic {
 	}
 }
 
-impl Encode for Extrinsic {
+impl<E: Encode> Encode for Extrinsic<E> {
 	fn encode(&self) -> Vec<u8> {
 		encode_extrinsic(&self.preamble, &self.call.0)
 	}
 }
 
+impl Extrinsic {
+	/// Decodes an extrinsic from untrusted bytes, rejecting a length prefix above `limits.max_vec_len`
+	/// or past the end of `bytes` before allocating for it.
+	pub fn decode_with_limits(bytes: &[u8], limits: &DecodeLimits) -> Result<Self, DecodeError> {
+		let mut prefix = bytes;
+		let length = Compact::<u32>::decode(&mut prefix).map_err(|e| DecodeError::Failed(e.to_string()))?;
+		limits.check_len(length.0 as usize, Some(prefix.len()))?;
+
+		decode_extrinsic(&mut &bytes[..], limits).map_err(|e| DecodeError::Failed(e.to_string()))
+	}
+}
+
+impl Extrinsic<ExtensionWithMetadataHash> {
+	/// Decodes an extrinsic of a runtime that includes `CheckMetadataHash`, reading the mode byte that
+	/// follows the regular extension data.
+	pub fn decode_with_metadata_hash(bytes: &[u8]) -> Result<Self, codec::Error> {
+		decode_extrinsic(&mut &bytes[..], &DecodeLimits::default())
+	}
+}
+
 impl Decode for Extrinsic {
 	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
-		// This is a little more complicated than usual since the binary format must be compatible
-		// with SCALE's generic `Vec<u8>` type. Basically this just means accepting that there
-		// will be a prefix of vector length.
-		let expected_length: Compact<u32> = Decode::decode(input)?;
-		let expected_length = expected_length.0 as usize;
-		let mut input = CountedInput::new(input);
-
-		let preamble: Preamble = Decode::decode(&mut input)?;
-
-		let call_length = expected_length
-			.checked_sub(input.count() as usize)
-			.ok_or("Preamble bytes exceed expected extrinsic length")?;
-		let mut call = vec![0u8; call_length];
-		input.read(&mut call)?;
-
-		if input.count() as usize != expected_length {
-			return Err("Invalid length prefix".into());
-		}
+		decode_extrinsic(input, &DecodeLimits::default())
+	}
+}
 
-		Ok(Self { preamble, call: ExtrinsicCall::new(call) })
+fn decode_extrinsic<E: Decode, I: codec::Input>(
+	input: &mut I,
+	limits: &DecodeLimits,
+) -> Result<Extrinsic<E>, codec::Error> {
+	// This is a little more complicated than usual since the binary format must be compatible
+	// with SCALE's generic `Vec<u8>` type. Basically this just means accepting that there
+	// will be a prefix of vector length.
+	let expected_length: Compact<u32> = Decode::decode(input)?;
+	let expected_length = expected_length.0 as usize;
+	if limits.check_len(expected_length, input.remaining_len()?).is_err() {
+		return Err("Extrinsic length prefix exceeds decode limits".into());
 	}
+	let mut input = CountedInput::new(input);
+
+	let preamble = decode_preamble(&mut input)?;
+
+	let call_length = expected_length
+		.checked_sub(input.count() as usize)
+		.ok_or("Preamble bytes exceed expected extrinsic length")?;
+	let mut call = vec![0u8; call_length];
+	input.read(&mut call)?;
+
+	if input.count() as usize != expected_length {
+		return Err("Invalid length prefix".into());
+	}
+
+	Ok(Extrinsic { preamble, call: ExtrinsicCall::new(call) })
 }
 
-impl Serialize for Extrinsic {
+impl<E: Encode> Serialize for Extrinsic<E> {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: serde::Serializer,
@@ -366,8 +554,8 @@ impl<'de> Deserialize<'de> for Extrinsic {
 }
 
 #[derive(Debug, Clone)]
-pub struct ExtrinsicBorrowed<'a> {
-	pub preamble: Preamble,
+pub struct ExtrinsicBorrowed<'a, E = Extension> {
+	pub preamble: Preamble<E>,
 	pub call: ExtrinsicCallBorrowed<'a>,
 }
 
@@ -376,22 +564,24 @@ impl<'a> ExtrinsicBorrowed<'a> {
 		Self { preamble, call: ExtrinsicCallBorrowed::new(call) }
 	}
 
-	pub fn new_signed(account_id: AccountId, signature: [u8; 64], extension: Extension, call: &'a [u8]) -> Self {
-		let address = MultiAddress::Id(account_id);
-		let signature = MultiSignature::Sr25519(signature);
-		let preamble = Preamble::Signed(address, signature, extension);
+	pub fn new_bare(extrinsic_version: ExtrinsicVersion, call: &'a [u8]) -> Self {
+		let preamble = Preamble::Bare(extrinsic_version);
 
 		Self { preamble, call: ExtrinsicCallBorrowed::new(call) }
 	}
+}
 
-	pub fn new_general(extension_version: ExtensionVersion, extension: Extension, call: &'a [u8]) -> Self {
-		let preamble = Preamble::General(extension_version, extension);
+impl<'a, E: Encode> ExtrinsicBorrowed<'a, E> {
+	pub fn new_signed(account_id: AccountId, signature: [u8; 64], extension: E, call: &'a [u8]) -> Self {
+		let address = MultiAddress::Id(account_id);
+		let signature = MultiSignature::Sr25519(signature);
+		let preamble = Preamble::Signed(address, signature, extension);
 
 		Self { preamble, call: ExtrinsicCallBorrowed::new(call) }
 	}
 
-	pub fn new_bare(extrinsic_version: ExtrinsicVersion, call: &'a [u8]) -> Self {
-		let preamble = Preamble::Bare(extrinsic_version);
+	pub fn new_general(extension_version: ExtensionVersion, extension: E, call: &'a [u8]) -> Self {
+		let preamble = Preamble::General(extension_version, extension);
 
 		Self { preamble, call: ExtrinsicCallBorrowed::new(call) }
 	}
@@ -402,7 +592,7 @@ impl<'a> ExtrinsicBorrowed<'a> {
 	}
 }
 
-impl<'a> Encode for ExtrinsicBorrowed<'a> {
+impl<'a, E: Encode> Encode for ExtrinsicBorrowed<'a, E> {
 	fn encode(&self) -> Vec<u8> {
 		encode_extrinsic(&self.preamble, self.call.0)
 	}
@@ -415,6 +605,11 @@ pub struct ExtensionImplicit {
 	pub genesis_hash: H256,
 	pub fork_hash: H256,
 }
+impl ExtensionImplicit {
+	pub fn new(spec_version: u32, tx_version: u32, genesis_hash: H256, fork_hash: H256) -> Self {
+		Self { spec_version, tx_version, genesis_hash, fork_hash }
+	}
+}
 impl Encode for ExtensionImplicit {
 	fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
 		self.spec_version.encode_to(dest);
@@ -433,30 +628,54 @@ impl Decode for ExtensionImplicit {
 	}
 }
 
+/// Implicit data for runtimes that include `CheckMetadataHash`, signed alongside
+/// [`ExtensionWithMetadataHash`].
+///
+/// `metadata_hash` is the RFC-78 merkleized metadata hash of the runtime and must be `Some` exactly
+/// when the mode is [`MetadataHashMode::Enabled`].
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct ExtensionImplicitWithMetadataHash {
+	pub implicit: ExtensionImplicit,
+	pub metadata_hash: Option<H256>,
+}
+
+impl ExtensionImplicitWithMetadataHash {
+	pub fn new(implicit: ExtensionImplicit, metadata_hash: Option<H256>) -> Self {
+		Self { implicit, metadata_hash }
+	}
+}
+
 // There is no need for Encode and Decode
 #[derive(Debug, Clone)]
-pub struct SignedPayload<'a> {
+pub struct SignedPayload<'a, E = Extension, I = ExtensionImplicit> {
 	/// Already encoded call
 	pub call: &'a [u8],
-	pub extension: &'a Extension,
-	pub implicit: &'a ExtensionImplicit,
+	pub extension: &'a E,
+	pub implicit: &'a I,
 }
 
-impl<'a> SignedPayload<'a> {
-	pub fn new(call: &'a [u8], extension: &'a Extension, implicit: &'a ExtensionImplicit) -> Self {
+impl<'a, E: Encode, I: Encode> SignedPayload<'a, E, I> {
+	pub fn new(call: &'a [u8], extension: &'a E, implicit: &'a I) -> Self {
 		Self { call, extension, implicit }
 	}
 
-	pub fn sign_static(
-		call: &'a [u8],
-		extension: &'a Extension,
-		implicit: &'a ExtensionImplicit,
-		signer: &Keypair,
-	) -> [u8; 64] {
+	pub fn sign_static(call: &'a [u8], extension: &'a E, implicit: &'a I, signer: &Keypair) -> [u8; 64] {
 		Self { call, extension, implicit }.sign(signer)
 	}
 
 	pub fn sign(&self, signer: &Keypair) -> [u8; 64] {
+		signer.sign(&self.message()).0
+	}
+
+	/// Checks that `signature` is a valid sr25519 signature of this payload by `account_id`.
+	pub fn verify(&self, signature: &[u8; 64], account_id: &AccountId) -> bool {
+		let signature = subxt_signer::sr25519::Signature(*signature);
+		let public_key = subxt_signer::sr25519::PublicKey(account_id.0);
+		subxt_signer::sr25519::verify(&signature, self.message(), &public_key)
+	}
+
+	/// Returns the bytes that get signed: the encoded payload, or its hash when longer than 256 bytes.
+	pub fn message(&self) -> Vec<u8> {
 		let size_hint = self.call.size_hint() + self.extension.size_hint() + self.implicit.size_hint();
 
 		let mut data: Vec<u8> = Vec::with_capacity(size_hint);
@@ -465,10 +684,126 @@ impl<'a> SignedPayload<'a> {
 		self.implicit.encode_to(&mut data);
 
 		if data.len() > 256 {
-			let hash = BlakeTwo256.hash(&data);
-			signer.sign(hash.as_ref()).0
+			BlakeTwo256.hash(&data).0.to_vec()
 		} else {
-			signer.sign(&data).0
+			data
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn extension_encodes_metadata_hash_mode() {
+		let extension = Extension::new(Era::Immortal, 1, 0);
+		let without = extension.encode();
+		assert_eq!(Extension::decode(&mut without.as_slice()).unwrap(), extension);
+
+		for mode in [MetadataHashMode::Disabled, MetadataHashMode::Enabled] {
+			let extension = ExtensionWithMetadataHash::new(extension.clone(), mode);
+			let with = extension.encode();
+			assert_eq!(with[..without.len()], without[..]);
+			assert_eq!(with[without.len()..], mode.encode()[..]);
+			assert_eq!(ExtensionWithMetadataHash::decode(&mut with.as_slice()).unwrap(), extension);
+			assert_eq!(extension.decoded_extensions().metadata_hash_mode, Some(mode));
+		}
+		assert!(MetadataHashMode::decode(&mut [2u8].as_slice()).is_err());
+
+		let hash = H256::repeat_byte(9);
+		let implicit = ExtensionImplicit::new(1, 1, H256::zero(), H256::zero());
+		let without = implicit.encode();
+		let enabled = ExtensionImplicitWithMetadataHash::new(implicit.clone(), Some(hash));
+		assert_eq!(enabled.encode()[without.len()..], Some(hash).encode()[..]);
+		let disabled = ExtensionImplicitWithMetadataHash::new(implicit, None);
+		assert_eq!(disabled.encode()[without.len()..], [0u8]);
+	}
+
+	#[test]
+	fn extrinsic_with_metadata_hash_decodes_aligned() {
+		let call = ExtrinsicCall::from(&crate::avail::system::tx::Remark { remark: b"aligned".to_vec() });
+		let extension = ExtensionWithMetadataHash::new(Extension::new(Era::Immortal, 7, 0), MetadataHashMode::Enabled);
+		let encoded =
+			ExtrinsicBorrowed::new_signed(AccountId::from([1u8; 32]), [0u8; 64], extension.clone(), &call.0).encode();
+
+		let decoded = Extrinsic::decode_with_metadata_hash(&encoded).unwrap();
+		let Preamble::Signed(_, _, decoded_extension) = decoded.preamble else {
+			panic!("Expected a signed extrinsic");
+		};
+		assert_eq!(decoded_extension, extension);
+		assert_eq!(decoded.call.0, call.0);
+
+		let plain = ExtrinsicBorrowed::new_signed(AccountId::from([1u8; 32]), [0u8; 64], extension.extension, &call.0);
+		let decoded = Extrinsic::decode(&mut plain.encode().as_slice()).unwrap();
+		assert_eq!(decoded.call.0, call.0);
+	}
+
+	#[test]
+	fn decoded_extensions_split_mortal_era() {
+		let extension = Extension::new(Era::mortal(64, 1000), 3, 5);
+		let decoded = Extension::decode(&mut extension.encode().as_slice()).unwrap();
+
+		let decoded = decoded.decoded_extensions();
+		assert_eq!(decoded.era, DecodedEra { mortal: true, period: 64, phase: 40 });
+		assert_eq!((decoded.nonce, decoded.tip), (3, 5));
+		assert_eq!(decoded.era.birth(1010), Some(1000));
+		assert_eq!(decoded.era.death(1010), Some(1064));
+
+		let immortal = Extension::new(Era::Immortal, 0, 0);
+		let era = immortal.decoded_extensions().era;
+		assert!(!era.mortal);
+		assert_eq!(era.death(1010), None);
+	}
+
+	#[test]
+	fn call_try_decode_reports_failure_kind() {
+		use crate::avail::{data_availability::tx::SubmitData, system::tx::Remark};
+
+		let remark = Remark { remark: b"hello".to_vec() };
+		let call = ExtrinsicCall::from(&remark);
+		assert_eq!(call.try_decode::<Remark>().unwrap().remark, remark.remark);
+
+		let err = call.try_decode::<SubmitData>().unwrap_err();
+		assert_eq!(
+			err,
+			DecodeError::WrongCall {
+				expected: SubmitData::HEADER_INDEX,
+				actual: Remark::HEADER_INDEX
+			}
+		);
+
+		let truncated = ExtrinsicCall::new(call.0[..3].to_vec());
+		assert!(matches!(truncated.try_decode::<Remark>(), Err(DecodeError::Failed(_))));
+		assert_eq!(ExtrinsicCall::new(vec![0]).try_decode::<Remark>().unwrap_err(), DecodeError::MissingHeader);
+	}
+
+	#[test]
+	fn decode_limits_reject_oversized_length_prefix() {
+		use crate::avail::{nomination_pools::tx::Nominate, system::tx::Remark};
+
+		let mut crafted = Compact(u32::MAX).encode();
+		crafted.extend_from_slice(&[4, 0, 0]);
+		assert!(Extrinsic::decode(&mut crafted.as_slice()).is_err());
+		let err = Extrinsic::decode_with_limits(&crafted, &DecodeLimits::default()).unwrap_err();
+		assert!(matches!(err, DecodeError::LimitExceeded(_)));
+
+		let call = ExtrinsicCall::from(&Remark { remark: vec![1u8; 64] });
+		let encoded = Extrinsic::new(Preamble::Bare(4), call.clone()).encode();
+		let decoded = Extrinsic::decode_with_limits(&encoded, &DecodeLimits::default()).unwrap();
+		assert_eq!(decoded.call.0, call.0);
+
+		let limits = DecodeLimits { max_vec_len: 16, ..Default::default() };
+		let err = Extrinsic::decode_with_limits(&encoded, &limits).unwrap_err();
+		assert!(matches!(err, DecodeError::LimitExceeded(_)));
+
+		let nominate = Nominate { pool_id: 1, validators: vec![AccountId::from([1u8; 32])] };
+		let call = ExtrinsicCall::from(&nominate);
+		assert!(
+			call.try_decode_with_limits::<Nominate>(&DecodeLimits::default())
+				.is_ok()
+		);
+		let limits = DecodeLimits { max_depth: 0, ..Default::default() };
+		assert!(matches!(call.try_decode_with_limits::<Nominate>(&limits), Err(DecodeError::Failed(_))));
+	}
+}
@@ -4,8 +4,9 @@ pub mod storage;
 
 // Some Exports
 pub use extrinsic::{
-	EXTRINSIC_FORMAT_VERSION, Extension, ExtensionImplicit, Extrinsic, ExtrinsicBorrowed, ExtrinsicCall,
-	ExtrinsicCallBorrowed, Preamble, SignedPayload,
+	DecodedEra, DecodedExtensions, EXTRINSIC_FORMAT_VERSION, Extension, ExtensionImplicit,
+	ExtensionImplicitWithMetadataHash, ExtensionWithMetadataHash, Extrinsic, ExtrinsicBorrowed, ExtrinsicCall,
+	ExtrinsicCallBorrowed, MetadataHashMode, Preamble, SignedPayload,
 };
 pub use storage::{
 	StorageDoubleMap, StorageDoubleMapIterator, StorageHasher, StorageMap, StorageMapIterator, StorageValue,
@@ -68,13 +68,7 @@ pub trait StorageValue {
 	type VALUE: codec::Decode;
 
 	fn encode_storage_key() -> [u8; 32] {
-		use sp_crypto_hashing::twox_128;
-
-		let mut encoded_storage_key = [0u8; 32];
-		encoded_storage_key[0..16].copy_from_slice(&twox_128(Self::PALLET_NAME.as_bytes()));
-		encoded_storage_key[16..].copy_from_slice(&twox_128(Self::STORAGE_NAME.as_bytes()));
-
-		encoded_storage_key
+		crate::utils::storage_prefix(Self::PALLET_NAME, Self::STORAGE_NAME)
 	}
 
 	fn hex_encode_storage_key() -> String {
@@ -129,13 +123,7 @@ pub trait StorageMap {
 	type VALUE: codec::Decode;
 
 	fn encode_partial_key() -> [u8; 32] {
-		use sp_crypto_hashing::twox_128;
-
-		let mut encoded_storage_key = [0u8; 32];
-		encoded_storage_key[0..16].copy_from_slice(&twox_128(Self::PALLET_NAME.as_bytes()));
-		encoded_storage_key[16..].copy_from_slice(&twox_128(Self::STORAGE_NAME.as_bytes()));
-
-		encoded_storage_key
+		crate::utils::storage_prefix(Self::PALLET_NAME, Self::STORAGE_NAME)
 	}
 
 	fn hex_encode_partial_key() -> String {
@@ -222,6 +210,20 @@ pub trait StorageMap {
 		}
 	}
 
+	/// Fetches and decodes a Storage Value
+	///
+	/// Returns the default value if no Storage Value is present
+	fn fetch_or_default(
+		client: &RpcClient,
+		key: &Self::KEY,
+		at: Option<H256>,
+	) -> impl std::future::Future<Output = Result<Self::VALUE, Error>>
+	where
+		Self::VALUE: Default,
+	{
+		async move { Ok(Self::fetch(client, key, at).await?.unwrap_or_default()) }
+	}
+
 	fn iter(client: RpcClient, block_hash: H256) -> StorageMapIterator<Self>
 	where
 		Self: Sized,
@@ -240,11 +242,8 @@ pub trait StorageDoubleMap {
 	type VALUE: codec::Decode;
 
 	fn encode_partial_key(key1: &Self::KEY1) -> Vec<u8> {
-		use sp_crypto_hashing::twox_128;
-
 		let mut encoded_storage_key = Vec::new();
-		encoded_storage_key.extend_from_slice(&twox_128(Self::PALLET_NAME.as_bytes()));
-		encoded_storage_key.extend_from_slice(&twox_128(Self::STORAGE_NAME.as_bytes()));
+		encoded_storage_key.extend_from_slice(&crate::utils::storage_prefix(Self::PALLET_NAME, Self::STORAGE_NAME));
 		encoded_storage_key.extend_from_slice(&Self::KEY1_HASHER.hash(&key1.encode()));
 
 		encoded_storage_key
@@ -36,6 +36,25 @@ impl From<BlockInfo> for H256 {
 	}
 }
 
+impl From<BlockInfo> for (H256, u32) {
+	fn from(value: BlockInfo) -> Self {
+		(value.hash, value.height)
+	}
+}
+
+impl TryFrom<HashNumber> for BlockInfo {
+	type Error = String;
+
+	/// Succeeds only for [`HashNumber::HashAndNumber`]; a lone hash or height is not enough.
+	fn try_from(value: HashNumber) -> Result<Self, Self::Error> {
+		match value {
+			HashNumber::HashAndNumber(x) => Ok(Self::from(x)),
+			HashNumber::Hash(_) => Err(std::format!("Cannot build block info from {}: height is unknown", value)),
+			HashNumber::Number(_) => Err(std::format!("Cannot build block info from {}: hash is unknown", value)),
+		}
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HashNumber {
 	Hash(H256),
@@ -383,3 +402,26 @@ impl From<&str> for AccountIdLike {
 		Self::BoxedString(value.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn block_info_hash_number_conversions() {
+		let hash = H256::repeat_byte(3);
+		let info = BlockInfo { hash, height: 7 };
+
+		assert_eq!(BlockInfo::from((hash, 7)), info);
+		assert_eq!(<(H256, u32)>::from(info), (hash, 7));
+		assert_eq!(H256::from(info), hash);
+
+		let hash_number = HashNumber::from(info);
+		assert_eq!(hash_number, HashNumber::HashAndNumber((hash, 7)));
+		assert_eq!(HashNumber::from((hash, 7)), hash_number);
+		assert_eq!(BlockInfo::try_from(hash_number), Ok(info));
+
+		assert!(BlockInfo::try_from(HashNumber::from(hash)).is_err());
+		assert!(BlockInfo::try_from(HashNumber::from(7u32)).is_err());
+	}
+}
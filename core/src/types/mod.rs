@@ -5,7 +5,7 @@ pub mod substrate;
 // General Chain Config
 pub use substrate::{AccountId, AccountIndex, BlakeTwo256, BlockHash, BlockHeight, Signature};
 // Commonly used substrate structs
-pub use substrate::{Era, MultiAddress, MultiSignature, RuntimePhase};
+pub use substrate::{Era, EventPhase, MultiAddress, MultiSignature, RuntimePhase};
 
 // Unnamed
 pub use metadata::{AccountIdLike, BlockInfo, HashNumber, HashString, HashStringNumber, StringOrBytes};
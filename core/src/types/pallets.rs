@@ -15,6 +15,8 @@ pub enum RuntimeCall {
 	BalancesTransferAllDeath(balances::tx::TransferAllowDeath),
 	BalancesTransferKeepAlive(balances::tx::TransferKeepAlive),
 	BalancesTransferAll(balances::tx::TransferAll),
+	BalancesForceTransfer(balances::tx::ForceTransfer),
+	BalancesForceSetBalance(balances::tx::ForceSetBalance),
 	UtilityBatch(utility::tx::Batch),
 	UtilityBatchAll(utility::tx::BatchAll),
 	UtilityForceBatch(utility::tx::ForceBatch),
@@ -73,12 +75,93 @@ pub enum RuntimeCall {
 	SessionPurgeKeys(session::tx::PurgeKeys),
 	TimestampSet(timestamp::tx::Set),
 }
+impl RuntimeCall {
+	/// Pallet and call index of the wrapped call.
+	pub fn header_index(&self) -> (u8, u8) {
+		match self {
+			RuntimeCall::BalancesTransferAllDeath(_) => balances::tx::TransferAllowDeath::HEADER_INDEX,
+			RuntimeCall::BalancesTransferKeepAlive(_) => balances::tx::TransferKeepAlive::HEADER_INDEX,
+			RuntimeCall::BalancesTransferAll(_) => balances::tx::TransferAll::HEADER_INDEX,
+			RuntimeCall::BalancesForceTransfer(_) => balances::tx::ForceTransfer::HEADER_INDEX,
+			RuntimeCall::BalancesForceSetBalance(_) => balances::tx::ForceSetBalance::HEADER_INDEX,
+			RuntimeCall::UtilityBatch(_) => utility::tx::Batch::HEADER_INDEX,
+			RuntimeCall::UtilityBatchAll(_) => utility::tx::BatchAll::HEADER_INDEX,
+			RuntimeCall::UtilityForceBatch(_) => utility::tx::ForceBatch::HEADER_INDEX,
+			RuntimeCall::SystemRemark(_) => system::tx::Remark::HEADER_INDEX,
+			RuntimeCall::SystemSetCode(_) => system::tx::SetCode::HEADER_INDEX,
+			RuntimeCall::SystemSetCodeWithoutChecks(_) => system::tx::SetCodeWithoutChecks::HEADER_INDEX,
+			RuntimeCall::SystemRemarkWithEvent(_) => system::tx::RemarkWithEvent::HEADER_INDEX,
+			RuntimeCall::ProxyProxy(_) => proxy::tx::Proxy::HEADER_INDEX,
+			RuntimeCall::ProxyAddProxy(_) => proxy::tx::AddProxy::HEADER_INDEX,
+			RuntimeCall::ProxyRemoveProxy(_) => proxy::tx::RemoveProxy::HEADER_INDEX,
+			RuntimeCall::ProxyRemoveProxies(_) => proxy::tx::RemoveProxies::HEADER_INDEX,
+			RuntimeCall::ProxyCreatePure(_) => proxy::tx::CreatePure::HEADER_INDEX,
+			RuntimeCall::ProxyKillPure(_) => proxy::tx::KillPure::HEADER_INDEX,
+			RuntimeCall::MultisigAsMultiThreshold1(_) => multisig::tx::AsMultiThreshold1::HEADER_INDEX,
+			RuntimeCall::MultisigAsMulti(_) => multisig::tx::AsMulti::HEADER_INDEX,
+			RuntimeCall::MultisigApproveAsMulti(_) => multisig::tx::ApproveAsMulti::HEADER_INDEX,
+			RuntimeCall::MultisigCancelAsMulti(_) => multisig::tx::CancelAsMulti::HEADER_INDEX,
+			RuntimeCall::DataAvailabilityCreateApplicationKey(_) => {
+				data_availability::tx::CreateApplicationKey::HEADER_INDEX
+			},
+			RuntimeCall::DataAvailabilitySubmitData(_) => data_availability::tx::SubmitData::HEADER_INDEX,
+			RuntimeCall::StakingBond(_) => staking::tx::Bond::HEADER_INDEX,
+			RuntimeCall::StakingBondExtra(_) => staking::tx::BondExtra::HEADER_INDEX,
+			RuntimeCall::StakingChill(_) => staking::tx::Chill::HEADER_INDEX,
+			RuntimeCall::StakingChillOther(_) => staking::tx::ChillOther::HEADER_INDEX,
+			RuntimeCall::StakingForceApplyMinCommission(_) => staking::tx::ForceApplyMinCommission::HEADER_INDEX,
+			RuntimeCall::StakingKick(_) => staking::tx::Kick::HEADER_INDEX,
+			RuntimeCall::StakingNominate(_) => staking::tx::Nominate::HEADER_INDEX,
+			RuntimeCall::StakingPayoutStakers(_) => staking::tx::PayoutStakers::HEADER_INDEX,
+			RuntimeCall::StakingPayoutStakersByPage(_) => staking::tx::PayoutStakersByPage::HEADER_INDEX,
+			RuntimeCall::StakingReapStash(_) => staking::tx::ReapStash::HEADER_INDEX,
+			RuntimeCall::StakingRebond(_) => staking::tx::Rebond::HEADER_INDEX,
+			RuntimeCall::StakingSetController(_) => staking::tx::SetController::HEADER_INDEX,
+			RuntimeCall::StakingSetPayee(_) => staking::tx::SetPayee::HEADER_INDEX,
+			RuntimeCall::StakingUnbond(_) => staking::tx::Unbond::HEADER_INDEX,
+			RuntimeCall::StakingValidate(_) => staking::tx::Validate::HEADER_INDEX,
+			RuntimeCall::StakingWithdrawUnbonded(_) => staking::tx::WithdrawUnbonded::HEADER_INDEX,
+			RuntimeCall::NominationPoolsBondExtra(_) => nomination_pools::tx::BondExtra::HEADER_INDEX,
+			RuntimeCall::NominationPoolsBondExtraOther(_) => nomination_pools::tx::BondExtraOther::HEADER_INDEX,
+			RuntimeCall::NominationPoolsChill(_) => nomination_pools::tx::Chill::HEADER_INDEX,
+			RuntimeCall::NominationPoolsClaimCommission(_) => nomination_pools::tx::ClaimCommission::HEADER_INDEX,
+			RuntimeCall::NominationPoolsClaimPayout(_) => nomination_pools::tx::ClaimPayout::HEADER_INDEX,
+			RuntimeCall::NominationPoolsClaimPayoutOther(_) => nomination_pools::tx::ClaimPayoutOther::HEADER_INDEX,
+			RuntimeCall::NominationPoolsCreate(_) => nomination_pools::tx::Create::HEADER_INDEX,
+			RuntimeCall::NominationPoolsCreateWithPoolId(_) => nomination_pools::tx::CreateWithPoolId::HEADER_INDEX,
+			RuntimeCall::NominationPoolsJoin(_) => nomination_pools::tx::Join::HEADER_INDEX,
+			RuntimeCall::NominationPoolsNominate(_) => nomination_pools::tx::Nominate::HEADER_INDEX,
+			RuntimeCall::NominationPoolsSetClaimPermission(_) => nomination_pools::tx::SetClaimPermission::HEADER_INDEX,
+			RuntimeCall::NominationPoolsSetCommission(_) => nomination_pools::tx::SetCommission::HEADER_INDEX,
+			RuntimeCall::NominationPoolsSetCommissionChangeRate(_) => {
+				nomination_pools::tx::SetCommissionChangeRate::HEADER_INDEX
+			},
+			RuntimeCall::NominationPoolsSetCommissionMax(_) => nomination_pools::tx::SetCommissionMax::HEADER_INDEX,
+			RuntimeCall::NominationPoolsSetMetadata(_) => nomination_pools::tx::SetMetadata::HEADER_INDEX,
+			RuntimeCall::NominationPoolsSetState(_) => nomination_pools::tx::SetState::HEADER_INDEX,
+			RuntimeCall::NominationPoolsUnbond(_) => nomination_pools::tx::Unbond::HEADER_INDEX,
+			RuntimeCall::NominationPoolsUpdateRoles(_) => nomination_pools::tx::UpdateRoles::HEADER_INDEX,
+			RuntimeCall::NominationPoolsWithdrawUnbonded(_) => nomination_pools::tx::WithdrawUnbonded::HEADER_INDEX,
+			RuntimeCall::SessionSetKeys(_) => session::tx::SetKeys::HEADER_INDEX,
+			RuntimeCall::SessionPurgeKeys(_) => session::tx::PurgeKeys::HEADER_INDEX,
+			RuntimeCall::TimestampSet(_) => timestamp::tx::Set::HEADER_INDEX,
+		}
+	}
+}
+impl From<&RuntimeCall> for ExtrinsicCall {
+	fn from(value: &RuntimeCall) -> Self {
+		let (pallet_id, variant_id) = value.header_index();
+		ExtrinsicCall::from_parts(pallet_id, variant_id, value.encode())
+	}
+}
 impl Encode for RuntimeCall {
 	fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
 		match self {
 			RuntimeCall::BalancesTransferAllDeath(x) => x.encode_to(dest),
 			RuntimeCall::BalancesTransferKeepAlive(x) => x.encode_to(dest),
 			RuntimeCall::BalancesTransferAll(x) => x.encode_to(dest),
+			RuntimeCall::BalancesForceTransfer(x) => x.encode_to(dest),
+			RuntimeCall::BalancesForceSetBalance(x) => x.encode_to(dest),
 			RuntimeCall::UtilityBatch(x) => x.encode_to(dest),
 			RuntimeCall::UtilityBatchAll(x) => x.encode_to(dest),
 			RuntimeCall::UtilityForceBatch(x) => x.encode_to(dest),
@@ -176,6 +259,16 @@ impl Decode for RuntimeCall {
 				let call = balances::tx::TransferAll::decode(input)?;
 				return Ok(RuntimeCall::BalancesTransferAll(call));
 			}
+
+			if variant_id == balances::tx::ForceTransfer::HEADER_INDEX.1 {
+				let call = balances::tx::ForceTransfer::decode(input)?;
+				return Ok(RuntimeCall::BalancesForceTransfer(call));
+			}
+
+			if variant_id == balances::tx::ForceSetBalance::HEADER_INDEX.1 {
+				let call = balances::tx::ForceSetBalance::decode(input)?;
+				return Ok(RuntimeCall::BalancesForceSetBalance(call));
+			}
 		}
 
 		if pallet_id == utility::PALLET_ID {
@@ -510,6 +603,11 @@ pub mod data_availability {
 			pub owner: AccountId,
 			pub id: u32,
 		}
+		impl Default for AppKey {
+			fn default() -> Self {
+				Self { owner: AccountId::from([0u8; 32]), id: 0 }
+			}
+		}
 		impl Encode for AppKey {
 			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
 				self.owner.encode_to(dest);
@@ -609,7 +707,7 @@ pub mod data_availability {
 	}
 
 	pub mod tx {
-		use super::*;
+		use super::{system::types::DispatchFeeModifier, *};
 
 		#[derive(Debug, Clone)]
 		pub struct CreateApplicationKey {
@@ -652,6 +750,69 @@ pub mod data_availability {
 			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
 		}
 
+		#[derive(Debug, Clone)]
+		pub struct SubmitBlockLengthProposal {
+			pub rows: u32,
+			pub cols: u32,
+		}
+		impl Encode for SubmitBlockLengthProposal {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.rows.encode_to(dest);
+				self.cols.encode_to(dest);
+			}
+		}
+		impl Decode for SubmitBlockLengthProposal {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let rows = Decode::decode(input)?;
+				let cols = Decode::decode(input)?;
+				Ok(Self { rows, cols })
+			}
+		}
+		impl HasHeader for SubmitBlockLengthProposal {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 2);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct SetApplicationKey {
+			pub old_key: Vec<u8>,
+			pub new_key: Vec<u8>,
+		}
+		impl Encode for SetApplicationKey {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.old_key.encode_to(dest);
+				self.new_key.encode_to(dest);
+			}
+		}
+		impl Decode for SetApplicationKey {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let old_key = Decode::decode(input)?;
+				let new_key = Decode::decode(input)?;
+				Ok(Self { old_key, new_key })
+			}
+		}
+		impl HasHeader for SetApplicationKey {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 3);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct SetSubmitDataFeeModifier {
+			pub modifier: DispatchFeeModifier,
+		}
+		impl Encode for SetSubmitDataFeeModifier {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.modifier.encode_to(dest);
+			}
+		}
+		impl Decode for SetSubmitDataFeeModifier {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let modifier = Decode::decode(input)?;
+				Ok(Self { modifier })
+			}
+		}
+		impl HasHeader for SetSubmitDataFeeModifier {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 4);
+		}
+
 		#[derive(Clone)]
 		pub struct SubmitBlobMetadata {
 			pub app_id: u32,
@@ -1131,6 +1292,53 @@ pub mod balances {
 		impl HasHeader for TransferAll {
 			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 4);
 		}
+
+		#[derive(Debug, Clone)]
+		pub struct ForceTransfer {
+			pub source: MultiAddress,
+			pub dest: MultiAddress,
+			pub value: u128,
+		}
+		impl Encode for ForceTransfer {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.source.encode_to(dest);
+				self.dest.encode_to(dest);
+				Compact(self.value).encode_to(dest);
+			}
+		}
+		impl Decode for ForceTransfer {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let source = Decode::decode(input)?;
+				let dest = Decode::decode(input)?;
+				let value = Compact::<u128>::decode(input)?.0;
+				Ok(Self { source, dest, value })
+			}
+		}
+		impl HasHeader for ForceTransfer {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 2);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct ForceSetBalance {
+			pub who: MultiAddress,
+			pub new_free: u128,
+		}
+		impl Encode for ForceSetBalance {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.who.encode_to(dest);
+				Compact(self.new_free).encode_to(dest);
+			}
+		}
+		impl Decode for ForceSetBalance {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let who = Decode::decode(input)?;
+				let new_free = Compact::<u128>::decode(input)?.0;
+				Ok(Self { who, new_free })
+			}
+		}
+		impl HasHeader for ForceSetBalance {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 8);
+		}
 	}
 }
 
@@ -1295,6 +1503,18 @@ pub mod utility {
 	pub mod tx {
 		use super::*;
 
+		/// Decodes a batch-like call from its full encoding (pallet/variant header included).
+		fn decode_from_call<T: HasHeader + Decode>(call: &[u8]) -> Result<T, codec::Error> {
+			let Some((header, mut data)) = call.split_at_checked(2) else {
+				return Err(codec::Error::from("Call is too short to contain a pallet and variant index"));
+			};
+			if (header[0], header[1]) != T::HEADER_INDEX {
+				return Err(codec::Error::from("Call header does not match the expected batch call"));
+			}
+
+			T::decode(&mut data)
+		}
+
 		#[derive(Debug, Default, Clone)]
 		pub struct Batch {
 			length: u32,
@@ -1305,6 +1525,11 @@ pub mod utility {
 				Self::default()
 			}
 
+			/// Decodes the inner calls of an encoded `Batch` call without constructing it first.
+			pub fn decode_calls_from(call: &[u8]) -> Result<Vec<RuntimeCall>, codec::Error> {
+				decode_from_call::<Self>(call)?.decode_calls()
+			}
+
 			pub fn decode_calls(&self) -> Result<Vec<RuntimeCall>, codec::Error> {
 				if self.length == 0 {
 					return Ok(Vec::new());
@@ -1385,6 +1610,11 @@ pub mod utility {
 				Self::default()
 			}
 
+			/// Decodes the inner calls of an encoded `BatchAll` call without constructing it first.
+			pub fn decode_calls_from(call: &[u8]) -> Result<Vec<RuntimeCall>, codec::Error> {
+				decode_from_call::<Self>(call)?.decode_calls()
+			}
+
 			pub fn decode_calls(&self) -> Result<Vec<RuntimeCall>, codec::Error> {
 				if self.length == 0 {
 					return Ok(Vec::new());
@@ -1465,6 +1695,11 @@ pub mod utility {
 				Self::default()
 			}
 
+			/// Decodes the inner calls of an encoded `ForceBatch` call without constructing it first.
+			pub fn decode_calls_from(call: &[u8]) -> Result<Vec<RuntimeCall>, codec::Error> {
+				decode_from_call::<Self>(call)?.decode_calls()
+			}
+
 			pub fn decode_calls(&self) -> Result<Vec<RuntimeCall>, codec::Error> {
 				if self.length == 0 {
 					return Ok(Vec::new());
@@ -3192,6 +3427,19 @@ pub mod vector {
 			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 11);
 		}
 	}
+
+	pub mod storage {
+		use super::*;
+
+		/// Fee charged by `send_message` on top of the transaction fee, on runtimes that declare it.
+		pub struct MessageFee;
+		impl StorageValue for MessageFee {
+			type VALUE = u128;
+
+			const PALLET_NAME: &str = "Vector";
+			const STORAGE_NAME: &str = "MessageFee";
+		}
+	}
 }
 
 pub mod system {
@@ -3230,7 +3478,7 @@ pub mod system {
 			}
 		}
 
-		#[derive(Debug, Clone)]
+		#[derive(Debug, Clone, PartialEq)]
 		pub struct DispatchInfo {
 			/// Weight of this transaction.
 			pub weight: Weight,
@@ -3255,7 +3503,7 @@ pub mod system {
 			}
 		}
 
-		#[derive(Debug, Clone, Copy)]
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 		#[repr(u8)]
 		pub enum Pays {
 			/// Transactor will pay related fees.
@@ -4504,19 +4752,1049 @@ pub mod treasury {
 		}
 	}
 }
-pub mod babe {
+pub mod technical_committee {
 	use super::*;
-	pub const PALLET_ID: u8 = 2;
+	pub const PALLET_ID: u8 = 14;
+
+	pub mod events {
+		use super::*;
+
+		/// A motion was proposed by a member; `threshold` approvals are needed to pass it.
+		#[derive(Debug, Clone)]
+		pub struct Proposed {
+			pub account: AccountId,
+			pub proposal_index: u32,
+			pub proposal_hash: H256,
+			pub threshold: u32,
+		}
+		impl HasHeader for Proposed {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 0);
+		}
+		impl Encode for Proposed {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.account.encode_to(dest);
+				self.proposal_index.encode_to(dest);
+				self.proposal_hash.encode_to(dest);
+				self.threshold.encode_to(dest);
+			}
+		}
+		impl Decode for Proposed {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let account = Decode::decode(input)?;
+				let proposal_index = Decode::decode(input)?;
+				let proposal_hash = Decode::decode(input)?;
+				let threshold = Decode::decode(input)?;
+				Ok(Self { account, proposal_index, proposal_hash, threshold })
+			}
+		}
+
+		/// A member voted on a motion, giving the current `yes` and `no` tallies.
+		#[derive(Debug, Clone)]
+		pub struct Voted {
+			pub account: AccountId,
+			pub proposal_hash: H256,
+			pub voted: bool,
+			pub yes: u32,
+			pub no: u32,
+		}
+		impl HasHeader for Voted {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
+		}
+		impl Encode for Voted {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.account.encode_to(dest);
+				self.proposal_hash.encode_to(dest);
+				self.voted.encode_to(dest);
+				self.yes.encode_to(dest);
+				self.no.encode_to(dest);
+			}
+		}
+		impl Decode for Voted {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let account = Decode::decode(input)?;
+				let proposal_hash = Decode::decode(input)?;
+				let voted = Decode::decode(input)?;
+				let yes = Decode::decode(input)?;
+				let no = Decode::decode(input)?;
+				Ok(Self { account, proposal_hash, voted, yes, no })
+			}
+		}
+
+		/// A motion was approved by the required threshold.
+		#[derive(Debug, Clone)]
+		pub struct Approved {
+			pub proposal_hash: H256,
+		}
+		impl HasHeader for Approved {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 2);
+		}
+		impl Encode for Approved {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.proposal_hash.encode_to(dest);
+			}
+		}
+		impl Decode for Approved {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let proposal_hash = Decode::decode(input)?;
+				Ok(Self { proposal_hash })
+			}
+		}
+
+		/// A motion was not approved by the required threshold.
+		#[derive(Debug, Clone)]
+		pub struct Disapproved {
+			pub proposal_hash: H256,
+		}
+		impl HasHeader for Disapproved {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 3);
+		}
+		impl Encode for Disapproved {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.proposal_hash.encode_to(dest);
+			}
+		}
+		impl Decode for Disapproved {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let proposal_hash = Decode::decode(input)?;
+				Ok(Self { proposal_hash })
+			}
+		}
+
+		/// A motion was executed; `result` is the outcome of its dispatch.
+		#[derive(Debug, Clone)]
+		pub struct Executed {
+			pub proposal_hash: H256,
+			pub result: Result<(), super::system::types::DispatchError>,
+		}
+		impl HasHeader for Executed {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 4);
+		}
+		impl Encode for Executed {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.proposal_hash.encode_to(dest);
+				self.result.encode_to(dest);
+			}
+		}
+		impl Decode for Executed {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let proposal_hash = Decode::decode(input)?;
+				let result = Decode::decode(input)?;
+				Ok(Self { proposal_hash, result })
+			}
+		}
+
+		/// A single member did some action; `result` is the outcome of its dispatch.
+		#[derive(Debug, Clone)]
+		pub struct MemberExecuted {
+			pub proposal_hash: H256,
+			pub result: Result<(), super::system::types::DispatchError>,
+		}
+		impl HasHeader for MemberExecuted {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 5);
+		}
+		impl Encode for MemberExecuted {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.proposal_hash.encode_to(dest);
+				self.result.encode_to(dest);
+			}
+		}
+		impl Decode for MemberExecuted {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let proposal_hash = Decode::decode(input)?;
+				let result = Decode::decode(input)?;
+				Ok(Self { proposal_hash, result })
+			}
+		}
+
+		/// A proposal was closed because its threshold was reached or its voting period ended.
+		#[derive(Debug, Clone)]
+		pub struct Closed {
+			pub proposal_hash: H256,
+			pub yes: u32,
+			pub no: u32,
+		}
+		impl HasHeader for Closed {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 6);
+		}
+		impl Encode for Closed {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.proposal_hash.encode_to(dest);
+				self.yes.encode_to(dest);
+				self.no.encode_to(dest);
+			}
+		}
+		impl Decode for Closed {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let proposal_hash = Decode::decode(input)?;
+				let yes = Decode::decode(input)?;
+				let no = Decode::decode(input)?;
+				Ok(Self { proposal_hash, yes, no })
+			}
+		}
+	}
+
+	pub mod tx {
+		use super::*;
+
+		/// Votes on an open motion. Dispatched with the operational class.
+		#[derive(Debug, Clone)]
+		pub struct Vote {
+			pub proposal: H256,
+			pub index: u32,
+			pub approve: bool,
+		}
+		impl Encode for Vote {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.proposal.encode_to(dest);
+				Compact(self.index).encode_to(dest);
+				self.approve.encode_to(dest);
+			}
+		}
+		impl Decode for Vote {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let proposal = Decode::decode(input)?;
+				let index = Compact::<u32>::decode(input)?.0;
+				let approve = Decode::decode(input)?;
+				Ok(Self { proposal, index, approve })
+			}
+		}
+		impl HasHeader for Vote {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 3);
+		}
+	}
+}
+pub mod sudo {
+	use super::*;
+	pub const PALLET_ID: u8 = 19;
 
 	pub mod storage {
 		use super::*;
 
-		pub struct BabeRandomness;
-		impl StorageValue for BabeRandomness {
-			type VALUE = [u8; 32];
+		pub struct Key;
+		impl StorageValue for Key {
+			type VALUE = AccountId;
 
-			const PALLET_NAME: &str = "Babe";
-			const STORAGE_NAME: &str = "Randomness";
+			const PALLET_NAME: &str = "Sudo";
+			const STORAGE_NAME: &str = "Key";
+		}
+	}
+
+	pub mod events {
+		use super::*;
+
+		/// A sudo call just took place.
+		#[derive(Debug, Clone)]
+		pub struct Sudid {
+			pub sudo_result: Result<(), super::system::types::DispatchError>,
+		}
+		impl HasHeader for Sudid {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 0);
+		}
+		impl Encode for Sudid {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.sudo_result.encode_to(dest);
+			}
+		}
+		impl Decode for Sudid {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let sudo_result = Decode::decode(input)?;
+				Ok(Self { sudo_result })
+			}
+		}
+
+		/// The sudo key has been updated.
+		#[derive(Debug, Clone)]
+		pub struct KeyChanged {
+			pub old: Option<AccountId>,
+			pub new: AccountId,
+		}
+		impl HasHeader for KeyChanged {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
+		}
+		impl Encode for KeyChanged {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.old.encode_to(dest);
+				self.new.encode_to(dest);
+			}
+		}
+		impl Decode for KeyChanged {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let old = Decode::decode(input)?;
+				let new = Decode::decode(input)?;
+				Ok(Self { old, new })
+			}
+		}
+
+		/// A sudo call with a different origin just took place.
+		#[derive(Debug, Clone)]
+		pub struct SudoAsDone {
+			pub sudo_result: Result<(), super::system::types::DispatchError>,
+		}
+		impl HasHeader for SudoAsDone {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 3);
+		}
+		impl Encode for SudoAsDone {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.sudo_result.encode_to(dest);
+			}
 		}
+		impl Decode for SudoAsDone {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let sudo_result = Decode::decode(input)?;
+				Ok(Self { sudo_result })
+			}
+		}
+	}
+
+	pub mod tx {
+		use super::*;
+		use crate::types::substrate::Weight;
+
+		#[derive(Debug, Clone)]
+		pub struct Sudo {
+			pub call: ExtrinsicCall,
+		}
+		impl Encode for Sudo {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.call.encode_to(dest);
+			}
+		}
+		impl Decode for Sudo {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let call = Decode::decode(input)?;
+				Ok(Self { call })
+			}
+		}
+		impl HasHeader for Sudo {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 0);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct SudoUncheckedWeight {
+			pub call: ExtrinsicCall,
+			pub weight: Weight,
+		}
+		impl Encode for SudoUncheckedWeight {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.call.encode_to(dest);
+				self.weight.encode_to(dest);
+			}
+		}
+		impl Decode for SudoUncheckedWeight {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				// `ExtrinsicCall` would also consume the trailing weight, so the inner call is decoded as a
+				// `RuntimeCall` to find where it ends. Calls unknown to `RuntimeCall` fail to decode.
+				let call = ExtrinsicCall::from(&RuntimeCall::decode(input)?);
+				let weight = Decode::decode(input)?;
+				Ok(Self { call, weight })
+			}
+		}
+		impl HasHeader for SudoUncheckedWeight {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct SudoAs {
+			pub who: MultiAddress,
+			pub call: ExtrinsicCall,
+		}
+		impl Encode for SudoAs {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.who.encode_to(dest);
+				self.call.encode_to(dest);
+			}
+		}
+		impl Decode for SudoAs {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let who = Decode::decode(input)?;
+				let call = Decode::decode(input)?;
+				Ok(Self { who, call })
+			}
+		}
+		impl HasHeader for SudoAs {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 3);
+		}
+	}
+}
+
+pub mod scheduler {
+	use super::*;
+	pub const PALLET_ID: u8 = 24;
+
+	pub mod types {
+		/// `(period, count)` of a periodic task: repeats every `period` blocks, `count` times in total.
+		pub type Period = (u32, u32);
+		/// `(block, index)` locating a scheduled task in the agenda.
+		pub type TaskAddress = (u32, u32);
+		pub type TaskName = [u8; 32];
+	}
+
+	pub mod events {
+		use super::*;
+		use types::{TaskAddress, TaskName};
+
+		/// Scheduled some task.
+		#[derive(Debug, Clone)]
+		pub struct Scheduled {
+			pub when: u32,
+			pub index: u32,
+		}
+		impl HasHeader for Scheduled {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 0);
+		}
+		impl Encode for Scheduled {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.when.encode_to(dest);
+				self.index.encode_to(dest);
+			}
+		}
+		impl Decode for Scheduled {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let when = Decode::decode(input)?;
+				let index = Decode::decode(input)?;
+				Ok(Self { when, index })
+			}
+		}
+
+		/// Canceled some task.
+		#[derive(Debug, Clone)]
+		pub struct Canceled {
+			pub when: u32,
+			pub index: u32,
+		}
+		impl HasHeader for Canceled {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
+		}
+		impl Encode for Canceled {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.when.encode_to(dest);
+				self.index.encode_to(dest);
+			}
+		}
+		impl Decode for Canceled {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let when = Decode::decode(input)?;
+				let index = Decode::decode(input)?;
+				Ok(Self { when, index })
+			}
+		}
+
+		/// Dispatched some task.
+		#[derive(Debug, Clone)]
+		pub struct Dispatched {
+			pub task: TaskAddress,
+			pub id: Option<TaskName>,
+			pub result: Result<(), super::system::types::DispatchError>,
+		}
+		impl HasHeader for Dispatched {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 2);
+		}
+		impl Encode for Dispatched {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.task.encode_to(dest);
+				self.id.encode_to(dest);
+				self.result.encode_to(dest);
+			}
+		}
+		impl Decode for Dispatched {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let task = Decode::decode(input)?;
+				let id = Decode::decode(input)?;
+				let result = Decode::decode(input)?;
+				Ok(Self { task, id, result })
+			}
+		}
+	}
+
+	pub mod tx {
+		use super::*;
+		use types::{Period, TaskName};
+
+		#[derive(Debug, Clone)]
+		pub struct Schedule {
+			pub when: u32,
+			pub maybe_periodic: Option<Period>,
+			pub priority: u8,
+			pub call: ExtrinsicCall,
+		}
+		impl Encode for Schedule {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.when.encode_to(dest);
+				self.maybe_periodic.encode_to(dest);
+				self.priority.encode_to(dest);
+				self.call.encode_to(dest);
+			}
+		}
+		impl Decode for Schedule {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let when = Decode::decode(input)?;
+				let maybe_periodic = Decode::decode(input)?;
+				let priority = Decode::decode(input)?;
+				let call = Decode::decode(input)?;
+				Ok(Self { when, maybe_periodic, priority, call })
+			}
+		}
+		impl HasHeader for Schedule {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 0);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct Cancel {
+			pub when: u32,
+			pub index: u32,
+		}
+		impl Encode for Cancel {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.when.encode_to(dest);
+				self.index.encode_to(dest);
+			}
+		}
+		impl Decode for Cancel {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let when = Decode::decode(input)?;
+				let index = Decode::decode(input)?;
+				Ok(Self { when, index })
+			}
+		}
+		impl HasHeader for Cancel {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct ScheduleNamed {
+			pub id: TaskName,
+			pub when: u32,
+			pub maybe_periodic: Option<Period>,
+			pub priority: u8,
+			pub call: ExtrinsicCall,
+		}
+		impl Encode for ScheduleNamed {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.id.encode_to(dest);
+				self.when.encode_to(dest);
+				self.maybe_periodic.encode_to(dest);
+				self.priority.encode_to(dest);
+				self.call.encode_to(dest);
+			}
+		}
+		impl Decode for ScheduleNamed {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let id = Decode::decode(input)?;
+				let when = Decode::decode(input)?;
+				let maybe_periodic = Decode::decode(input)?;
+				let priority = Decode::decode(input)?;
+				let call = Decode::decode(input)?;
+				Ok(Self { id, when, maybe_periodic, priority, call })
+			}
+		}
+		impl HasHeader for ScheduleNamed {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 2);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct CancelNamed {
+			pub id: TaskName,
+		}
+		impl Encode for CancelNamed {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.id.encode_to(dest);
+			}
+		}
+		impl Decode for CancelNamed {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let id = Decode::decode(input)?;
+				Ok(Self { id })
+			}
+		}
+		impl HasHeader for CancelNamed {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 3);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct ScheduleAfter {
+			pub after: u32,
+			pub maybe_periodic: Option<Period>,
+			pub priority: u8,
+			pub call: ExtrinsicCall,
+		}
+		impl Encode for ScheduleAfter {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.after.encode_to(dest);
+				self.maybe_periodic.encode_to(dest);
+				self.priority.encode_to(dest);
+				self.call.encode_to(dest);
+			}
+		}
+		impl Decode for ScheduleAfter {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let after = Decode::decode(input)?;
+				let maybe_periodic = Decode::decode(input)?;
+				let priority = Decode::decode(input)?;
+				let call = Decode::decode(input)?;
+				Ok(Self { after, maybe_periodic, priority, call })
+			}
+		}
+		impl HasHeader for ScheduleAfter {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 4);
+		}
+	}
+}
+
+pub mod identity {
+	use super::*;
+	pub const PALLET_ID: u8 = 37;
+
+	pub mod types {
+		use super::*;
+
+		/// Maximum number of bytes a [`Data::Raw`] value may hold.
+		pub const MAX_RAW_DATA_LEN: usize = 32;
+
+		/// Identity field value, either raw bytes (up to 32) or a hash of the value.
+		#[derive(Debug, Clone, Default, PartialEq, Eq)]
+		pub enum Data {
+			#[default]
+			None,
+			Raw(Vec<u8>),
+			BlakeTwo256([u8; 32]),
+			Sha256([u8; 32]),
+			Keccak256([u8; 32]),
+			ShaThree256([u8; 32]),
+		}
+		impl Data {
+			/// Returns the raw bytes when this is a [`Data::Raw`] value.
+			pub fn as_raw(&self) -> Option<&[u8]> {
+				match self {
+					Self::Raw(x) => Some(x.as_slice()),
+					_ => None,
+				}
+			}
+		}
+		impl From<&str> for Data {
+			fn from(value: &str) -> Self {
+				Self::Raw(value.as_bytes().to_vec())
+			}
+		}
+		impl From<String> for Data {
+			fn from(value: String) -> Self {
+				Self::Raw(value.into_bytes())
+			}
+		}
+		impl Encode for Data {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				match self {
+					Self::None => 0u8.encode_to(dest),
+					Self::Raw(x) => {
+						((x.len() as u8).saturating_add(1)).encode_to(dest);
+						dest.write(x);
+					},
+					Self::BlakeTwo256(x) => {
+						34u8.encode_to(dest);
+						dest.write(x);
+					},
+					Self::Sha256(x) => {
+						35u8.encode_to(dest);
+						dest.write(x);
+					},
+					Self::Keccak256(x) => {
+						36u8.encode_to(dest);
+						dest.write(x);
+					},
+					Self::ShaThree256(x) => {
+						37u8.encode_to(dest);
+						dest.write(x);
+					},
+				}
+			}
+		}
+		impl Decode for Data {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let variant = u8::decode(input)?;
+				match variant {
+					0 => Ok(Self::None),
+					1..=33 => {
+						let mut value = vec![0u8; variant as usize - 1];
+						input.read(&mut value)?;
+						Ok(Self::Raw(value))
+					},
+					34 => Ok(Self::BlakeTwo256(Decode::decode(input)?)),
+					35 => Ok(Self::Sha256(Decode::decode(input)?)),
+					36 => Ok(Self::Keccak256(Decode::decode(input)?)),
+					37 => Ok(Self::ShaThree256(Decode::decode(input)?)),
+					_ => Err("Failed to decode Data. Unknown variant".into()),
+				}
+			}
+		}
+
+		/// On-chain identity information. Use the setters to build it field by field.
+		#[derive(Debug, Clone, Default, PartialEq, Eq)]
+		pub struct IdentityInfo {
+			pub additional: Vec<(Data, Data)>,
+			pub display: Data,
+			pub legal: Data,
+			pub web: Data,
+			pub riot: Data,
+			pub email: Data,
+			pub pgp_fingerprint: Option<[u8; 20]>,
+			pub image: Data,
+			pub twitter: Data,
+		}
+		impl IdentityInfo {
+			pub fn new() -> Self {
+				Self::default()
+			}
+
+			pub fn display(mut self, value: impl Into<Data>) -> Self {
+				self.display = value.into();
+				self
+			}
+
+			pub fn legal(mut self, value: impl Into<Data>) -> Self {
+				self.legal = value.into();
+				self
+			}
+
+			pub fn web(mut self, value: impl Into<Data>) -> Self {
+				self.web = value.into();
+				self
+			}
+
+			pub fn riot(mut self, value: impl Into<Data>) -> Self {
+				self.riot = value.into();
+				self
+			}
+
+			pub fn email(mut self, value: impl Into<Data>) -> Self {
+				self.email = value.into();
+				self
+			}
+
+			pub fn pgp_fingerprint(mut self, value: [u8; 20]) -> Self {
+				self.pgp_fingerprint = Some(value);
+				self
+			}
+
+			pub fn image(mut self, value: impl Into<Data>) -> Self {
+				self.image = value.into();
+				self
+			}
+
+			pub fn twitter(mut self, value: impl Into<Data>) -> Self {
+				self.twitter = value.into();
+				self
+			}
+
+			pub fn additional(mut self, key: impl Into<Data>, value: impl Into<Data>) -> Self {
+				self.additional.push((key.into(), value.into()));
+				self
+			}
+
+			/// Iterates over every field value, including additional key/value pairs.
+			pub fn fields(&self) -> impl Iterator<Item = &Data> {
+				let fixed = [
+					&self.display, &self.legal, &self.web, &self.riot, &self.email, &self.image, &self.twitter,
+				];
+				fixed
+					.into_iter()
+					.chain(self.additional.iter().flat_map(|(k, v)| [k, v]))
+			}
+		}
+		impl Encode for IdentityInfo {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.additional.encode_to(dest);
+				self.display.encode_to(dest);
+				self.legal.encode_to(dest);
+				self.web.encode_to(dest);
+				self.riot.encode_to(dest);
+				self.email.encode_to(dest);
+				self.pgp_fingerprint.encode_to(dest);
+				self.image.encode_to(dest);
+				self.twitter.encode_to(dest);
+			}
+		}
+		impl Decode for IdentityInfo {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let additional = Decode::decode(input)?;
+				let display = Decode::decode(input)?;
+				let legal = Decode::decode(input)?;
+				let web = Decode::decode(input)?;
+				let riot = Decode::decode(input)?;
+				let email = Decode::decode(input)?;
+				let pgp_fingerprint = Decode::decode(input)?;
+				let image = Decode::decode(input)?;
+				let twitter = Decode::decode(input)?;
+				Ok(Self {
+					additional,
+					display,
+					legal,
+					web,
+					riot,
+					email,
+					pgp_fingerprint,
+					image,
+					twitter,
+				})
+			}
+		}
+	}
+
+	pub mod events {
+		use super::*;
+
+		/// A name was set or reset (which will remove all judgements).
+		#[derive(Debug, Clone)]
+		pub struct IdentitySet {
+			pub who: AccountId,
+		}
+		impl HasHeader for IdentitySet {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 0);
+		}
+		impl Encode for IdentitySet {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.who.encode_to(dest);
+			}
+		}
+		impl Decode for IdentitySet {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let who = Decode::decode(input)?;
+				Ok(Self { who })
+			}
+		}
+
+		/// A name was cleared, and the given balance returned.
+		#[derive(Debug, Clone)]
+		pub struct IdentityCleared {
+			pub who: AccountId,
+			pub deposit: u128,
+		}
+		impl HasHeader for IdentityCleared {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
+		}
+		impl Encode for IdentityCleared {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.who.encode_to(dest);
+				self.deposit.encode_to(dest);
+			}
+		}
+		impl Decode for IdentityCleared {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let who = Decode::decode(input)?;
+				let deposit = Decode::decode(input)?;
+				Ok(Self { who, deposit })
+			}
+		}
+	}
+
+	pub mod tx {
+		use super::*;
+
+		#[derive(Debug, Clone)]
+		pub struct SetIdentity {
+			pub info: super::types::IdentityInfo,
+		}
+		impl Encode for SetIdentity {
+			fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+				self.info.encode_to(dest);
+			}
+		}
+		impl Decode for SetIdentity {
+			fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+				let info = Decode::decode(input)?;
+				Ok(Self { info })
+			}
+		}
+		impl HasHeader for SetIdentity {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 1);
+		}
+
+		#[derive(Debug, Clone)]
+		pub struct ClearIdentity {}
+		impl Encode for ClearIdentity {
+			fn encode_to<T: codec::Output + ?Sized>(&self, _dest: &mut T) {}
+		}
+		impl Decode for ClearIdentity {
+			fn decode<I: codec::Input>(_input: &mut I) -> Result<Self, codec::Error> {
+				Ok(Self {})
+			}
+		}
+		impl HasHeader for ClearIdentity {
+			const HEADER_INDEX: (u8, u8) = (PALLET_ID, 3);
+		}
+	}
+}
+
+pub mod babe {
+	use super::*;
+	pub const PALLET_ID: u8 = 2;
+
+	pub mod storage {
+		use super::*;
+
+		pub struct BabeRandomness;
+		impl StorageValue for BabeRandomness {
+			type VALUE = [u8; 32];
+
+			const PALLET_NAME: &str = "Babe";
+			const STORAGE_NAME: &str = "Randomness";
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn runtime_call_into_extrinsic_call() {
+		let dest = MultiAddress::Id(AccountId::from([1u8; 32]));
+		let call = RuntimeCall::BalancesTransferKeepAlive(balances::tx::TransferKeepAlive { dest, value: 10 });
+		assert_eq!(call.header_index(), balances::tx::TransferKeepAlive::HEADER_INDEX);
+
+		let ext_call = ExtrinsicCall::from(&call);
+		let decoded = RuntimeCall::decode(&mut ext_call.encode().as_slice()).unwrap();
+		let RuntimeCall::BalancesTransferKeepAlive(decoded) = decoded else {
+			panic!("Expected BalancesTransferKeepAlive");
+		};
+		assert_eq!(decoded.value, 10);
+	}
+
+	#[test]
+	fn force_balance_calls_decode_as_runtime_calls() {
+		let source = MultiAddress::Id(AccountId::from([1u8; 32]));
+		let dest = MultiAddress::Id(AccountId::from([2u8; 32]));
+		let call = ExtrinsicCall::from(&balances::tx::ForceTransfer { source, dest, value: 5 });
+		assert_eq!(call.0[..2], [balances::PALLET_ID, 2]);
+		let RuntimeCall::BalancesForceTransfer(decoded) = RuntimeCall::decode(&mut call.0.as_slice()).unwrap() else {
+			panic!("Expected BalancesForceTransfer");
+		};
+		assert_eq!(decoded.value, 5);
+
+		let who = MultiAddress::Id(AccountId::from([3u8; 32]));
+		let call = ExtrinsicCall::from(&balances::tx::ForceSetBalance { who, new_free: 7 });
+		assert_eq!(call.0[..2], [balances::PALLET_ID, 8]);
+		let RuntimeCall::BalancesForceSetBalance(decoded) = RuntimeCall::decode(&mut call.0.as_slice()).unwrap() else {
+			panic!("Expected BalancesForceSetBalance");
+		};
+		assert_eq!(decoded.new_free, 7);
+	}
+
+	#[test]
+	fn extrinsic_success_decodes_dispatch_info() {
+		use crate::types::substrate::{DispatchClass, Weight};
+
+		let dispatch_info = system::types::DispatchInfo {
+			weight: Weight { ref_time: 1_000, proof_size: 64 },
+			class: DispatchClass::Normal,
+			pays_fee: system::types::Pays::Yes,
+		};
+		let event = system::events::ExtrinsicSuccess::decode(&mut dispatch_info.encode().as_slice()).unwrap();
+		assert_eq!(event.dispatch_info, dispatch_info);
+		assert_eq!(event.dispatch_info.class, DispatchClass::Normal);
+		assert_eq!(event.dispatch_info.pays_fee, system::types::Pays::Yes);
+	}
+
+	#[test]
+	fn technical_committee_voted_round_trip() {
+		use technical_committee::events::Voted;
+
+		let voted = Voted {
+			account: AccountId::from([4u8; 32]),
+			proposal_hash: H256::repeat_byte(9),
+			voted: true,
+			yes: 2,
+			no: 1,
+		};
+		let decoded = Voted::decode(&mut voted.encode().as_slice()).unwrap();
+		assert_eq!(decoded.account, voted.account);
+		assert_eq!(decoded.proposal_hash, voted.proposal_hash);
+		assert!(decoded.voted);
+		assert_eq!((decoded.yes, decoded.no), (2, 1));
+		assert_eq!(Voted::HEADER_INDEX, (14, 1));
+	}
+
+	#[test]
+	fn batch_decode_calls_from_bytes() {
+		let dest = MultiAddress::Id(AccountId::from([1u8; 32]));
+		let first = balances::tx::TransferKeepAlive { dest: dest.clone(), value: 10 };
+		let second = balances::tx::TransferKeepAlive { dest, value: 20 };
+
+		let mut batch = utility::tx::BatchAll::new();
+		batch.add_calls(vec![ExtrinsicCall::from(&first), ExtrinsicCall::from(&second)]);
+		let encoded = ExtrinsicCall::from(&batch).0;
+
+		let calls = utility::tx::BatchAll::decode_calls_from(&encoded).unwrap();
+		assert_eq!(calls.len(), 2);
+		let values: Vec<u128> = calls
+			.iter()
+			.map(|x| match x {
+				RuntimeCall::BalancesTransferKeepAlive(t) => t.value,
+				_ => panic!("expected TransferKeepAlive"),
+			})
+			.collect();
+		assert_eq!(values, vec![10, 20]);
+
+		assert!(utility::tx::Batch::decode_calls_from(&encoded).is_err());
+		assert!(utility::tx::ForceBatch::decode_calls_from(&encoded[..1]).is_err());
+	}
+
+	#[test]
+	fn scheduler_schedule_round_trip() {
+		let inner = ExtrinsicCall::from(&system::tx::Remark { remark: b"later".to_vec() });
+		let call = scheduler::tx::Schedule {
+			when: 100,
+			maybe_periodic: Some((10, 3)),
+			priority: 0,
+			call: inner.clone(),
+		};
+		let encoded = ExtrinsicCall::from(&call);
+		assert_eq!(encoded.0[..2], [scheduler::PALLET_ID, 0]);
+
+		let decoded = encoded.try_decode::<scheduler::tx::Schedule>().unwrap();
+		assert_eq!(decoded.when, 100);
+		assert_eq!(decoded.maybe_periodic, Some((10, 3)));
+		assert_eq!(decoded.call.0, inner.0);
+
+		let event = scheduler::events::Dispatched { task: (100, 0), id: None, result: Ok(()) };
+		let decoded = scheduler::events::Dispatched::decode(&mut event.encode().as_slice()).unwrap();
+		assert_eq!(decoded.task, (100, 0));
+		assert!(decoded.result.is_ok());
+	}
+
+	#[test]
+	fn sudo_unchecked_weight_round_trip() {
+		use crate::types::substrate::Weight;
+
+		let inner = ExtrinsicCall::from(&system::tx::Remark { remark: b"as root".to_vec() });
+		let call = sudo::tx::SudoUncheckedWeight {
+			call: inner.clone(),
+			weight: Weight { ref_time: 1_000, proof_size: 64 },
+		};
+		let encoded = ExtrinsicCall::from(&call);
+		assert_eq!(encoded.0[..2], [sudo::PALLET_ID, 1]);
+
+		let decoded = encoded.try_decode::<sudo::tx::SudoUncheckedWeight>().unwrap();
+		assert_eq!(decoded.call.0, inner.0);
+		assert_eq!(decoded.weight, call.weight);
+	}
+
+	#[test]
+	fn data_availability_governance_call_indices() {
+		let call = ExtrinsicCall::from(&data_availability::tx::SubmitBlockLengthProposal { rows: 256, cols: 128 });
+		assert_eq!(call.0[..2], [data_availability::PALLET_ID, 2]);
+		assert_eq!(call.0[2..], (256u32, 128u32).encode());
+
+		let call = ExtrinsicCall::from(&data_availability::tx::SetApplicationKey {
+			old_key: b"old".to_vec(),
+			new_key: b"new".to_vec(),
+		});
+		assert_eq!(call.0[..2], [data_availability::PALLET_ID, 3]);
+
+		let modifier = system::types::DispatchFeeModifier {
+			weight_maximum_fee: None,
+			weight_fee_divider: Some(2),
+			weight_fee_multiplier: None,
+		};
+		let call = ExtrinsicCall::from(&data_availability::tx::SetSubmitDataFeeModifier { modifier });
+		assert_eq!(call.0[..2], [data_availability::PALLET_ID, 4]);
 	}
 }
@@ -22,6 +22,9 @@ pub enum RuntimePhase {
 	Initialization,
 }
 
+/// Phase an event was emitted in; serde-friendly alternative to `subxt_core::events::Phase`.
+pub type EventPhase = RuntimePhase;
+
 impl RuntimePhase {
 	pub fn extrinsic_index(&self) -> Option<u32> {
 		match self {
@@ -31,6 +34,27 @@ impl RuntimePhase {
 	}
 }
 
+impl std::fmt::Display for RuntimePhase {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RuntimePhase::ApplyExtrinsic(x) => write!(f, "ApplyExtrinsic({})", x),
+			RuntimePhase::Finalization => f.write_str("Finalization"),
+			RuntimePhase::Initialization => f.write_str("Initialization"),
+		}
+	}
+}
+
+impl From<subxt_core::events::Phase> for RuntimePhase {
+	fn from(value: subxt_core::events::Phase) -> Self {
+		use subxt_core::events::Phase;
+		match value {
+			Phase::ApplyExtrinsic(x) => RuntimePhase::ApplyExtrinsic(x),
+			Phase::Finalization => RuntimePhase::Finalization,
+			Phase::Initialization => RuntimePhase::Initialization,
+		}
+	}
+}
+
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, Debug, scale_info::TypeInfo)]
 #[repr(u8)]
 pub enum MultiSignature {
@@ -57,6 +81,13 @@ pub enum MultiAddress {
 	Address20([u8; 20]) = 4,
 }
 
+impl MultiAddress {
+	/// Creates an address referring to an account by its `pallet_indices` index.
+	pub fn from_index(index: u32) -> Self {
+		Self::Index(index)
+	}
+}
+
 impl From<AccountId> for MultiAddress {
 	fn from(a: AccountId) -> Self {
 		Self::Id(a)
@@ -222,3 +253,40 @@ impl PerDispatchClassWeight {
 			.saturating_add(self.mandatory.ref_time)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::types::metadata::MultiAddressLike;
+
+	#[test]
+	fn multi_address_index_round_trip() {
+		let address = MultiAddress::from_index(42);
+		let encoded = address.encode();
+		assert_eq!(encoded, vec![1, 42 << 2]);
+
+		let decoded = MultiAddress::decode(&mut encoded.as_slice()).unwrap();
+		assert_eq!(decoded, address);
+
+		let like: MultiAddressLike = address.clone().into();
+		assert_eq!(MultiAddress::try_from(like).unwrap(), address);
+		assert!(AccountId::try_from(&address).is_err());
+	}
+
+	#[test]
+	fn event_phase_serde_round_trip() {
+		use subxt_core::events::Phase;
+
+		let cases = [
+			(Phase::ApplyExtrinsic(3), r#"{"ApplyExtrinsic":3}"#, "ApplyExtrinsic(3)"),
+			(Phase::Finalization, r#""Finalization""#, "Finalization"),
+			(Phase::Initialization, r#""Initialization""#, "Initialization"),
+		];
+		for (phase, json, display) in cases {
+			let phase = EventPhase::from(phase);
+			assert_eq!(serde_json::to_string(&phase).unwrap(), json);
+			assert_eq!(serde_json::from_str::<EventPhase>(json).unwrap(), phase);
+			assert_eq!(phase.to_string(), display);
+		}
+	}
+}
@@ -1,6 +1,6 @@
 use crate::{AccountId, AccountIdLike};
 use codec::{Decode, Encode};
-use sp_crypto_hashing::blake2_256;
+use sp_crypto_hashing::{blake2_256, blake2_512, twox_128};
 
 pub fn decode_already_decoded<I: codec::Input>(input: &mut I) -> Result<Vec<u8>, codec::Error> {
 	let length = input.remaining_len()?;
@@ -34,6 +34,35 @@ pub fn account_id_from_slice(value: &[u8]) -> Result<AccountId, String> {
 	Ok(AccountId { 0: account_id })
 }
 
+/// Encodes an account id as an SS58 address using the given network prefix.
+///
+/// Prefixes below 64 use the single-byte form; larger ones (up to 16383) use the two-byte form.
+pub fn account_id_to_ss58(account_id: &AccountId, prefix: u16) -> String {
+	let prefix = prefix & 0b0011_1111_1111_1111;
+	let mut payload: Vec<u8> = match prefix {
+		0..=63 => vec![prefix as u8],
+		_ => vec![
+			((prefix & 0b0000_0000_1111_1100) as u8 >> 2) | 0b0100_0000,
+			((prefix >> 8) as u8) | (((prefix & 0b0000_0000_0000_0011) as u8) << 6),
+		],
+	};
+	payload.extend_from_slice(&account_id.0);
+
+	let checksum = blake2_512(&[b"SS58PRE".as_slice(), &payload].concat());
+	payload.extend_from_slice(&checksum[..2]);
+
+	bs58::encode(payload).into_string()
+}
+
+/// Returns the storage prefix `twox128(pallet) ++ twox128(storage)` shared by every key of a storage item.
+pub fn storage_prefix(pallet: &str, storage: &str) -> [u8; 32] {
+	let mut prefix = [0u8; 32];
+	prefix[0..16].copy_from_slice(&twox_128(pallet.as_bytes()));
+	prefix[16..].copy_from_slice(&twox_128(storage.as_bytes()));
+
+	prefix
+}
+
 /// Derive a multi-account ID from the sorted list of accounts and the threshold that are
 /// required.
 pub fn multi_account_id(who: &[impl Into<AccountIdLike> + Clone], threshold: u16) -> AccountId {
@@ -50,6 +79,18 @@ pub fn multi_account_id(who: &[impl Into<AccountIdLike> + Clone], threshold: u16
 		.expect("infinite length input; no invalid inputs for type; qed")
 }
 
+/// Derives the multisig address for `signatories` and `threshold`, taking and returning SS58 strings.
+///
+/// The address uses the generic SS58 prefix (42) that Avail accounts are displayed with.
+pub fn multi_account_id_ss58(signatories: &[&str], threshold: u16) -> Result<String, String> {
+	let who = signatories
+		.iter()
+		.map(|x| account_id_from_str(x))
+		.collect::<Result<Vec<AccountId>, String>>()?;
+
+	Ok(account_id_to_ss58(&multi_account_id(&who, threshold), 42))
+}
+
 /// Input that adds infinite number of zero after wrapped input.
 struct TrailingZeroInput<'a>(&'a [u8]);
 
@@ -81,3 +122,38 @@ impl<'a> codec::Input for TrailingZeroInput<'a> {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ss58_with_prefix() {
+		let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+		let account_id = account_id_from_str(alice).unwrap();
+		assert_eq!(account_id_to_ss58(&account_id, 42), alice);
+		assert_eq!(account_id_to_ss58(&account_id, 0), "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5");
+		assert_eq!(account_id_to_ss58(&account_id, 2), "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F");
+	}
+
+	#[test]
+	fn multi_account_id_ss58_matches_account_ids() {
+		let signatories = [
+			"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+			"5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+			"5FLSigC9HGRKVhB9FiEo4Y3koPsNmBmLJbpXg2mp1hXcS59Y",
+		];
+		let address = multi_account_id_ss58(&signatories, 2).unwrap();
+		assert_eq!(address, "5DjYJStmdZ2rcqXbXGX7TW85JsrW6uG4y9MUcLq2BoPMpRA7");
+
+		let reversed = [signatories[2], signatories[1], signatories[0]];
+		assert_eq!(multi_account_id_ss58(&reversed, 2).unwrap(), address);
+		assert!(multi_account_id_ss58(&["not an address"], 2).is_err());
+	}
+
+	#[test]
+	fn storage_prefix_matches_known_bytes() {
+		let expected = "905e59f6c8fc974ec64116e6f647992829ac34430c4934c5e3aaeed5abe53e39";
+		assert_eq!(const_hex::encode(storage_prefix("DataAvailability", "AppKeys")), expected);
+	}
+}